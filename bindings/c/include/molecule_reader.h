@@ -13,7 +13,7 @@ extern "C" {
 #define MOLECULE_API_DECORATOR
 #endif /* MOLECULE_API_DECORATOR */
 
-#define MOLECULE_API_VERSION        7000
+#define MOLECULE_API_VERSION        8000
 #define MOLECULEC_VERSION_MIN       5000
 
 #if MOLECULE_API_VERSION < MOLECULE_API_VERSION_MIN
@@ -78,6 +78,14 @@ typedef struct {
 #define MOL_ERR_INDEX_OUT_OF_BOUNDS         0x05
 #define MOL_ERR_FIELD_COUNT                 0x06
 #define MOL_ERR_DATA                        0x07
+#define MOL_ERR_CONSTRAINT                  0x08
+#define MOL_ERR_NESTING_DEPTH               0x09
+
+// The maximum depth of the data nested in the recursive types, the data which is nested deeper
+// is rejected, so it can't overflow the stack. It could be defined before including this file.
+#ifndef MOL_MAX_NESTING_DEPTH
+#define MOL_MAX_NESTING_DEPTH               256
+#endif /* MOL_MAX_NESTING_DEPTH */
 
 /* Utilities. */
 
@@ -98,6 +106,20 @@ MOLECULE_API_DECORATOR mol_num_t mol_unpack_number(const uint8_t *src) {
     return output;
 }
 
+// Compares two segments as bytes, a segment is less than the longer ones which start with it.
+MOLECULE_API_DECORATOR int mol_seg_cmp(const mol_seg_t *lhs, const mol_seg_t *rhs) {
+    mol_num_t size = lhs->size < rhs->size ? lhs->size : rhs->size;
+    for (mol_num_t i = 0; i < size; i++) {
+        if (lhs->ptr[i] != rhs->ptr[i]) {
+            return lhs->ptr[i] < rhs->ptr[i] ? -1 : 1;
+        }
+    }
+    if (lhs->size == rhs->size) {
+        return 0;
+    }
+    return lhs->size < rhs->size ? -1 : 1;
+}
+
 
 /*
  * Core functions.
@@ -227,6 +249,18 @@ MOLECULE_API_DECORATOR mol_seg_t mol_table_slice_by_index(const mol_seg_t *input
     return res.seg;
 }
 
+// Slice a segment for Table by index, the default value is used if the field is missing.
+// Only the missing fields are replaced, the table should be verified at first.
+MOLECULE_API_DECORATOR mol_seg_t mol_table_slice_by_index_or_default(const mol_seg_t *input, mol_num_t field_index, const uint8_t *default_ptr, mol_num_t default_size) {
+    if (mol_table_actual_field_count(input) <= field_index) {
+        mol_seg_t seg;
+        seg.ptr = (uint8_t *)default_ptr;
+        seg.size = default_size;
+        return seg;
+    }
+    return mol_table_slice_by_index(input, field_index);
+}
+
 // Slice the raw bytes from a `vector <byte>` (FixVec, with a header).
 MOLECULE_API_DECORATOR mol_seg_t mol_fixvec_slice_raw_bytes(const mol_seg_t *input) {
     mol_seg_t seg;
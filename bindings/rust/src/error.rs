@@ -12,12 +12,19 @@ macro_rules! verification_error {
 }
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum VerificationError {
     TotalSizeNotMatch(String, usize, usize),
     HeaderIsBroken(String, usize, usize),
     UnknownItem(String, usize, Number),
     OffsetsNotMatch(String),
     FieldCountNotMatch(String, usize, usize),
+    InvalidBool(String, u8),
+    InvalidUtf8(String, usize),
+    InvalidEnumValue(String, u64),
+    UnsortedKeys(String, usize),
+    ConstraintViolated(String, String, u64),
+    NestingTooDeep(String, usize),
 }
 
 pub type VerificationResult<T> = result::Result<T, VerificationError>;
@@ -56,6 +63,40 @@ impl fmt::Display for VerificationError {
                     st, expected, actual
                 )?;
             }
+            VerificationError::InvalidBool(st, actual) => {
+                write!(
+                    f,
+                    "{} is not a valid bool, expect 0 or 1, actual {}",
+                    st, actual
+                )?;
+            }
+            VerificationError::InvalidUtf8(st, valid_up_to) => {
+                write!(
+                    f,
+                    "{} is not valid UTF-8, the invalid bytes start from {}",
+                    st, valid_up_to
+                )?;
+            }
+            VerificationError::InvalidEnumValue(st, actual) => {
+                write!(f, "{} value (={}) is not declared", st, actual)?;
+            }
+            VerificationError::UnsortedKeys(st, index) => {
+                write!(
+                    f,
+                    "{} keys are not strictly ascending, the key of entry {} is out of order",
+                    st, index
+                )?;
+            }
+            VerificationError::ConstraintViolated(st, constraint, actual) => {
+                write!(
+                    f,
+                    "{} violates the constraint `{}`, actual {}",
+                    st, constraint, actual
+                )?;
+            }
+            VerificationError::NestingTooDeep(st, limit) => {
+                write!(f, "{} is nested deeper than the limit {}", st, limit)?;
+            }
         }
         Ok(())
     }
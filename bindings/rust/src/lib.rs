@@ -23,6 +23,18 @@ pub mod error;
 pub mod prelude;
 mod primitive;
 
+/// The version of the API, as `(major * 1000 + minor) * 1000 + patch`.
+///
+/// The generated code checks it, since it requires the API of a newer version.
+pub const API_VERSION: u32 = 8000;
+
+/// The maximum depth of the data nested in the recursive types.
+///
+/// Each recursive type which is nested in another one is one level deeper, for example, each node of
+/// a linked list is two levels deeper, since the next node is in an option. The data which is
+/// nested deeper is rejected by the verification, so the untrusted data can't overflow the stack.
+pub const MAX_NESTING_DEPTH: usize = 256;
+
 // Little Endian
 pub type Number = u32;
 // Size of Number
@@ -21,6 +21,10 @@ pub trait Reader<'r>: Sized + fmt::Debug + Clone + Copy {
     type Entity: Entity;
     const NAME: &'static str;
     fn verify(slice: &[u8], compatible: bool) -> VerificationResult<()>;
+    /// Verifies the data which is nested at the depth, only the recursive types check the depth.
+    fn verify_nested(slice: &[u8], compatible: bool, _depth: usize) -> VerificationResult<()> {
+        Self::verify(slice, compatible)
+    }
     fn new_unchecked(slice: &'r [u8]) -> Self;
     fn as_slice(&self) -> &'r [u8];
     fn from_slice(slice: &'r [u8]) -> VerificationResult<Self> {
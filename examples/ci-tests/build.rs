@@ -7,13 +7,13 @@ fn compile_schema(schema: &str) {
         .generate_code(Language::Rust)
         .output_dir_set_default()
         .run()
-        .unwrap();
+        .unwrap_or_else(|err| panic!("{}", err));
     compiler
         .input_schema_file(schema)
         .generate_code(Language::C)
         .output_dir_set_default()
         .run()
-        .unwrap();
+        .unwrap_or_else(|err| panic!("{}", err));
     println!("cargo:rerun-if-changed={}", schema);
 }
 
@@ -21,6 +21,10 @@ fn main() {
     println!("cargo:rerun-if-changed=../../test/vectors");
     println!("cargo:rerun-if-changed=src/capi.c");
     compile_schema("../../test/schemas/types.mol");
+    compile_schema("schemas/extensions.mol");
+    compile_schema("schemas/transitive/a.mol");
+    compile_schema("schemas/transitive/b.mol");
+    compile_schema("schemas/transitive/root.mol");
     let out_dir = ::std::env::var("OUT_DIR").unwrap();
     cc::Build::new()
         .file("src/capi.c")
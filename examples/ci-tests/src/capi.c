@@ -1,4 +1,5 @@
 #include "types.h"
+#include "extensions.h"
 
 uint32_t tablea_verify(uint8_t *data, uint32_t data_len) {
     mol_seg_t input;
@@ -7,3 +8,221 @@ uint32_t tablea_verify(uint8_t *data, uint32_t data_len) {
     mol_errno result = MolReader_TableA_verify(&input, false);
     return result == MOL_OK ? 0 : 1;
 }
+
+uint32_t transaction_verify(uint8_t *data, uint32_t data_len) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    mol_errno result = MolReader_Transaction_verify(&input, false);
+    return result == MOL_OK ? 0 : 1;
+}
+
+uint32_t action_verify(uint8_t *data, uint32_t data_len) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    mol_errno result = MolReader_Action_verify(&input, false);
+    return result == MOL_OK ? 0 : 1;
+}
+
+uint32_t checkpoint_number(uint8_t *data, uint32_t data_len,
+                           uint8_t *output) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    if (MolReader_ExtCheckpoint_verify(&input, false) != MOL_OK) {
+        return 1;
+    }
+    mol_seg_t number = MolReader_ExtCheckpoint_get_number(&input);
+    memcpy(output, number.ptr, number.size);
+    return 0;
+}
+
+uint32_t header_unpack(uint8_t *data, uint32_t data_len, uint64_t *number,
+                       int32_t *offset) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    if (MolReader_Header_verify(&input, false) != MOL_OK) {
+        return 1;
+    }
+    mol_seg_t number_seg = MolReader_Header_get_number(&input);
+    *number = MolReader_Uint64_unpack(&number_seg);
+    mol_seg_t offset_seg = MolReader_Header_get_offset(&input);
+    *offset = MolReader_Int32_unpack(&offset_seg);
+    return 0;
+}
+
+uint32_t settings_verify(uint8_t *data, uint32_t data_len) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    return MolReader_Settings_verify(&input, false);
+}
+
+uint32_t settings_verbose(uint8_t *data, uint32_t data_len) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    if (MolReader_Settings_verify(&input, false) != MOL_OK) {
+        return 2;
+    }
+    mol_seg_t verbose = MolReader_Settings_get_verbose(&input);
+    return MolReader_Boolean_unpack(&verbose) ? 1 : 0;
+}
+
+uint32_t profile_verify(uint8_t *data, uint32_t data_len) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    return MolReader_Profile_verify(&input, false);
+}
+
+uint32_t job_verify(uint8_t *data, uint32_t data_len) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    return MolReader_Job_verify(&input, false);
+}
+
+uint32_t job_is_failed(uint8_t *data, uint32_t data_len) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    if (MolReader_Job_verify(&input, false) != MOL_OK) {
+        return 2;
+    }
+    mol_seg_t task = MolReader_Job_get_task(&input);
+    mol_seg_t status = MolReader_Task_get_status(&task);
+    return MolReader_Status_unpack(&status) == MolEnum_Status_Failed ? 1 : 0;
+}
+
+uint32_t ledger_verify(uint8_t *data, uint32_t data_len) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    return MolReader_Ledger_verify(&input, false);
+}
+
+uint32_t group_verify(uint8_t *data, uint32_t data_len) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    return MolReader_Group_verify(&input, false);
+}
+
+uint32_t port_verify(uint8_t *data, uint32_t data_len) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    return MolReader_Port_verify(&input, false);
+}
+
+uint32_t members_verify(uint8_t *data, uint32_t data_len) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    return MolReader_Members_verify(&input, false);
+}
+
+uint32_t account_verify(uint8_t *data, uint32_t data_len, bool compatible) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    return MolReader_Account_verify(&input, compatible);
+}
+
+uint32_t account_level(uint8_t *data, uint32_t data_len) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    if (MolReader_Account_verify(&input, true) != MOL_OK) {
+        return 0xffffffff;
+    }
+    mol_seg_t level = MolReader_Account_get_level(&input);
+    return MolReader_Uint8_unpack(&level);
+}
+
+uint32_t tree_node_verify(uint8_t *data, uint32_t data_len) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    return MolReader_TreeNode_verify(&input, false);
+}
+
+static uint32_t count_tree_nodes(const mol_seg_t *node) {
+    mol_seg_t children = MolReader_TreeNode_get_children(node);
+    mol_num_t length = MolReader_TreeNodeVec_length(&children);
+    uint32_t count = 1;
+    for (mol_num_t i = 0; i < length; i++) {
+        mol_seg_res_t child = MolReader_TreeNodeVec_get(&children, i);
+        count += count_tree_nodes(&child.seg);
+    }
+    return count;
+}
+
+uint32_t tree_node_count(uint8_t *data, uint32_t data_len) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    if (MolReader_TreeNode_verify(&input, false) != MOL_OK) {
+        return 0xffffffff;
+    }
+    return count_tree_nodes(&input);
+}
+
+uint32_t list_node_verify(uint8_t *data, uint32_t data_len) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    return MolReader_ListNode_verify(&input, false);
+}
+
+uint32_t expr_verify(uint8_t *data, uint32_t data_len) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    return MolReader_Expr_verify(&input, false);
+}
+
+uint32_t utf8_verify(uint8_t *data, uint32_t data_len) {
+    mol_seg_t input;
+    input.ptr = data;
+    input.size = data_len;
+    return mol_utf8_verify(&input) == MOL_OK ? 0 : 1;
+}
+
+uint32_t transaction_build_withdraw(uint8_t *data, uint32_t data_len,
+                                    uint8_t *output, uint32_t output_len) {
+    mol_builder_t payload_builder;
+    MolBuilder_Payload_init(&payload_builder);
+    for (uint32_t i = 0; i < data_len; i++) {
+        MolBuilder_Payload_push(&payload_builder, data[i]);
+    }
+    mol_seg_res_t payload = MolBuilder_Payload_build(payload_builder);
+    mol_builder_t builder;
+    MolBuilder_Transaction_init(&builder);
+    MolBuilder_Transaction_set_Withdraw(&builder, payload.seg.ptr, payload.seg.size);
+    mol_seg_res_t result = MolBuilder_Transaction_build(builder);
+    free(payload.seg.ptr);
+    uint32_t size = result.seg.size;
+    if (size <= output_len) {
+        memcpy(output, result.seg.ptr, size);
+    }
+    free(result.seg.ptr);
+    return size;
+}
+
+uint32_t copy_constants(uint8_t *output, uint32_t output_len) {
+    uint32_t size = sizeof(MolConst_MAX_PAYLOAD_SIZE) +
+                    sizeof(MolConst_GENESIS_DIGEST) + sizeof(MolConst_SEED);
+    if (size <= output_len) {
+        uint8_t *ptr = output;
+        memcpy(ptr, MolConst_MAX_PAYLOAD_SIZE, sizeof(MolConst_MAX_PAYLOAD_SIZE));
+        ptr += sizeof(MolConst_MAX_PAYLOAD_SIZE);
+        memcpy(ptr, MolConst_GENESIS_DIGEST, sizeof(MolConst_GENESIS_DIGEST));
+        ptr += sizeof(MolConst_GENESIS_DIGEST);
+        memcpy(ptr, MolConst_SEED, sizeof(MolConst_SEED));
+    }
+    return size;
+}
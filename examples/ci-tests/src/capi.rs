@@ -1,9 +1,188 @@
 mod ffi {
     extern "C" {
         pub(super) fn tablea_verify(data: *const u8, data_len: u32) -> u32;
+        pub(super) fn transaction_verify(data: *const u8, data_len: u32) -> u32;
+        pub(super) fn action_verify(data: *const u8, data_len: u32) -> u32;
+        pub(super) fn transaction_build_withdraw(
+            data: *const u8,
+            data_len: u32,
+            output: *mut u8,
+            output_len: u32,
+        ) -> u32;
+        pub(super) fn checkpoint_number(data: *const u8, data_len: u32, output: *mut u8) -> u32;
+        pub(super) fn header_unpack(
+            data: *const u8,
+            data_len: u32,
+            number: *mut u64,
+            offset: *mut i32,
+        ) -> u32;
+        pub(super) fn settings_verify(data: *const u8, data_len: u32) -> u32;
+        pub(super) fn settings_verbose(data: *const u8, data_len: u32) -> u32;
+        pub(super) fn profile_verify(data: *const u8, data_len: u32) -> u32;
+        pub(super) fn job_verify(data: *const u8, data_len: u32) -> u32;
+        pub(super) fn job_is_failed(data: *const u8, data_len: u32) -> u32;
+        pub(super) fn ledger_verify(data: *const u8, data_len: u32) -> u32;
+        pub(super) fn group_verify(data: *const u8, data_len: u32) -> u32;
+        pub(super) fn port_verify(data: *const u8, data_len: u32) -> u32;
+        pub(super) fn members_verify(data: *const u8, data_len: u32) -> u32;
+        pub(super) fn account_verify(data: *const u8, data_len: u32, compatible: bool) -> u32;
+        pub(super) fn account_level(data: *const u8, data_len: u32) -> u32;
+        pub(super) fn tree_node_verify(data: *const u8, data_len: u32) -> u32;
+        pub(super) fn tree_node_count(data: *const u8, data_len: u32) -> u32;
+        pub(super) fn list_node_verify(data: *const u8, data_len: u32) -> u32;
+        pub(super) fn expr_verify(data: *const u8, data_len: u32) -> u32;
+        pub(super) fn utf8_verify(data: *const u8, data_len: u32) -> u32;
+        pub(super) fn copy_constants(output: *mut u8, output_len: u32) -> u32;
     }
 }
 
 pub fn tablea_verify(input: &[u8]) -> bool {
     unsafe { ffi::tablea_verify(input.as_ptr(), input.len() as u32) == 0 }
 }
+
+pub fn transaction_verify(input: &[u8]) -> bool {
+    unsafe { ffi::transaction_verify(input.as_ptr(), input.len() as u32) == 0 }
+}
+
+pub fn action_verify(input: &[u8]) -> bool {
+    unsafe { ffi::action_verify(input.as_ptr(), input.len() as u32) == 0 }
+}
+
+/// Reads the `number` of a `Checkpoint`, which is `ExtCheckpoint` in C.
+pub fn checkpoint_number(input: &[u8]) -> Option<[u8; 4]> {
+    let mut output = [0u8; 4];
+    let result =
+        unsafe { ffi::checkpoint_number(input.as_ptr(), input.len() as u32, output.as_mut_ptr()) };
+    if result == 0 {
+        Some(output)
+    } else {
+        None
+    }
+}
+
+/// Unpacks the `number` and the `offset` of a `Header`.
+pub fn header_unpack(input: &[u8]) -> Option<(u64, i32)> {
+    let mut number = 0;
+    let mut offset = 0;
+    let result =
+        unsafe { ffi::header_unpack(input.as_ptr(), input.len() as u32, &mut number, &mut offset) };
+    if result == 0 {
+        Some((number, offset))
+    } else {
+        None
+    }
+}
+
+/// Verifies a `Settings`, returns the error code of the C API.
+pub fn settings_verify(input: &[u8]) -> u32 {
+    unsafe { ffi::settings_verify(input.as_ptr(), input.len() as u32) }
+}
+
+/// Reads the `verbose` of a `Settings`.
+pub fn settings_verbose(input: &[u8]) -> Option<bool> {
+    match unsafe { ffi::settings_verbose(input.as_ptr(), input.len() as u32) } {
+        0 => Some(false),
+        1 => Some(true),
+        _ => None,
+    }
+}
+
+/// Verifies a `Profile`, returns the error code of the C API.
+pub fn profile_verify(input: &[u8]) -> u32 {
+    unsafe { ffi::profile_verify(input.as_ptr(), input.len() as u32) }
+}
+
+/// Verifies a `Job`, returns the error code of the C API.
+pub fn job_verify(input: &[u8]) -> u32 {
+    unsafe { ffi::job_verify(input.as_ptr(), input.len() as u32) }
+}
+
+/// Checks whether the status of the task in a `Job` is `Failed`.
+pub fn job_is_failed(input: &[u8]) -> Option<bool> {
+    match unsafe { ffi::job_is_failed(input.as_ptr(), input.len() as u32) } {
+        0 => Some(false),
+        1 => Some(true),
+        _ => None,
+    }
+}
+
+/// Verifies a `Ledger`, returns the error code of the C API.
+pub fn ledger_verify(input: &[u8]) -> u32 {
+    unsafe { ffi::ledger_verify(input.as_ptr(), input.len() as u32) }
+}
+
+/// Verifies a `Group`, returns the error code of the C API.
+pub fn group_verify(input: &[u8]) -> u32 {
+    unsafe { ffi::group_verify(input.as_ptr(), input.len() as u32) }
+}
+
+/// Verifies a `Port`, returns the error code of the C API.
+pub fn port_verify(input: &[u8]) -> u32 {
+    unsafe { ffi::port_verify(input.as_ptr(), input.len() as u32) }
+}
+
+/// Verifies a `Members`, returns the error code of the C API.
+pub fn members_verify(input: &[u8]) -> u32 {
+    unsafe { ffi::members_verify(input.as_ptr(), input.len() as u32) }
+}
+
+/// Verifies an `Account`, returns the error code of the C API.
+pub fn account_verify(input: &[u8], compatible: bool) -> u32 {
+    unsafe { ffi::account_verify(input.as_ptr(), input.len() as u32, compatible) }
+}
+
+/// Gets the level of an `Account` which is verified in compatible mode, the default value is
+/// returned if the field is missing.
+pub fn account_level(input: &[u8]) -> Option<u8> {
+    match unsafe { ffi::account_level(input.as_ptr(), input.len() as u32) } {
+        0xffffffff => None,
+        level => Some(level as u8),
+    }
+}
+
+/// Verifies a `TreeNode`, returns the error code of the C API.
+pub fn tree_node_verify(input: &[u8]) -> u32 {
+    unsafe { ffi::tree_node_verify(input.as_ptr(), input.len() as u32) }
+}
+
+/// Counts the nodes of a `TreeNode` recursively, returns `None` if it's invalid.
+pub fn tree_node_count(input: &[u8]) -> Option<u32> {
+    match unsafe { ffi::tree_node_count(input.as_ptr(), input.len() as u32) } {
+        0xffffffff => None,
+        count => Some(count),
+    }
+}
+
+/// Verifies a `ListNode`, returns the error code of the C API.
+pub fn list_node_verify(input: &[u8]) -> u32 {
+    unsafe { ffi::list_node_verify(input.as_ptr(), input.len() as u32) }
+}
+
+/// Verifies an `Expr`, returns the error code of the C API.
+pub fn expr_verify(input: &[u8]) -> u32 {
+    unsafe { ffi::expr_verify(input.as_ptr(), input.len() as u32) }
+}
+
+/// Checks whether the bytes are valid UTF-8 by the helper of the C API.
+pub fn utf8_verify(input: &[u8]) -> bool {
+    unsafe { ffi::utf8_verify(input.as_ptr(), input.len() as u32) == 0 }
+}
+
+/// Builds a `Transaction` with the `Withdraw` item into `output`, returns the
+/// size of the built data.
+pub fn transaction_build_withdraw(input: &[u8], output: &mut [u8]) -> usize {
+    unsafe {
+        ffi::transaction_build_withdraw(
+            input.as_ptr(),
+            input.len() as u32,
+            output.as_mut_ptr(),
+            output.len() as u32,
+        ) as usize
+    }
+}
+
+/// Copies the constants declared in `extensions.mol` into `output`, returns
+/// the total size of them.
+pub fn copy_constants(output: &mut [u8]) -> usize {
+    unsafe { ffi::copy_constants(output.as_mut_ptr(), output.len() as u32) as usize }
+}
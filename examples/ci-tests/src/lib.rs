@@ -8,6 +8,30 @@ pub mod types {
     include!(concat!(env!("OUT_DIR"), "/", "types", ".rs"));
 }
 
+pub mod extensions {
+    #![allow(clippy::all)]
+    pub use molecule::prelude::{Byte, ByteReader};
+    include!(concat!(env!("OUT_DIR"), "/", "extensions", ".rs"));
+}
+
+/// The schemas which import each other, `root` imports `a` indirectly.
+pub mod transitive {
+    pub mod a {
+        #![allow(clippy::all)]
+        include!(concat!(env!("OUT_DIR"), "/", "a", ".rs"));
+    }
+
+    pub mod b {
+        #![allow(clippy::all)]
+        include!(concat!(env!("OUT_DIR"), "/", "b", ".rs"));
+    }
+
+    pub mod root {
+        #![allow(clippy::all)]
+        include!(concat!(env!("OUT_DIR"), "/", "root", ".rs"));
+    }
+}
+
 #[cfg(test)]
 mod test_vectors {
     mod default {
@@ -0,0 +1,411 @@
+use std::path::Path;
+
+use codegen::{ast::HasName as _, Error, FileResolver, MemoryResolver, Parser};
+
+fn parse_error(name: &str) -> Error {
+    let path = format!("schemas/errors/{}.mol", name);
+    Parser::parse(&path)
+        .err()
+        .unwrap_or_else(|| panic!("schema `{}` should be invalid", path))
+}
+
+fn check_location(err: &Error, name: &str, line: usize, column: usize) {
+    let location = err
+        .location()
+        .unwrap_or_else(|| panic!("error for `{}` should have a location", name));
+    assert!(location.path().ends_with(format!("{}.mol", name)));
+    assert_eq!((location.line(), location.column()), (line, column));
+}
+
+#[test]
+fn unknown_type() {
+    let err = parse_error("unknown_type");
+    match err {
+        Error::UnknownType(_, ref name) => assert_eq!(name, "Bar"),
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "unknown_type", 2, 8);
+    let expected = "error: the type `Bar` is not declared\n \
+                    --> schemas/errors/unknown_type.mol:2:8\n  \
+                    |\n\
+                    2 |     a: Bar,\n  \
+                    |        ^^^";
+    assert_eq!(err.to_string(), expected);
+}
+
+#[test]
+fn empty_union() {
+    let err = parse_error("empty_union");
+    match err {
+        Error::EmptyUnion(_, ref name) => assert_eq!(name, "Foo"),
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "empty_union", 1, 7);
+}
+
+#[test]
+fn not_fixed_size() {
+    let err = parse_error("not_fixed_size");
+    match err {
+        Error::NotFixedSize(_, ref name, ref typ) => {
+            assert_eq!(name, "Foo");
+            assert_eq!(typ, "Bytes");
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "not_fixed_size", 4, 8);
+}
+
+#[test]
+fn duplicate_name() {
+    let err = parse_error("duplicate_name");
+    match err {
+        Error::DuplicateName(_, _, ref name) => assert_eq!(name, "Foo"),
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "duplicate_name", 2, 7);
+}
+
+#[test]
+fn duplicate_name_across_files() {
+    let err = parse_error("redeclare/conflict");
+    match err {
+        Error::DuplicateName(_, ref previous, ref name) => {
+            assert_eq!(name, "Uint32");
+            assert!(previous.path().ends_with("redeclare/conflict.mol"));
+            assert_eq!((previous.line(), previous.column()), (3, 7));
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "redeclare/common", 1, 7);
+    let message = err.to_string();
+    assert!(message.starts_with(
+        "error: the name `Uint32` is declared in both \
+         `schemas/errors/redeclare/conflict.mol` and `schemas/errors/redeclare/common.mol`"
+    ));
+    assert!(message.contains("note: previously declared here"));
+}
+
+#[test]
+fn identical_redeclarations() {
+    let path = "schemas/errors/redeclare/identical.mol";
+    let err = Parser::parse(&path).err().unwrap();
+    match err {
+        Error::DuplicateName(_, _, ref name) => assert_eq!(name, "Uint32"),
+        _ => panic!("unexpected error: {}", err),
+    }
+    let resolver = FileResolver::new();
+    let ast = Parser::new()
+        .allow_identical_redeclarations(true)
+        .parse_schema_file(&path, &resolver)
+        .unwrap();
+    let names = ast
+        .decls()
+        .iter()
+        .map(|decl| decl.name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["Uint32", "Pair"]);
+    let err = Parser::new()
+        .allow_identical_redeclarations(true)
+        .parse_schema_file(&"schemas/errors/redeclare/conflict.mol", &resolver)
+        .err()
+        .unwrap();
+    match err {
+        Error::DuplicateName(_, _, ref name) => assert_eq!(name, "Uint32"),
+        _ => panic!("unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn redeclarations_with_different_attributes() {
+    let common = "table Foo {\n    a: byte,\n    #[default]\n    b: option<Foo>,\n}\n";
+    let mut resolver = MemoryResolver::new();
+    resolver.insert("common.mol", common);
+    let parse = |source: &str| {
+        Parser::new()
+            .allow_identical_redeclarations(true)
+            .parse_schema_sources(&"root.mol", source, &resolver)
+            .map_err(Box::new)
+    };
+    let identical = format!("import common;\n\n{}", common);
+    assert!(parse(&identical).is_ok());
+    let sources = [
+        // the default value of a field
+        "import common;\n\ntable Foo {\n    a: byte,\n    b: option<Foo>,\n}\n",
+        // the attributes of the declaration
+        "import common;\n\n#[c(prefix = \"x_\")]\ntable Foo {\n    a: byte,\n    #[default]\n    b: option<Foo>,\n}\n",
+    ];
+    for source in &sources {
+        let err = parse(source).unwrap_err();
+        match *err {
+            Error::DuplicateName(ref location, ref previous, ref name) => {
+                assert_eq!(name, "Foo");
+                assert!(previous.path().ends_with("root.mol"));
+                assert!(location.path().ends_with("common.mol"));
+            }
+            _ => panic!("unexpected error: {}", err),
+        }
+        assert!(err.to_string().contains("note: previously declared here"));
+    }
+}
+
+#[test]
+fn anonymous_conflict() {
+    let err = parse_error("anonymous_conflict");
+    match err {
+        Error::DuplicateName(_, ref previous, ref name) => {
+            assert_eq!(name, "Byte2");
+            assert_eq!((previous.line(), previous.column()), (1, 7));
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "anonymous_conflict", 4, 8);
+}
+
+#[test]
+fn reserved_name() {
+    let err = parse_error("reserved_name");
+    match err {
+        Error::ReservedName(_, ref name) => assert_eq!(name, "Byte"),
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "reserved_name", 1, 7);
+}
+
+#[test]
+fn duplicate_item_id() {
+    let err = parse_error("duplicate_item_id");
+    match err {
+        Error::DuplicateItemId(_, ref previous, ref name, id) => {
+            assert_eq!(name, "Foo");
+            assert_eq!(id, 3);
+            assert_eq!((previous.line(), previous.column()), (4, 5));
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "duplicate_item_id", 5, 5);
+}
+
+#[test]
+fn item_id_out_of_range() {
+    let err = parse_error("item_id_out_of_range");
+    match err {
+        Error::ItemIdOutOfRange(_, ref name, id) => {
+            assert_eq!(name, "Foo");
+            assert_eq!(id, 1 << 32);
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "item_id_out_of_range", 5, 5);
+}
+
+#[test]
+fn item_id_too_large() {
+    let err = parse_error("item_id_too_large");
+    match err {
+        Error::Syntax(_, ref reason) => {
+            assert_eq!(reason, "the number `99999999999999999999` is too large")
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "item_id_too_large", 5, 13);
+}
+
+#[test]
+fn invalid_constant() {
+    let err = parse_error("invalid_constant");
+    match err {
+        Error::InvalidConstant(_, ref name, _) => assert_eq!(name, "MAX"),
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "invalid_constant", 3, 21);
+    assert!(err.to_string().starts_with(
+        "error: the value of constant `MAX` is invalid: \
+                      the integer doesn't fit in 2 bytes"
+    ));
+
+    let err = parse_error("invalid_constant_length");
+    match err {
+        Error::InvalidConstant(_, ref name, ref reason) => {
+            assert_eq!(name, "ZERO");
+            assert_eq!(reason, "expect 4 bytes but got 3");
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "invalid_constant_length", 3, 20);
+}
+
+#[test]
+fn not_fixed_size_constant() {
+    let err = parse_error("not_fixed_size_constant");
+    match err {
+        Error::NotFixedSize(_, ref name, ref typ) => {
+            assert_eq!(name, "EMPTY");
+            assert_eq!(typ, "Bytes");
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "not_fixed_size_constant", 3, 14);
+}
+
+#[test]
+fn invalid_enum() {
+    let err = parse_error("invalid_enum");
+    match err {
+        Error::InvalidEnum(_, ref name, ref reason) => {
+            assert_eq!(name, "Foo");
+            assert_eq!(reason, "the value of `B` doesn't fit in 2 bytes");
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "invalid_enum", 3, 5);
+
+    let err = parse_error("invalid_enum_type");
+    match err {
+        Error::InvalidEnum(_, ref name, _) => assert_eq!(name, "Foo"),
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "invalid_enum_type", 1, 12);
+
+    let err = parse_error("invalid_enum_constant");
+    match err {
+        Error::InvalidConstant(_, ref name, ref reason) => {
+            assert_eq!(name, "BAR");
+            assert_eq!(reason, "the value 2 isn't an item of the enum `Foo`");
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn invalid_constraint() {
+    let err = parse_error("invalid_constraint");
+    match err {
+        Error::InvalidConstraint(_, ref name, ref reason) => {
+            assert_eq!(name, "Port");
+            assert_eq!(reason, "`min` (10) is greater than `max` (1)");
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "invalid_constraint", 4, 7);
+
+    let err = parse_error("misplaced_constraint");
+    match err {
+        Error::InvalidConstraint(_, ref name, ref reason) => {
+            assert_eq!(name, "Foo");
+            assert_eq!(reason, "`#[non_empty]` is only allowed on vectors");
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "misplaced_constraint", 4, 7);
+}
+
+#[test]
+fn invalid_default() {
+    let err = parse_error("invalid_default");
+    match err {
+        Error::InvalidDefault(_, ref name, ref reason) => {
+            assert_eq!(name, "Foo.b");
+            assert_eq!(
+                reason,
+                "`Bytes` isn't fixed size, only `#[default]` is allowed"
+            );
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "invalid_default", 6, 8);
+
+    let err = parse_error("default_not_trailing");
+    match err {
+        Error::InvalidDefault(_, ref name, ref reason) => {
+            assert_eq!(name, "Foo.c");
+            assert_eq!(
+                reason,
+                "it should have a default value, since the field `b` before it has one"
+            );
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "default_not_trailing", 5, 8);
+}
+
+#[test]
+fn cyclic_import() {
+    let err = parse_error("cyclic_import");
+    match err {
+        Error::CyclicImport(_, ref chain) => {
+            let path = Path::new("schemas/errors/cyclic_import.mol");
+            assert_eq!(chain, &[path, path]);
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "cyclic_import", 1, 8);
+}
+
+#[test]
+fn cyclic_import_chain() {
+    let err = parse_error("cycle/a");
+    let expected = "error: found cyclic import: schemas/errors/cycle/a.mol -> \
+                    schemas/errors/cycle/b.mol -> schemas/errors/cycle/c.mol -> \
+                    schemas/errors/cycle/b.mol";
+    assert!(err.to_string().starts_with(expected), "{}", err);
+    check_location(&err, "cycle/c", 1, 8);
+}
+
+#[test]
+fn diamond_import_is_not_cyclic() {
+    let ast = Parser::parse(&"schemas/import/c/cc/cc.mol").unwrap();
+    assert_eq!(ast.major_decls().len(), 1);
+}
+
+#[test]
+fn cyclic_dependency() {
+    let err = parse_error("cyclic_dependency");
+    match err {
+        Error::CyclicDependency(_, ref names) => assert_eq!(names, &["Foo", "Bar"]),
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "cyclic_dependency", 1, 8);
+}
+
+#[test]
+fn recursive_fixed_size() {
+    let err = parse_error("recursive_fixed_size");
+    match err {
+        Error::NotFixedSize(_, ref name, ref typ) => {
+            assert_eq!((&name[..], &typ[..]), ("Point", "Link"))
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "recursive_fixed_size", 2, 11);
+}
+
+#[test]
+fn infinite_default() {
+    let err = parse_error("infinite_default");
+    match err {
+        Error::CyclicDependency(_, ref names) => assert_eq!(names, &["Node", "Next"]),
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "infinite_default", 2, 7);
+}
+
+#[test]
+fn syntax_error() {
+    let err = parse_error("syntax");
+    match err {
+        Error::Syntax(_, _) => {}
+        _ => panic!("unexpected error: {}", err),
+    }
+    check_location(&err, "syntax", 2, 5);
+}
+
+#[test]
+fn io_failure() {
+    let err = parse_error("not_exists");
+    match err {
+        Error::Io(Some(ref path), _) => assert!(path.ends_with("not_exists.mol")),
+        _ => panic!("unexpected error: {}", err),
+    }
+    assert!(err.location().is_none());
+}
@@ -0,0 +1,1440 @@
+use std::rc::Rc;
+
+use codegen::{
+    ast::{self, HasName as _},
+    Compiler, Error, Language, MemoryResolver, Parser,
+};
+use molecule::{error::VerificationError, prelude::*};
+
+use molecule_ci_tests::{capi, extensions as ext};
+
+fn payload(data: &[u8]) -> ext::Payload {
+    ext::Payload::new_builder()
+        .extend(data.iter().map(|b| Byte::new(*b)))
+        .build()
+}
+
+#[test]
+fn named_union_items() {
+    let deposit = ext::Transaction::new_builder()
+        .set(ext::TransactionUnion::Deposit(payload(&[1, 2])))
+        .build();
+    let withdraw = ext::Transaction::new_builder()
+        .set(ext::TransactionUnion::Withdraw(payload(&[1, 2])))
+        .build();
+    let digest = ext::Transaction::new_builder()
+        .set(ext::Digest::default())
+        .build();
+    assert_eq!(deposit.item_id(), 0);
+    assert_eq!(withdraw.item_id(), 1);
+    assert_eq!(digest.item_id(), 2);
+    assert_eq!(deposit.to_enum().item_name(), "Deposit");
+    assert_eq!(withdraw.to_enum().item_name(), "Withdraw");
+    assert_eq!(digest.to_enum().item_name(), ext::Digest::NAME);
+    assert_eq!(deposit.as_slice()[4..], withdraw.as_slice()[4..]);
+    match withdraw.as_reader().to_enum() {
+        ext::TransactionUnionReader::Withdraw(inner) => assert_eq!(inner.raw_data(), &[1, 2]),
+        _ => panic!("the item should be `Withdraw`"),
+    }
+    assert!(ext::Transaction::default().to_enum().item_name() == "Deposit");
+    assert_eq!(
+        format!("{}", withdraw.to_enum()),
+        "TransactionUnion::Withdraw(Payload(0x0102))"
+    );
+}
+
+#[test]
+fn named_union_items_in_c() {
+    let expected = ext::Transaction::new_builder()
+        .set(ext::TransactionUnion::Withdraw(payload(&[3, 4, 5])))
+        .build();
+    let mut output = [0u8; 64];
+    let size = capi::transaction_build_withdraw(&[3, 4, 5], &mut output);
+    assert_eq!(&output[..size], expected.as_slice());
+    assert!(capi::transaction_verify(expected.as_slice()));
+    assert!(!capi::transaction_verify(&[3, 0, 0, 0]));
+}
+
+#[test]
+fn explicit_union_item_ids() {
+    let action = ext::Action::default();
+    assert_eq!(action.item_id(), 3);
+    assert_eq!(action.to_enum().item_name(), "Transfer");
+    let ids = [
+        (ext::ActionUnion::Transfer(payload(&[1])), 3),
+        (ext::ActionUnion::Burn(payload(&[1])), 7),
+        (ext::ActionUnion::Mint(payload(&[1])), 8),
+        (ext::ActionUnion::Digest(ext::Digest::default()), 1),
+    ];
+    for (item, id) in ids.iter() {
+        let action = ext::Action::new_builder().set(item.clone()).build();
+        assert_eq!(action.item_id(), *id);
+        assert_eq!(action.to_enum().item_name(), item.item_name());
+        assert!(ext::Action::from_slice(action.as_slice()).is_ok());
+        assert!(capi::action_verify(action.as_slice()));
+    }
+    for id in &[0u32, 2, 4, 9] {
+        let mut data = molecule::pack_number(*id).to_vec();
+        data.extend_from_slice(&[0, 0, 0, 0]);
+        assert!(ext::ActionReader::verify(&data, false).is_err());
+        assert!(!capi::action_verify(&data));
+    }
+}
+
+#[test]
+fn inline_types_are_declared_once() {
+    let ast = Parser::parse(&"schemas/extensions.mol").unwrap();
+    let names = ast
+        .decls()
+        .iter()
+        .map(|decl| decl.name())
+        .collect::<Vec<_>>();
+    let expected = [
+        "Payload",
+        "Digest",
+        "Transaction",
+        "Action",
+        "Byte32",
+        "PayloadVec",
+        "PayloadOpt",
+        "DigestVec",
+        "Byte20",
+        "Byte20Opt",
+        "Byte20OptVec",
+        "Record",
+        "Digestx2",
+        "Entry",
+        "Message",
+        "Length",
+        "Checkpoint",
+        "Uint8",
+        "Uint64",
+        "Uint128",
+        "Int32",
+        "Int16",
+        "Int16Vec",
+        "Header",
+        "Boolean",
+        "Booleanx3",
+        "Switches",
+        "BooleanVec",
+        "Settings",
+        "Utf8String",
+        "Utf8StringVec",
+        "Profile",
+        "Status",
+        "Priority",
+        "Task",
+        "StatusVec",
+        "PriorityOpt",
+        "Job",
+        "BalancesEntry",
+        "Balances",
+        "LabelsEntry",
+        "Labels",
+        "Ledger",
+        "Port",
+        "Members",
+        "Notes",
+        "Group",
+        "AccountV1",
+        "AccountV2",
+        "Account",
+        "Uint32",
+        "TreeNode",
+        "TreeNodeVec",
+        "ListNodeOpt",
+        "ListNode",
+        "Expr",
+        "BinaryExpr",
+        "Byte24",
+    ];
+    assert_eq!(names, expected);
+}
+
+#[test]
+fn inline_types() {
+    let record = ext::Record::new_builder()
+        .owner(ext::Byte32::new_builder().nth31(Byte::new(1)).build())
+        .tags(ext::PayloadVec::new_builder().push(payload(&[2])).build())
+        .memo(
+            ext::PayloadOpt::new_builder()
+                .set(Some(payload(&[3])))
+                .build(),
+        )
+        .digests(
+            ext::DigestVec::new_builder()
+                .push(Default::default())
+                .build(),
+        )
+        .flags(
+            ext::Byte20OptVec::new_builder()
+                .push(Default::default())
+                .push(
+                    ext::Byte20Opt::new_builder()
+                        .set(Some(Default::default()))
+                        .build(),
+                )
+                .build(),
+        )
+        .build();
+    let record = ext::Record::from_slice(record.as_slice()).unwrap();
+    assert_eq!(record.owner().nth31().as_slice(), &[1]);
+    assert_eq!(record.tags().get(0).unwrap().raw_data(), &[2][..]);
+    assert_eq!(record.memo().to_opt().unwrap().raw_data(), &[3][..]);
+    assert_eq!(record.digests().len(), 1);
+    assert!(record.flags().get(0).unwrap().is_none());
+    assert!(record.flags().get(1).unwrap().is_some());
+
+    assert_eq!(ext::Entry::TOTAL_SIZE, 32 + 4 * 2);
+
+    let message = ext::Message::new_builder()
+        .set(ext::DigestVec::default())
+        .build();
+    assert_eq!(message.to_enum().item_name(), "DigestVec");
+}
+
+#[test]
+fn constants() {
+    assert_eq!(ext::MAX_PAYLOAD_SIZE, [0x00, 0x04, 0x00, 0x00]);
+    assert_eq!(ext::GENESIS_DIGEST, [0xde, 0xad, 0xbe, 0xef]);
+    assert_eq!(ext::SEED.len(), 24);
+    assert!(ext::SEED.iter().enumerate().all(|(i, b)| i == *b as usize));
+    let digest = ext::Digest::from_slice(&ext::GENESIS_DIGEST).unwrap();
+    assert_eq!(digest.nth0(), Byte::new(0xde));
+    assert!(ext::LengthReader::verify(&ext::MAX_PAYLOAD_SIZE, false).is_ok());
+
+    let mut output = [0u8; 64];
+    let size = capi::copy_constants(&mut output);
+    let expected = [
+        &ext::MAX_PAYLOAD_SIZE[..],
+        &ext::GENESIS_DIGEST[..],
+        &ext::SEED[..],
+    ]
+    .concat();
+    assert_eq!(&output[..size], &expected[..]);
+}
+
+#[test]
+fn constants_in_ast() {
+    let ast = Parser::parse(&"schemas/extensions.mol").unwrap();
+    let consts = ast
+        .major_consts()
+        .into_iter()
+        .map(|c| (c.name(), c.typ().name().to_owned(), c.value().to_vec()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        consts[0],
+        ("MAX_PAYLOAD_SIZE", "Length".to_owned(), vec![0, 4, 0, 0])
+    );
+    assert_eq!(consts[2].1, "Byte24");
+}
+
+fn find_decl<'a>(ast: &'a codegen::ast::Ast, name: &str) -> &'a ast::TopDecl {
+    ast.decls()
+        .iter()
+        .find(|decl| decl.name() == name)
+        .unwrap_or_else(|| panic!("`{}` should be declared", name))
+}
+
+#[test]
+fn doc_comments() {
+    let ast = Parser::parse(&"schemas/extensions.mol").unwrap();
+    let payload = find_decl(&ast, "Payload");
+    assert_eq!(payload.docs(), &["Raw bytes carried by transactions."]);
+    assert!(find_decl(&ast, "Digest").docs().is_empty());
+    match find_decl(&ast, "Transaction") {
+        ast::TopDecl::Union(union) => {
+            let expected = [
+                "A transaction.",
+                "",
+                "The items are named, since two of them have the same type.",
+            ];
+            assert_eq!(union.docs(), &expected);
+            assert_eq!(union.items()[0].docs(), &["Adds funds to the account."]);
+            assert_eq!(union.items()[1].docs(), &["Takes funds from the account."]);
+            assert!(union.items()[2].docs().is_empty());
+        }
+        _ => panic!("`Transaction` should be a union"),
+    }
+    match find_decl(&ast, "Record") {
+        ast::TopDecl::Table(table) => {
+            let expected = ["The hash of the owner's lock script."];
+            assert_eq!(table.fields()[0].docs(), &expected);
+            assert!(table.fields()[1].docs().is_empty());
+        }
+        _ => panic!("`Record` should be a table"),
+    }
+    match find_decl(&ast, "Entry") {
+        ast::TopDecl::Struct(st) => {
+            let expected = ["The key, it's unique in a collection."];
+            assert_eq!(st.fields()[0].docs(), &expected);
+        }
+        _ => panic!("`Entry` should be a struct"),
+    }
+    let expected = ["The upper limit of the size of a `Payload`, in bytes."];
+    assert_eq!(ast.major_consts()[0].docs(), &expected);
+}
+
+#[test]
+fn doc_comments_in_generated_code() {
+    let rust = include_str!(concat!(env!("OUT_DIR"), "/extensions.rs"));
+    assert!(rust.contains(r#"# [doc = " Raw bytes carried by transactions."]"#));
+    assert!(rust.contains(r#"# [doc = " Adds funds to the account."]"#));
+    let c = include_str!(concat!(env!("OUT_DIR"), "/extensions.h"));
+    let expected = "/*\n \
+                    * A transaction.\n \
+                    *\n \
+                    * The items are named, since two of them have the same type.\n \
+                    */\n\
+                    MOLECULE_API_DECORATOR  mol_errno       MolReader_Transaction_verify";
+    assert!(c.contains(expected));
+    assert!(c.contains("/*\n * Adds funds to the account.\n */\n#define"));
+}
+
+#[test]
+fn plain_comments_are_not_docs() {
+    let source = "//// a separator\n\
+                  /*** a banner ***/\n\
+                  /**/\n\
+                  // a plain comment\n\
+                  /// The only doc.\n\
+                  // another plain comment\n\
+                  array Foo [byte; 1];\n";
+    let resolver = MemoryResolver::new();
+    let ast = Parser::parse_sources(&"comments.mol", source, &resolver).unwrap();
+    assert_eq!(find_decl(&ast, "Foo").docs(), &["The only doc."]);
+}
+
+#[test]
+fn stray_doc_comments() {
+    let parse = |source: &str| {
+        let mut resolver = MemoryResolver::new();
+        resolver.insert("common.mol", "array Common [byte; 1];\n");
+        Parser::parse_sources(&"stray.mol", source, &resolver).unwrap()
+    };
+    // a license banner before the imports
+    let source = "/**\n * The license.\n */\n\
+                  import common;\n\
+                  /// The only doc.\n\
+                  array Foo [byte; 1];\n";
+    let ast = parse(source);
+    assert_eq!(find_decl(&ast, "Foo").docs(), &["The only doc."]);
+    // after the last field
+    let source = "struct Foo {\n    a: byte,\n    /// nothing follows\n}\n\
+                  table Bar {\n    a: byte,\n    /// nothing follows\n}\n\
+                  table Baz {\n    /// nothing follows\n}\n\
+                  array Qux [byte; 1];\n";
+    let ast = parse(source);
+    assert!(find_decl(&ast, "Qux").docs().is_empty());
+    // at the end of the file
+    let source = "array Foo [byte; 1];\n/// nothing follows\n";
+    let ast = parse(source);
+    assert!(find_decl(&ast, "Foo").docs().is_empty());
+    // on the same line after a field, it isn't attached to the next field
+    let source = "table Foo {\n    a: byte, /// a note\n    /// The doc of b.\n    b: byte,\n}\n";
+    let ast = parse(source);
+    match find_decl(&ast, "Foo") {
+        ast::TopDecl::Table(table) => {
+            assert!(table.fields()[0].docs().is_empty());
+            assert_eq!(table.fields()[1].docs(), &["The doc of b."]);
+        }
+        _ => panic!("`Foo` should be a table"),
+    }
+}
+
+fn checkpoint(number: u8) -> ext::Checkpoint {
+    ext::Checkpoint::new_builder()
+        .number(ext::Length::new_builder().nth0(Byte::new(number)).build())
+        .build()
+}
+
+#[test]
+fn attributes() {
+    let ast = Parser::parse(&"schemas/extensions.mol").unwrap();
+    let checkpoint = find_decl(&ast, "Checkpoint");
+    let names = checkpoint
+        .attributes()
+        .iter()
+        .map(|attr| attr.name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["rust", "c", "go"]);
+    let rust = ast::Attribute::find(checkpoint.attributes(), "rust").unwrap();
+    assert_eq!(rust.value(), None);
+    let derive = rust.arg("derive").unwrap();
+    assert_eq!(derive.value(), Some("Hash, PartialEq, Eq"));
+    let go = ast::Attribute::find(checkpoint.attributes(), "go").unwrap();
+    assert_eq!(
+        go.arg("name").and_then(ast::Attribute::value),
+        Some("ChainCheckpoint")
+    );
+    assert_eq!(go.arg("skip").map(ast::Attribute::value), Some(None));
+    match checkpoint {
+        ast::TopDecl::Struct(st) => {
+            assert!(st.fields()[0].attributes().is_empty());
+            let deprecated = &st.fields()[1].attributes()[0];
+            assert_eq!(deprecated.name(), "deprecated");
+            let note = deprecated.arg("note").and_then(ast::Attribute::value);
+            assert_eq!(note, Some("use `digest` instead"));
+        }
+        _ => panic!("`Checkpoint` should be a struct"),
+    }
+    match find_decl(&ast, "Action") {
+        ast::TopDecl::Union(union) => {
+            let deprecated = &union.items()[1].attributes()[0];
+            let expected = Some("burn the funds with a `Transfer` to nobody");
+            assert_eq!(deprecated.value(), expected);
+        }
+        _ => panic!("`Action` should be a union"),
+    }
+    assert!(ast.major_consts()[2].attributes().is_empty());
+    let empty_digest = &ast.major_consts()[3];
+    assert_eq!(empty_digest.attributes()[0].name(), "deprecated");
+    assert!(empty_digest.attributes()[0].args().is_empty());
+}
+
+#[test]
+fn rust_derives_from_attributes() {
+    use std::collections::HashSet;
+    let first = checkpoint(1);
+    let second = checkpoint(2);
+    let set = vec![first.clone(), second.clone(), checkpoint(1)]
+        .into_iter()
+        .collect::<HashSet<_>>();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains(&first));
+    assert!(first.as_reader() != second.as_reader());
+    assert!(first.as_reader() == checkpoint(1).as_reader());
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_fields_are_still_usable() {
+    let digest = ext::Digest::new_builder().nth3(Byte::new(9)).build();
+    let checkpoint = ext::Checkpoint::new_builder().hash(digest.clone()).build();
+    assert_eq!(checkpoint.hash().as_slice(), digest.as_slice());
+    assert_eq!(checkpoint.as_reader().hash().as_slice(), digest.as_slice());
+    assert_eq!(checkpoint.clone().as_builder().build(), checkpoint);
+}
+
+#[test]
+fn attributes_in_generated_code() {
+    let rust = include_str!(concat!(env!("OUT_DIR"), "/extensions.rs"));
+    let derives = "# [derive (Clone , Hash , PartialEq , Eq)] pub struct Checkpoint";
+    assert!(rust.contains(derives));
+    let deprecated = r#"# [deprecated (note = "use `digest` instead")] pub fn hash"#;
+    assert_eq!(rust.matches(deprecated).count(), 3);
+    assert!(rust.contains(r#"# [deprecated] pub const EMPTY_DIGEST"#));
+    let note = r#"# [doc = " **Deprecated**: burn the funds with a `Transfer` to nobody"] Burn"#;
+    assert!(rust.contains(note));
+    let c = include_str!(concat!(env!("OUT_DIR"), "/extensions.h"));
+    assert!(c.contains("MolReader_ExtCheckpoint_verify"));
+    assert!(!c.contains("MolReader_Checkpoint_"));
+    assert!(c.contains("/*\n * Deprecated: use `digest` instead\n */\n#define"));
+    assert!(c.contains("/*\n * Deprecated.\n */\nstatic const uint8_t MolConst_EMPTY_DIGEST"));
+}
+
+// Generates the Rust code for an array `Foo` with the derives.
+fn generate_with_derives(derives: &str) -> Result<String, Box<Error>> {
+    use std::{env, fs};
+    let out_dir = env::temp_dir().join(format!(
+        "molecule-ci-tests-derives-{}-{}",
+        std::process::id(),
+        derives.len()
+    ));
+    fs::create_dir_all(&out_dir).unwrap();
+    let schema = out_dir.join("derives.mol");
+    let source = format!("#[rust(derive = \"{}\")]\narray Foo [byte; 1];\n", derives);
+    fs::write(&schema, source).unwrap();
+    let result = Compiler::new()
+        .input_schema_file(&schema)
+        .generate_code(Language::Rust)
+        .output_dir(&out_dir)
+        .run()
+        .map(|_| fs::read_to_string(out_dir.join("derives.rs")).unwrap())
+        .map_err(Box::new);
+    fs::remove_dir_all(&out_dir).unwrap();
+    result
+}
+
+#[test]
+fn rust_derives_are_paths() {
+    let rust = generate_with_derives("serde::Serialize, ::std::hash::Hash").unwrap();
+    let derives = "# [derive (Clone , serde :: Serialize , :: std :: hash :: Hash)]";
+    assert!(rust.contains(derives));
+
+    for derives in &["serde::Serialize<T>", "Hash PartialEq", "serde::", "1Hash"] {
+        let err = generate_with_derives(derives).unwrap_err();
+        match *err {
+            Error::InvalidAttribute(Some(ref location), ref name, ref reason) => {
+                assert_eq!(name, "Foo");
+                assert!(reason.ends_with("in `derive` is not a path"), "{}", reason);
+                // the location of the `derive` argument
+                assert_eq!((location.line(), location.column()), (1, 8));
+                assert!(location.path().ends_with("derives.mol"));
+                assert!(err.to_string().contains("derives.mol:1:8"), "{}", err);
+            }
+            _ => panic!("unexpected error for `{}`: {}", derives, err),
+        }
+    }
+}
+
+#[test]
+fn c_prefix_from_attributes() {
+    let checkpoint = checkpoint(7);
+    let number = capi::checkpoint_number(checkpoint.as_slice()).unwrap();
+    assert_eq!(number, [7, 0, 0, 0]);
+    assert!(capi::checkpoint_number(&checkpoint.as_slice()[1..]).is_none());
+}
+
+#[test]
+fn hash_is_not_an_attribute_in_comments() {
+    let source = "# a comment\n\
+                  #[rust(derive = \"Hash\",)]\n\
+                  # another comment\n\
+                  #[deprecated = \"a \\\"quoted\\\" note\"]\n\
+                  array Foo [byte; 1];\n";
+    let resolver = MemoryResolver::new();
+    let ast = Parser::parse_sources(&"attributes.mol", source, &resolver).unwrap();
+    let attrs = find_decl(&ast, "Foo").attributes();
+    assert_eq!(attrs.len(), 2);
+    assert_eq!(attrs[1].value(), Some("a \"quoted\" note"));
+}
+
+#[test]
+fn builtin_integers() {
+    let header = ext::Header::new_builder()
+        .version(7u8.into())
+        .number(
+            ext::Uint64::new_builder()
+                .set_u64(0x0102_0304_0506_0708)
+                .build(),
+        )
+        .difficulty((!0u128).into())
+        .offset((-2i32).into())
+        .weights(
+            ext::Int16Vec::new_builder()
+                .push((-1i16).into())
+                .push(300i16.into())
+                .build(),
+        )
+        .build();
+    assert_eq!(header.version().get_u8(), 7);
+    assert_eq!(header.number().get_u64(), 0x0102_0304_0506_0708);
+    assert_eq!(
+        header.number().raw_data().as_ref(),
+        &[8, 7, 6, 5, 4, 3, 2, 1]
+    );
+    assert_eq!(header.difficulty().get_u128(), !0u128);
+    assert_eq!(header.offset().get_i32(), -2);
+    assert_eq!(header.offset().as_slice(), &[0xfe, 0xff, 0xff, 0xff]);
+    let reader = header.as_reader();
+    let weights = reader
+        .weights()
+        .iter()
+        .map(|weight| weight.get_i16())
+        .collect::<Vec<_>>();
+    assert_eq!(weights, [-1, 300]);
+    assert_eq!(i32::from(reader.offset().to_entity()), -2);
+    assert_eq!(format!("{}", header.offset()), "Int32(-2)");
+    assert_eq!(ext::MAX_BLOCK_NUMBER, 100_000_000u64.to_le_bytes());
+
+    let unpacked = capi::header_unpack(header.as_slice()).unwrap();
+    assert_eq!(unpacked, (0x0102_0304_0506_0708, -2));
+}
+
+#[test]
+fn builtin_integers_in_ast() {
+    let ast = Parser::parse(&"schemas/extensions.mol").unwrap();
+    match find_decl(&ast, "Uint64") {
+        ast::TopDecl::Array(array) => {
+            let builtin = ast::BuiltIn::Integer {
+                signed: false,
+                size: 8,
+            };
+            assert_eq!(array.builtin(), Some(builtin));
+            assert_eq!(array.item_count(), 8);
+            assert!(array.item().typ().is_byte());
+        }
+        _ => panic!("`Uint64` should be an array"),
+    }
+    match find_decl(&ast, "Length") {
+        ast::TopDecl::Array(array) => assert_eq!(array.builtin(), None),
+        _ => panic!("`Length` should be an array"),
+    }
+    assert_eq!(ast::BuiltIn::from_name("int128").unwrap().size(), Some(16));
+    assert_eq!(ast::BuiltIn::from_name("uint24"), None);
+}
+
+#[test]
+fn builtin_integers_conflict_with_declarations() {
+    let resolver = MemoryResolver::new();
+    let source = "array Uint32 [byte; 4];\nstruct Foo { a: uint32, }\n";
+    let err = Parser::parse_sources(&"conflict.mol", source, &resolver).unwrap_err();
+    assert!(err.to_string().contains("Uint32"), "{}", err);
+    let source = "array Uint32 [byte; 4];\nstruct Foo { a: Uint32, }\n";
+    assert!(Parser::parse_sources(&"compatible.mol", source, &resolver).is_ok());
+    let source = "array uint32 [byte; 4];\n";
+    let err = Parser::parse_sources(&"reserved.mol", source, &resolver).unwrap_err();
+    assert!(err.to_string().contains("reserved"), "{}", err);
+}
+
+fn settings(verbose: bool) -> ext::Settings {
+    let switches = ext::Switches::new_builder()
+        .enabled(true.into())
+        .modes(
+            ext::Booleanx3::new_builder()
+                .nth1(ext::Boolean::new_builder().set_bool(true).build())
+                .build(),
+        )
+        .build();
+    ext::Settings::new_builder()
+        .switches(switches)
+        .flags(
+            ext::BooleanVec::new_builder()
+                .push(false.into())
+                .push(true.into())
+                .build(),
+        )
+        .verbose(verbose.into())
+        .build()
+}
+
+#[test]
+fn builtin_bool() {
+    let settings = settings(true);
+    let reader = settings.as_reader();
+    assert!(reader.switches().enabled().get_bool());
+    let modes = reader.switches().modes();
+    assert!(!modes.nth0().get_bool());
+    assert!(modes.nth1().get_bool());
+    let flags = reader
+        .flags()
+        .iter()
+        .map(|flag| flag.get_bool())
+        .collect::<Vec<_>>();
+    assert_eq!(flags, [false, true]);
+    assert!(bool::from(settings.verbose()));
+    assert_eq!(settings.verbose().as_slice(), &[1]);
+    assert_eq!(format!("{}", settings.verbose()), "Boolean(true)");
+
+    assert_eq!(capi::settings_verify(settings.as_slice()), 0);
+    assert_eq!(capi::settings_verbose(settings.as_slice()), Some(true));
+    assert_eq!(
+        capi::settings_verbose(self::settings(false).as_slice()),
+        Some(false)
+    );
+}
+
+fn check_invalid_bool(err: VerificationError, value: u8) {
+    match err {
+        VerificationError::InvalidBool(ref name, actual) => {
+            assert_eq!(name, "BooleanReader");
+            assert_eq!(actual, value);
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+}
+
+#[test]
+fn builtin_bool_rejects_invalid_values() {
+    let mol_err_data = 0x07;
+
+    assert!(ext::BooleanReader::verify(&[0], false).is_ok());
+    assert!(ext::BooleanReader::verify(&[1], false).is_ok());
+    check_invalid_bool(ext::BooleanReader::verify(&[2], false).unwrap_err(), 2);
+
+    // every `bool` in a `Settings` should be verified, even the nested ones:
+    // the header is 16 bytes, then `switches` (4), `flags` (4 + 2) and `verbose` (1)
+    let valid = settings(false).as_slice().to_vec();
+    assert_eq!(valid.len(), 27);
+    let enabled = 16;
+    let last_mode = 19;
+    let last_flag = 25;
+    let verbose = 26;
+    for &index in &[enabled, last_mode, last_flag, verbose] {
+        let mut data = valid.clone();
+        data[index] = 0xff;
+        let err = ext::SettingsReader::verify(&data, false).unwrap_err();
+        check_invalid_bool(err, 0xff);
+        assert_eq!(capi::settings_verify(&data), mol_err_data);
+    }
+}
+
+#[test]
+fn builtin_string() {
+    let profile = ext::Profile::new_builder()
+        .name("Molecule".into())
+        .aliases(
+            ext::Utf8StringVec::new_builder()
+                .push("分子".into())
+                .push(ext::Utf8String::new_builder().set_str("mol").build())
+                .build(),
+        )
+        .build();
+    assert_eq!(profile.name().as_str().unwrap(), "Molecule");
+    assert_eq!(profile.name().to_string(), "Molecule");
+    assert_eq!(profile.name().raw_data().as_ref(), b"Molecule");
+    let reader = profile.as_reader();
+    let aliases = reader.aliases();
+    let aliases = aliases
+        .iter()
+        .map(|alias| alias.as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(aliases, ["分子", "mol"]);
+    assert_eq!(
+        format!("{}", reader.aliases()),
+        "Utf8StringVecReader [分子, mol]"
+    );
+    assert_eq!(capi::profile_verify(profile.as_slice()), 0);
+    assert_eq!(ast::BuiltIn::from_name("string").unwrap().size(), None);
+
+    // the built-in types don't shadow `bool` and `String` when they are imported by a glob
+    let rust = include_str!(concat!(env!("OUT_DIR"), "/extensions.rs"));
+    assert!(!rust.contains("pub struct String("));
+    assert!(!rust.contains("pub struct Bool("));
+    {
+        use molecule_ci_tests::extensions::*;
+        let name: String = Profile::default().name().to_string();
+        let is_empty: bool = name.is_empty();
+        assert!(is_empty);
+    }
+}
+
+#[test]
+fn builtin_string_rejects_invalid_utf8() {
+    let mol_err_data = 0x07;
+
+    let name = ext::Utf8String::from("ok");
+    assert!(ext::Utf8StringReader::verify(name.as_slice(), false).is_ok());
+    let invalid = [2, 0, 0, 0, b'o', 0xff];
+    match ext::Utf8StringReader::verify(&invalid, false).unwrap_err() {
+        VerificationError::InvalidUtf8(ref name, valid_up_to) => {
+            assert_eq!(name, "Utf8StringReader");
+            assert_eq!(valid_up_to, 1);
+        }
+        err => panic!("unexpected error: {}", err),
+    }
+
+    // the invalid string in a nested vector
+    let profile = ext::Profile::new_builder()
+        .aliases(ext::Utf8StringVec::new_builder().push("abc".into()).build())
+        .build();
+    let mut data = profile.as_slice().to_vec();
+    let last = data.len() - 1;
+    data[last] = 0x80;
+    assert!(ext::ProfileReader::verify(&data, false).is_err());
+    assert_eq!(capi::profile_verify(&data), mol_err_data);
+}
+
+#[test]
+fn unchecked_invalid_string_is_displayed_lossily() {
+    let invalid = ext::Utf8String::new_unchecked(vec![4, 0, 0, 0, b'o', 0xff, b'k', 0xe5].into());
+    assert!(invalid.as_str().is_err());
+    assert!(invalid.as_reader().as_str().is_err());
+    assert_eq!(invalid.to_string(), "o\u{FFFD}k\u{FFFD}");
+    assert_eq!(invalid.as_reader().to_string(), "o\u{FFFD}k\u{FFFD}");
+
+    // the builder doesn't check the bytes
+    let pushed = ext::Utf8String::new_builder()
+        .push(Byte::new(b'a'))
+        .push(Byte::new(0xc0))
+        .build();
+    assert!(pushed.as_str().is_err());
+    let profile = ext::Profile::new_builder().name(pushed).build();
+    assert!(profile.to_string().contains("a\u{FFFD}"));
+}
+
+#[test]
+fn utf8_verify_in_c() {
+    let cases: &[&[u8]] = &[
+        b"",
+        b"ascii",
+        "分子 ✓ 🦀".as_bytes(),
+        // overlong encodings
+        &[0xc0, 0xaf],
+        &[0xe0, 0x80, 0xaf],
+        &[0xf0, 0x80, 0x80, 0xaf],
+        // surrogates
+        &[0xed, 0xa0, 0x80],
+        &[0xed, 0x9f, 0xbf],
+        // the largest code point and beyond
+        &[0xf4, 0x8f, 0xbf, 0xbf],
+        &[0xf4, 0x90, 0x80, 0x80],
+        &[0xf5, 0x80, 0x80, 0x80],
+        // truncated sequences
+        &[0xe4, 0xb8],
+        &[b'a', 0xf0, 0x9f, 0xa6],
+        // unexpected continuation bytes
+        &[0x80],
+        &[0xe4, 0xb8, 0xad, 0xad],
+        &[0xe4, 0x41, 0xad],
+    ];
+    for case in cases {
+        let expected = ::std::str::from_utf8(case).is_ok();
+        assert_eq!(capi::utf8_verify(case), expected, "{:?}", case);
+    }
+}
+
+fn job(status: ext::StatusEnum) -> ext::Job {
+    let task = ext::Task::new_builder()
+        .status(status.into())
+        .priority(ext::PriorityEnum::High.into())
+        .build();
+    ext::Job::new_builder()
+        .task(task)
+        .history(
+            ext::StatusVec::new_builder()
+                .push(ext::StatusEnum::Pending.into())
+                .push(ext::StatusEnum::Done.into())
+                .build(),
+        )
+        .build()
+}
+
+#[test]
+fn enums() {
+    use std::convert::TryFrom;
+
+    assert_eq!(ext::Status::TOTAL_SIZE, 1);
+    assert_eq!(ext::Priority::TOTAL_SIZE, 2);
+    assert_eq!(ext::StatusEnum::Failed as u8, 7);
+    assert_eq!(u16::from(ext::PriorityEnum::Normal), 2);
+    assert_eq!(ext::StatusEnum::try_from(1).unwrap(), ext::StatusEnum::Done);
+    match ext::StatusEnum::try_from(2).unwrap_err() {
+        VerificationError::InvalidEnumValue(ref name, actual) => {
+            assert_eq!(name, "Status");
+            assert_eq!(actual, 2);
+        }
+        err => panic!("unexpected error: {}", err),
+    }
+    assert!(ext::PriorityEnum::try_from(0).is_err());
+
+    // the first item is the default value
+    assert_eq!(ext::Status::default().to_enum(), ext::StatusEnum::Pending);
+    assert_eq!(ext::Priority::default().as_slice(), &[1, 0]);
+    assert_eq!(ext::Task::default().as_slice(), &[0, 1, 0]);
+    assert_eq!(ext::DEFAULT_PRIORITY, [2, 0]);
+
+    let job = job(ext::StatusEnum::Failed);
+    let reader = job.as_reader();
+    assert_eq!(reader.task().status().to_enum(), ext::StatusEnum::Failed);
+    assert_eq!(reader.task().priority().raw_value(), 1000);
+    assert_eq!(reader.task().priority().as_slice(), &[0xe8, 0x03]);
+    let history = reader
+        .history()
+        .iter()
+        .map(|status| status.to_enum())
+        .collect::<Vec<_>>();
+    assert_eq!(history, [ext::StatusEnum::Pending, ext::StatusEnum::Done]);
+    assert!(reader.fallback().is_none());
+    assert_eq!(
+        format!("{}", job.task()),
+        "Task { status: Status(Failed), priority: Priority(High) }"
+    );
+    assert_eq!(
+        ext::StatusEnum::from(job.task().status()).item_name(),
+        "Failed"
+    );
+
+    assert_eq!(capi::job_verify(job.as_slice()), 0);
+    assert_eq!(capi::job_is_failed(job.as_slice()), Some(true));
+    let done = self::job(ext::StatusEnum::Done);
+    assert_eq!(capi::job_is_failed(done.as_slice()), Some(false));
+}
+
+#[test]
+fn enums_reject_undeclared_values() {
+    let mol_err_data = 0x07;
+
+    assert!(ext::StatusReader::verify(&[7], false).is_ok());
+    match ext::StatusReader::verify(&[2], false).unwrap_err() {
+        VerificationError::InvalidEnumValue(ref name, actual) => {
+            assert_eq!(name, "StatusReader");
+            assert_eq!(actual, 2);
+        }
+        err => panic!("unexpected error: {}", err),
+    }
+    assert!(ext::PriorityReader::verify(&[0xe8, 0x03], false).is_ok());
+    assert!(ext::PriorityReader::verify(&[0xe8, 0x04], false).is_err());
+
+    // the header is 16 bytes, then `task` (3) and `history` (4 + 2)
+    let valid = job(ext::StatusEnum::Done).as_slice().to_vec();
+    assert_eq!(valid.len(), 25);
+    let status = 16;
+    let priority = 18;
+    let last_history = 24;
+    for &index in &[status, priority, last_history] {
+        let mut data = valid.clone();
+        data[index] = 0x09;
+        assert!(ext::JobReader::verify(&data, false).is_err());
+        assert_eq!(capi::job_verify(&data), mol_err_data);
+    }
+}
+
+#[test]
+fn enums_in_ast() {
+    let ast = Parser::parse(&"schemas/extensions.mol").unwrap();
+    match find_decl(&ast, "Priority") {
+        ast::TopDecl::Enum(inner) => {
+            assert_eq!(inner.total_size(), 2);
+            let items = inner
+                .items()
+                .iter()
+                .map(|item| (item.name(), item.value()))
+                .collect::<Vec<_>>();
+            assert_eq!(items, [("Low", 1), ("Normal", 2), ("High", 1000)]);
+        }
+        _ => panic!("`Priority` should be an enum"),
+    }
+}
+
+fn digest(first: u8) -> ext::Digest {
+    ext::Digest::new_builder().nth0(Byte::new(first)).build()
+}
+
+fn ledger() -> ext::Ledger {
+    let balances = ext::Balances::new_builder()
+        .insert(digest(3), 30u64.into())
+        .insert(digest(1), 10u64.into())
+        .insert(digest(2), 20u64.into())
+        .insert(digest(1), 11u64.into())
+        .build();
+    let labels = ext::Labels::new_builder()
+        .insert("b".into(), payload(&[2]))
+        .insert("a".into(), payload(&[1]))
+        .build();
+    ext::Ledger::new_builder()
+        .balances(balances)
+        .labels(labels)
+        .build()
+}
+
+#[test]
+fn maps() {
+    let ledger = ledger();
+    let balances = ledger.balances();
+    // the entries are sorted by the keys, and the last one is kept for a duplicate key
+    assert_eq!(balances.len(), 3);
+    let keys = balances
+        .as_reader()
+        .iter()
+        .map(|entry| entry.key().as_slice()[0])
+        .collect::<Vec<_>>();
+    assert_eq!(keys, [1, 2, 3]);
+    assert_eq!(balances.get(&digest(1)).unwrap().get_u64(), 11);
+    assert_eq!(balances.get(&digest(3)).unwrap().get_u64(), 30);
+    assert!(balances.get(&digest(4)).is_none());
+    assert_eq!(
+        balances.get_entry(1).unwrap().value().get_u64(),
+        balances.get(&digest(2)).unwrap().get_u64()
+    );
+
+    let reader = ledger.as_reader();
+    let labels = reader.labels();
+    assert_eq!(labels.len(), 2);
+    assert_eq!(labels.get_entry(0).unwrap().key().as_str().unwrap(), "a");
+    let key = ext::Utf8String::from("b");
+    assert_eq!(labels.get(&key.as_reader()).unwrap().raw_data(), &[2]);
+    assert!(labels
+        .get(&ext::Utf8String::from("c").as_reader())
+        .is_none());
+
+    // the same entries are built into the same bytes, regardless of the order
+    let labels = ext::Labels::new_builder()
+        .insert("a".into(), payload(&[1]))
+        .insert("b".into(), payload(&[2]))
+        .build();
+    assert_eq!(labels.as_slice(), ledger.labels().as_slice());
+    assert_eq!(ext::Balances::default().len(), 0);
+
+    assert!(ext::LedgerReader::verify(ledger.as_slice(), false).is_ok());
+    assert_eq!(capi::ledger_verify(ledger.as_slice()), 0);
+}
+
+#[test]
+fn maps_reject_unsorted_keys() {
+    let mol_err_data = 0x07;
+    let balances = ledger().balances();
+    // the count (4), then the entries (4 + 8)
+    let key = |idx: usize| 4 + 12 * idx;
+    let cases = vec![
+        // the first two keys are swapped
+        (key(0), 2, 1),
+        // the second key is the same as the first one
+        (key(1), 1, 1),
+    ];
+    for (index, value, entry_index) in cases {
+        let mut data = balances.as_slice().to_vec();
+        data[index] = value;
+        match ext::BalancesReader::verify(&data, false).unwrap_err() {
+            VerificationError::UnsortedKeys(ref name, actual) => {
+                assert_eq!(name, "BalancesReader");
+                assert_eq!(actual, entry_index);
+            }
+            err => panic!("unexpected error: {}", err),
+        }
+        let ledger = ext::Ledger::new_builder()
+            .balances(ext::Balances::new_unchecked(data.into()))
+            .build();
+        assert!(ext::LedgerReader::verify(ledger.as_slice(), false).is_err());
+        assert_eq!(capi::ledger_verify(ledger.as_slice()), mol_err_data);
+    }
+
+    let labels = ledger().labels();
+    let first_key = labels.get_entry(0).unwrap().key().as_slice().len();
+    let data = labels.as_slice();
+    let offset = |idx: usize| molecule::unpack_number(&data[4 * (idx + 1)..]) as usize;
+    // the keys are the first fields of the tables, after their headers (12 bytes)
+    let (first, second) = (offset(0) + 12 + 4, offset(1) + 12 + 4);
+    assert_eq!(first_key, 5);
+    for &(lhs, rhs) in &[(b'b', b'a'), (b'a', b'a')] {
+        let mut data = data.to_vec();
+        data[first] = lhs;
+        data[second] = rhs;
+        assert!(ext::LabelsReader::verify(&data, false).is_err());
+        let ledger = ext::Ledger::new_builder()
+            .labels(ext::Labels::new_unchecked(data.into()))
+            .build();
+        assert_eq!(capi::ledger_verify(ledger.as_slice()), mol_err_data);
+    }
+}
+
+#[test]
+fn maps_in_ast() {
+    let ast = Parser::parse(&"schemas/extensions.mol").unwrap();
+    match find_decl(&ast, "Balances") {
+        ast::TopDecl::FixVec(inner) => {
+            assert!(inner.is_map());
+            assert_eq!(inner.item_size(), 12);
+            let (key, value) = inner.map_entry().unwrap();
+            assert_eq!((key.name(), key.typ().name()), ("key", "Digest"));
+            assert_eq!((value.name(), value.typ().name()), ("value", "Uint64"));
+        }
+        _ => panic!("`Balances` should be a fixvec"),
+    }
+    match find_decl(&ast, "Labels") {
+        ast::TopDecl::DynVec(inner) => {
+            assert!(inner.is_map());
+            assert_eq!(inner.item().typ().name(), "LabelsEntry");
+            assert!(inner.map_entry().is_some());
+        }
+        _ => panic!("`Labels` should be a dynvec"),
+    }
+    match find_decl(&ast, "StatusVec") {
+        ast::TopDecl::FixVec(inner) => {
+            assert!(!inner.is_map());
+            assert!(inner.map_entry().is_none());
+        }
+        _ => panic!("`StatusVec` should be a fixvec"),
+    }
+}
+
+fn group(port: u16, members: &[u8], notes: usize) -> ext::Group {
+    let members = ext::Members::new_builder()
+        .extend(members.iter().map(|first| digest(*first)))
+        .build();
+    let notes = ext::Notes::new_builder()
+        .extend((0..notes).map(|idx| payload(&[idx as u8])))
+        .build();
+    ext::Group::new_builder()
+        .port(ext::Port::new_unchecked(port.to_le_bytes().to_vec().into()))
+        .members(members)
+        .notes(notes)
+        .build()
+}
+
+#[test]
+fn constraints() {
+    for group in &[group(1, &[1], 0), group(49151, &[1, 2, 3], 2)] {
+        assert!(ext::GroupReader::verify(group.as_slice(), false).is_ok());
+        assert_eq!(capi::group_verify(group.as_slice()), 0);
+    }
+    // the default values satisfy the constraints
+    let group = ext::Group::default();
+    assert_eq!(group.port().as_slice(), &[1, 0]);
+    assert_eq!(group.members().len(), 1);
+    assert!(ext::GroupReader::verify(group.as_slice(), false).is_ok());
+    assert_eq!(capi::group_verify(group.as_slice()), 0);
+}
+
+#[test]
+fn constraints_reject_violations() {
+    let mol_err_constraint = 0x08;
+    let mol_err_data = 0x07;
+    let check = |err: VerificationError, name: &str, constraint: &str, value: u64| match err {
+        VerificationError::ConstraintViolated(ref actual_name, ref actual_constraint, actual) => {
+            assert_eq!(actual_name, name);
+            assert_eq!(actual_constraint, constraint);
+            assert_eq!(actual, value);
+        }
+        err => panic!("unexpected error: {}", err),
+    };
+    let cases = vec![
+        (group(0, &[1], 0), "PortReader", "min = 1", 0),
+        (group(49152, &[1], 0), "PortReader", "max = 49151", 49152),
+        (group(1, &[], 0), "MembersReader", "non_empty", 0),
+        (
+            group(1, &[1, 2, 3, 4], 0),
+            "MembersReader",
+            "max_items = 3",
+            4,
+        ),
+        (group(1, &[1], 3), "NotesReader", "max_items = 2", 3),
+    ];
+    for (group, name, constraint, value) in cases {
+        let err = ext::GroupReader::verify(group.as_slice(), false).unwrap_err();
+        check(err, name, constraint, value);
+        // the nested failures are reported as `MOL_ERR_DATA`
+        assert_eq!(capi::group_verify(group.as_slice()), mol_err_data);
+    }
+
+    // the violations of the types themselves are reported as `MOL_ERR_CONSTRAINT`
+    for port in &[0u16, 49152] {
+        assert_eq!(capi::port_verify(&port.to_le_bytes()), mol_err_constraint);
+    }
+    assert_eq!(capi::port_verify(&80u16.to_le_bytes()), 0);
+    let cases: [(&[u8], u32); 3] = [
+        (&[], mol_err_constraint),
+        (&[1, 2, 3, 4], mol_err_constraint),
+        (&[1, 2], 0),
+    ];
+    for &(members, expected) in &cases {
+        let members = group(1, members, 0).members();
+        assert_eq!(capi::members_verify(members.as_slice()), expected);
+    }
+}
+
+#[test]
+fn constraints_in_ast() {
+    let ast = Parser::parse(&"schemas/extensions.mol").unwrap();
+    match find_decl(&ast, "Port") {
+        ast::TopDecl::Array(inner) => {
+            let constraints = inner.constraints();
+            assert_eq!(constraints.min_value(), Some(1));
+            assert_eq!(constraints.max_value(), Some(49151));
+            assert_eq!(constraints.max_items(), None);
+            assert!(inner.attributes().is_empty());
+        }
+        _ => panic!("`Port` should be an array"),
+    }
+    match find_decl(&ast, "Members") {
+        ast::TopDecl::FixVec(inner) => {
+            assert!(inner.constraints().non_empty());
+            assert_eq!(inner.constraints().max_items(), Some(3));
+        }
+        _ => panic!("`Members` should be a fixvec"),
+    }
+    // the constraints are removed from the attributes, the others are kept
+    let notes = find_decl(&ast, "Notes");
+    let names = notes
+        .attributes()
+        .iter()
+        .map(|attr| attr.name())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["rust"]);
+    match notes {
+        ast::TopDecl::DynVec(inner) => {
+            assert!(!inner.constraints().non_empty());
+            assert_eq!(inner.constraints().max_items(), Some(2));
+        }
+        _ => panic!("`Notes` should be a dynvec"),
+    }
+    match find_decl(&ast, "Payload") {
+        ast::TopDecl::FixVec(inner) => assert!(inner.constraints().is_empty()),
+        _ => panic!("`Payload` should be a fixvec"),
+    }
+}
+
+#[test]
+fn table_field_defaults() {
+    let v1 = ext::AccountV1::new_builder().owner(digest(1)).build();
+    let v2 = ext::AccountV2::new_builder()
+        .owner(digest(2))
+        .level(7u8.into())
+        .build();
+
+    // an older encoding is only valid in compatible mode
+    match ext::AccountReader::verify(v1.as_slice(), false).unwrap_err() {
+        VerificationError::FieldCountNotMatch(_, expected, actual) => {
+            assert_eq!((expected, actual), (5, 1));
+        }
+        err => panic!("unexpected error: {}", err),
+    }
+    assert!(ext::Account::from_slice(v2.as_slice()).is_err());
+
+    let account = ext::Account::from_compatible_slice(v1.as_slice()).unwrap();
+    assert_eq!(account.field_count(), 1);
+    assert!(!account.has_extra_fields());
+    assert_eq!(account.count_extra_fields(), 0);
+    assert_eq!(account.owner().as_slice(), digest(1).as_slice());
+    assert_eq!(account.level().get_u8(), 3);
+    assert_eq!(account.nickname().as_str().unwrap(), "");
+    assert_eq!(account.priority().to_enum(), ext::PriorityEnum::High);
+    assert_eq!(account.port().as_slice(), &[1, 0]);
+
+    let reader = ext::AccountReader::from_compatible_slice(v2.as_slice()).unwrap();
+    assert_eq!(reader.owner().as_slice(), digest(2).as_slice());
+    assert_eq!(reader.level().get_u8(), 7);
+    assert_eq!(reader.nickname().as_str().unwrap(), "");
+    assert_eq!(reader.priority().to_enum(), ext::PriorityEnum::High);
+
+    // an upgraded encoding has all fields
+    let upgraded = account.as_builder().nickname("alice".into()).build();
+    assert_eq!(upgraded.field_count(), ext::Account::FIELD_COUNT);
+    assert_eq!(ext::Account::MIN_FIELD_COUNT, 1);
+    assert_eq!(upgraded.level().get_u8(), 3);
+    assert_eq!(upgraded.nickname().as_str().unwrap(), "alice");
+    assert!(ext::AccountReader::verify(upgraded.as_slice(), false).is_ok());
+
+    // the required fields can't be missing
+    let empty = molecule::pack_number(molecule::NUMBER_SIZE as molecule::Number);
+    assert!(ext::AccountReader::verify(&empty, true).is_err());
+    assert_ne!(capi::account_verify(&empty, true), 0);
+
+    assert_eq!(capi::account_verify(v1.as_slice(), false), 0x06);
+    assert_eq!(capi::account_verify(v1.as_slice(), true), 0);
+    assert_eq!(capi::account_level(v1.as_slice()), Some(3));
+    assert_eq!(capi::account_level(v2.as_slice()), Some(7));
+    assert_eq!(capi::account_level(upgraded.as_slice()), Some(3));
+    assert_eq!(capi::account_verify(upgraded.as_slice(), false), 0);
+}
+
+#[test]
+fn table_field_defaults_in_ast() {
+    let ast = Parser::parse(&"schemas/extensions.mol").unwrap();
+    match find_decl(&ast, "Account") {
+        ast::TopDecl::Table(inner) => {
+            assert_eq!(inner.min_field_count(), 1);
+            let defaults = inner
+                .fields()
+                .iter()
+                .map(ast::FieldDecl::default)
+                .collect::<Vec<_>>();
+            let expected: [Option<&[u8]>; 5] = [
+                None,
+                Some(&[3]),
+                Some(&[0, 0, 0, 0]),
+                Some(&[232, 3]),
+                Some(&[1, 0]),
+            ];
+            assert_eq!(defaults, expected);
+            // the defaults are removed from the attributes
+            assert!(inner.fields()[1].attributes().is_empty());
+        }
+        _ => panic!("`Account` should be a table"),
+    }
+    match find_decl(&ast, "Ledger") {
+        ast::TopDecl::Table(inner) => assert_eq!(inner.min_field_count(), 2),
+        _ => panic!("`Ledger` should be a table"),
+    }
+}
+
+fn tree_node(value: u32, children: Vec<ext::TreeNode>) -> ext::TreeNode {
+    let children = ext::TreeNodeVec::new_builder().set(children).build();
+    ext::TreeNode::new_builder()
+        .value(value.into())
+        .children(children)
+        .build()
+}
+
+#[test]
+fn recursive_types() {
+    let leaf = tree_node(3, Vec::new());
+    let tree = tree_node(1, vec![tree_node(2, vec![leaf.clone()]), leaf]);
+    let reader = ext::TreeNodeReader::from_slice(tree.as_slice()).unwrap();
+    assert_eq!(reader.value().get_u32(), 1);
+    assert_eq!(reader.children().len(), 2);
+    let child = reader.children().get(0).unwrap();
+    assert_eq!(child.children().get(0).unwrap().value().get_u32(), 3);
+    assert_eq!(capi::tree_node_verify(tree.as_slice()), 0);
+    assert_eq!(capi::tree_node_count(tree.as_slice()), Some(4));
+
+    // the default values are finite
+    assert_eq!(ext::TreeNode::default().children().len(), 0);
+    assert!(ext::ListNode::default().next().is_none());
+    assert_eq!(ext::Expr::default().item_id(), 0);
+
+    // a broken node is rejected even if it's deeply nested
+    let mut data = tree.as_slice().to_vec();
+    let len = data.len();
+    data[len - 4] = 0xff;
+    assert!(ext::TreeNodeReader::verify(&data, false).is_err());
+    assert_ne!(capi::tree_node_verify(&data), 0);
+    assert_eq!(capi::tree_node_count(&data), None);
+
+    // -(1 + 2)
+    let literal = |value: u32| {
+        ext::Expr::new_builder()
+            .set(ext::ExprUnion::Literal(value.into()))
+            .build()
+    };
+    let add = ext::BinaryExpr::new_builder()
+        .lhs(literal(1))
+        .rhs(literal(2))
+        .build();
+    let add = ext::Expr::new_builder()
+        .set(ext::ExprUnion::Add(add))
+        .build();
+    let neg = ext::Expr::new_builder()
+        .set(ext::ExprUnion::Neg(add))
+        .build();
+    let reader = ext::ExprReader::from_slice(neg.as_slice()).unwrap();
+    match reader.to_enum() {
+        ext::ExprUnionReader::Neg(inner) => match inner.to_enum() {
+            ext::ExprUnionReader::Add(inner) => {
+                assert_eq!(inner.rhs().as_slice(), literal(2).as_slice())
+            }
+            _ => panic!("the item should be `Add`"),
+        },
+        _ => panic!("the item should be `Neg`"),
+    }
+    assert_eq!(capi::expr_verify(neg.as_slice()), 0);
+
+    // an older node, which doesn't have the next node, is valid in compatible mode
+    let node = ext::ListNode::new_builder()
+        .value(1u32.into())
+        .next(
+            ext::ListNodeOpt::new_builder()
+                .set(Some(ext::ListNode::default()))
+                .build(),
+        )
+        .build();
+    assert!(node.next().to_opt().unwrap().next().is_none());
+    let mut data = molecule::pack_number(12).to_vec();
+    data.extend_from_slice(&molecule::pack_number(8));
+    data.extend_from_slice(&1u32.to_le_bytes());
+    let older = ext::ListNode::from_compatible_slice(&data).unwrap();
+    assert_eq!(older.value().get_u32(), 1);
+    assert!(older.next().is_none());
+}
+
+#[test]
+fn transitive_imports() {
+    use molecule_ci_tests::transitive::root;
+
+    let a = root::A::new_builder().x(1u32.into()).build();
+    let b = root::B::new_builder().a(a).build();
+    let data = root::Root::new_builder()
+        .w(2u32.into())
+        .b(b.clone())
+        .build();
+    let reader = root::RootReader::from_slice(data.as_slice()).unwrap();
+    assert_eq!(reader.w().get_u32(), 2);
+    assert_eq!(reader.b().as_slice(), b.as_slice());
+    assert_eq!(reader.b().a().x().get_u32(), 1);
+}
+
+// A list which has the nodes nested at the depth, the values are the depths.
+fn nested_list(depth: usize) -> Vec<u8> {
+    let mut data = Vec::with_capacity(16 * (depth + 1));
+    for i in 0..=depth {
+        let total_size = 16 * (depth - i + 1);
+        data.extend_from_slice(&molecule::pack_number(total_size as molecule::Number));
+        data.extend_from_slice(&molecule::pack_number(12));
+        data.extend_from_slice(&molecule::pack_number(16));
+        data.extend_from_slice(&(i as u32).to_le_bytes());
+    }
+    data
+}
+
+// `-(-(...(1)))`, the literal is nested at the depth.
+fn nested_expr(depth: usize) -> Vec<u8> {
+    let mut data = Vec::with_capacity(4 * (depth + 2));
+    for _ in 0..depth {
+        data.extend_from_slice(&molecule::pack_number(1));
+    }
+    data.extend_from_slice(&molecule::pack_number(0));
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data
+}
+
+#[test]
+fn recursive_types_nesting_depth() {
+    let max_depth = molecule::MAX_NESTING_DEPTH;
+    let check = |err: VerificationError, name: &str| match err {
+        VerificationError::NestingTooDeep(ref actual, limit) => {
+            assert_eq!(actual, name);
+            assert_eq!(limit, max_depth);
+        }
+        _ => panic!("the error should be `NestingTooDeep` but it's {}", err),
+    };
+
+    // a node nests the next node in an option, so each node is two levels deeper
+    let list = nested_list(max_depth / 2 - 1);
+    let reader = ext::ListNodeReader::from_slice(&list).unwrap();
+    let last = (0..max_depth / 2 - 1).fold(reader, |node, _| node.next().to_opt().unwrap());
+    assert_eq!(last.value().get_u32() as usize, max_depth / 2 - 1);
+    assert!(last.next().is_none());
+    assert_eq!(capi::list_node_verify(&list), 0);
+    let list = nested_list(max_depth / 2);
+    check(
+        ext::ListNodeReader::verify(&list, false).unwrap_err(),
+        "ListNodeReader",
+    );
+    assert_ne!(capi::list_node_verify(&list), 0);
+
+    let expr = nested_expr(max_depth - 1);
+    assert!(ext::ExprReader::verify(&expr, false).is_ok());
+    assert_eq!(capi::expr_verify(&expr), 0);
+    let expr = nested_expr(max_depth);
+    check(
+        ext::ExprReader::verify(&expr, false).unwrap_err(),
+        "ExprReader",
+    );
+    assert_eq!(capi::expr_verify(&expr), 0x09);
+
+    // the untrusted data can't overflow the stack
+    let list = nested_list(20_000);
+    check(
+        ext::ListNodeReader::verify(&list, false).unwrap_err(),
+        "ListNodeReader",
+    );
+    assert!(ext::ListNodeReader::from_compatible_slice(&list).is_err());
+    assert_ne!(capi::list_node_verify(&list), 0);
+    let expr = nested_expr(20_000);
+    check(
+        ext::ExprReader::verify(&expr, false).unwrap_err(),
+        "ExprReader",
+    );
+    assert_ne!(capi::expr_verify(&expr), 0);
+}
+
+#[test]
+fn recursive_types_in_ast() {
+    let ast = Parser::parse(&"schemas/extensions.mol").unwrap();
+    let (tree, children) = match find_decl(&ast, "TreeNode") {
+        ast::TopDecl::Table(inner) => (inner, inner.fields()[1].typ()),
+        _ => panic!("`TreeNode` should be a table"),
+    };
+    match children.as_ref() {
+        ast::TopDecl::DynVec(inner) => assert_eq!(inner.item().typ().name(), tree.name()),
+        _ => panic!("`TreeNodeVec` should be a dynvec"),
+    }
+    match find_decl(&ast, "ListNode") {
+        ast::TopDecl::Table(inner) => assert_eq!(inner.fields()[1].default(), Some(&[][..])),
+        _ => panic!("`ListNode` should be a table"),
+    }
+    // the recursive types could be printed
+    assert!(format!("{:?}", ast).contains("TypeRef(TreeNodeVec)"));
+    // the recursive types refer to each other weakly, so they are freed with the AST, then the
+    // types which are referred weakly are gone even if the declarations referring them are kept
+    let decls = ast.decls().iter().map(Rc::downgrade).collect::<Vec<_>>();
+    drop(ast);
+    match children.as_ref() {
+        ast::TopDecl::DynVec(inner) => assert!(inner.item().try_typ().is_none()),
+        _ => panic!("`TreeNodeVec` should be a dynvec"),
+    }
+    drop(children);
+    assert!(decls.iter().all(|decl| decl.upgrade().is_none()));
+
+    let resolver = MemoryResolver::new();
+    let source = "table Foo { bar: Bar, }\ntable Bar { foo: Foo, }\n";
+    match Parser::parse_sources(&"x.mol", source, &resolver)
+        .err()
+        .unwrap()
+    {
+        codegen::Error::CyclicDependency(_, ref names) => assert_eq!(names, &["Foo", "Bar"]),
+        err => panic!("unexpected error: {}", err),
+    }
+}
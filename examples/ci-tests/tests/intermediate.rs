@@ -0,0 +1,201 @@
+use std::{env, fs, path::PathBuf};
+
+use codegen::{ast::HasName as _, ir, Compiler, Error, IntermediateFormat, Parser};
+
+fn intermediate(format: IntermediateFormat, with_layout: bool) -> Vec<u8> {
+    let out_dir = env::temp_dir().join(format!(
+        "molecule-ci-tests-{}-{}-{}",
+        std::process::id(),
+        format,
+        with_layout
+    ));
+    fs::create_dir_all(&out_dir).unwrap();
+    Compiler::new()
+        .input_schema_file("schemas/extensions.mol")
+        .generate_intermediate(format)
+        .intermediate_layout(with_layout)
+        .output_dir(&out_dir)
+        .run()
+        .unwrap();
+    let mut path = PathBuf::from(&out_dir);
+    path.push("extensions");
+    path.set_extension(match format {
+        IntermediateFormat::JSON => "json",
+        IntermediateFormat::YAML => "yaml",
+        IntermediateFormat::Molecule => "bin",
+    });
+    let data = fs::read(&path).unwrap();
+    fs::remove_dir_all(&out_dir).unwrap();
+    data
+}
+
+#[test]
+fn layout_is_optional() {
+    let data = intermediate(IntermediateFormat::JSON, false);
+    let text = String::from_utf8(data).unwrap();
+    assert!(!text.contains("\"layout\""));
+}
+
+#[test]
+fn layout_in_intermediate() {
+    let data = intermediate(IntermediateFormat::JSON, true);
+    let text = String::from_utf8(data.clone()).unwrap();
+    // `struct Entry { key: [byte; 32], value: [Digest; 2] }`
+    let entry = text
+        .find("\"name\": \"Entry\"")
+        .map(|start| &text[start..])
+        .unwrap();
+    let layout = &entry[entry.find("\"layout\"").unwrap()..];
+    let layout = layout[..layout.find('}').unwrap()]
+        .split_whitespace()
+        .collect::<String>();
+    assert!(layout.contains("\"is_fixed_size\":true"));
+    assert!(layout.contains("\"total_size\":40"));
+    assert!(layout.contains("\"field_offsets\":[0,32]"));
+
+    // the layout is checked when it's recovered
+    let recovered = IntermediateFormat::JSON.recover(&data).unwrap();
+    let parsed = Parser::parse(&"schemas/extensions.mol").unwrap();
+    let names = |ast: &codegen::ast::Ast| {
+        ast.decls()
+            .iter()
+            .map(|decl| decl.name().to_owned())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(names(&recovered), names(&parsed));
+    let data = intermediate(IntermediateFormat::YAML, true);
+    assert!(IntermediateFormat::YAML.recover(&data).is_ok());
+}
+
+#[test]
+fn layout_in_ast() {
+    let ast = Parser::parse(&"schemas/extensions.mol").unwrap();
+    let find = |name: &str| ast.decls().iter().find(|decl| decl.name() == name).unwrap();
+    let entry = find("Entry");
+    assert!(entry.is_fixed_size());
+    assert_eq!(entry.total_size(), Some(40));
+    match entry.as_ref() {
+        codegen::ast::TopDecl::Struct(inner) => assert_eq!(inner.field_offsets(), vec![0, 32]),
+        _ => panic!("`Entry` should be a struct"),
+    }
+    assert!(!find("TreeNode").is_fixed_size());
+    assert_eq!(find("TreeNode").total_size(), None);
+}
+
+#[test]
+fn molecule_intermediate() {
+    // the recovered ASTs are same, no matter how the intermediate data is encoded
+    let data = intermediate(IntermediateFormat::Molecule, true);
+    let recovered = IntermediateFormat::Molecule.recover(&data).unwrap();
+    let expected = IntermediateFormat::JSON
+        .recover(&intermediate(IntermediateFormat::JSON, true))
+        .unwrap();
+    assert_eq!(format!("{:?}", recovered), format!("{:?}", expected));
+    let data = intermediate(IntermediateFormat::Molecule, false);
+    assert!(IntermediateFormat::Molecule.recover(&data).is_ok());
+
+    match IntermediateFormat::Molecule.recover(&data[..data.len() - 1]) {
+        Err(Error::Intermediate(reason)) => {
+            assert!(
+                reason.starts_with("failed to deserialize Molecule"),
+                "{}",
+                reason
+            )
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+}
+
+#[test]
+fn public_intermediate_data() {
+    let data = intermediate(IntermediateFormat::JSON, true);
+    let ir = IntermediateFormat::JSON.deserialize(&data).unwrap();
+    assert_eq!(ir.namespace(), "extensions");
+    let find = |name: &str| ir.decls().iter().find(|decl| decl.name() == name).unwrap();
+    match find("Entry") {
+        ir::TopDecl::Struct(inner) => {
+            let fields = inner
+                .fields()
+                .iter()
+                .map(|field| field.name())
+                .collect::<Vec<_>>();
+            assert_eq!(fields, vec!["key", "value"]);
+            assert_eq!(inner.docs(), &[] as &[String]);
+            assert_eq!(inner.fields()[0].docs().len(), 1);
+        }
+        _ => panic!("`Entry` should be a struct"),
+    }
+    let layout = find("Entry").layout().unwrap();
+    assert!(layout.is_fixed_size());
+    assert_eq!(layout.field_offsets(), &[0, 32]);
+    match find("Message") {
+        ir::TopDecl::Union(inner) => {
+            let names = inner
+                .items()
+                .iter()
+                .map(ir::UnionItemDecl::name)
+                .collect::<Vec<_>>();
+            assert_eq!(names, vec![None, Some("Text")]);
+        }
+        _ => panic!("`Message` should be a union"),
+    }
+
+    // the intermediate data could be encoded again, and recovered as the verified AST
+    let expected = format!("{:?}", IntermediateFormat::JSON.recover(&data).unwrap());
+    let encoded = IntermediateFormat::Molecule.serialize(&ir).unwrap();
+    assert_eq!(format!("{:?}", ir.into_ast().unwrap()), expected);
+    let recovered = IntermediateFormat::Molecule.recover(&encoded).unwrap();
+    assert_eq!(format!("{:?}", recovered), expected);
+}
+
+#[test]
+fn invalid_intermediate_data() {
+    let recover = |decls: &str, consts: &str| {
+        let text = format!(
+            r#"{{"namespace": "x", "imports": [], "declarations": [{}], "constants": [{}]}}"#,
+            decls, consts
+        );
+        match IntermediateFormat::JSON.recover(text.as_bytes()) {
+            Err(Error::Intermediate(reason)) => reason,
+            result => panic!("unexpected result: {:?}", result),
+        }
+    };
+    let bytes = r#"{"type": "array", "name": "Bytes2", "item": "byte", "item_count": 2}"#;
+    let table = |default: &str| {
+        format!(
+            r#"{{"type": "table", "name": "A", "fields": [{{"name": "b", "type": "Bytes2"{}}}]}}"#,
+            default
+        )
+    };
+
+    let reason = recover(&table(""), "");
+    assert!(reason.contains("(A) depends on the types which are not declared"));
+    let reason = recover(&format!("{}, {}", bytes, bytes), "");
+    assert!(reason.contains("(Bytes2) is used more than once"));
+    let reason = recover(bytes, r#"{"name": "C", "type": "Bytes2", "value": "0102"}"#);
+    assert!(reason.contains("the hex string (0102) doesn't start with 0x"));
+    let reason = recover(
+        bytes,
+        r#"{"name": "C", "type": "Bytes2", "value": "0x010"}"#,
+    );
+    assert!(reason.contains("the hex string (0x010) has odd length"));
+    let reason = recover(
+        &format!("{}, {}", bytes, table(r#", "default": "0xzz""#)),
+        "",
+    );
+    assert!(reason.contains("the hex string (0xzz) has invalid digits"));
+    let reason = recover(
+        &format!("{}, {}", bytes, table(r#", "default": "0x+f""#)),
+        "",
+    );
+    assert!(reason.contains("the hex string (0x+f) has invalid digits"));
+    let layout = r#""layout": {"is_fixed_size": true, "total_size": 3, "default": "0x000000"}"#;
+    let reason = recover(&bytes.replace("}", &format!(", {}}}", layout)), "");
+    assert!(reason.contains("the layout of type (Bytes2) doesn't match"));
+    let layout = r#""layout": {"is_fixed_size": true, "total_size": 2, "default": "0000"}"#;
+    let reason = recover(&bytes.replace("}", &format!(", {}}}", layout)), "");
+    assert!(reason.contains("the hex string (0000) doesn't start with 0x"));
+    let layout = r#""layout": {"is_fixed_size": true, "total_size": 3, "default": "0x000000"}"#;
+    let reason = recover(&bytes.replace("}", &format!(", {}}}", layout)), "");
+    assert!(reason.contains("the layout of type (Bytes2) doesn't match"));
+}
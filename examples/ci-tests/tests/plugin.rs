@@ -0,0 +1,251 @@
+use std::{env, fs, path::PathBuf};
+
+use codegen::{
+    plugin::{Diagnostic, Feature, Handshake, Level, Manifest, INTERMEDIATE_VERSION},
+    Compiler, Error, IntermediateFormat,
+};
+
+fn handshake(json: &str) -> Handshake {
+    Handshake::from_json(json.as_bytes()).unwrap()
+}
+
+#[test]
+fn handshake_round_trip() {
+    let handshake = Handshake::new(
+        &[IntermediateFormat::Molecule, IntermediateFormat::JSON],
+        &[Feature::Attributes],
+    );
+    let handshake = Handshake::from_json(handshake.to_json().as_bytes()).unwrap();
+    assert_eq!(handshake.intermediate_version(), INTERMEDIATE_VERSION);
+    assert_eq!(handshake.formats(), &["molecule", "json"]);
+    assert!(handshake.supports(Feature::Attributes));
+    assert!(!handshake.supports(Feature::DocComments));
+    assert_eq!(handshake.supported_features(), vec![Feature::Attributes]);
+    match handshake.negotiate() {
+        Ok(IntermediateFormat::Molecule) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+    assert!(!handshake.manifest());
+    assert!(Handshake::from_json(b"JSON\n").is_err());
+
+    let handshake = Handshake::new(&[IntermediateFormat::JSON], &[]).with_manifest(true);
+    assert!(Handshake::from_json(handshake.to_json().as_bytes())
+        .unwrap()
+        .manifest());
+}
+
+#[test]
+fn handshake_negotiation() {
+    // the unknown formats and features are skipped
+    let result = handshake(
+        r#"{"protocol": 1, "intermediate_version": "1.9.0",
+            "formats": ["xml", "YAML"], "features": ["layout", "future"]}"#,
+    )
+    .negotiate();
+    match result {
+        Ok(IntermediateFormat::YAML) => {}
+        result => panic!("unexpected result: {:?}", result),
+    }
+
+    let err = handshake(r#"{"protocol": 2, "intermediate_version": "1.0.0", "formats": ["json"]}"#)
+        .negotiate()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        "the plugin uses the protocol version 2, but the compiler uses 1"
+    );
+    let err = handshake(r#"{"protocol": 1, "intermediate_version": "2.0.0", "formats": ["json"]}"#)
+        .negotiate()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        "the plugin supports the intermediate data 2.0.0, but the compiler generates 1.0.0"
+    );
+    let err = handshake(r#"{"protocol": 1, "intermediate_version": "1.0.0", "formats": ["xml"]}"#)
+        .negotiate()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        "the plugin doesn't support any format of the compiler: [xml]"
+    );
+}
+
+#[test]
+fn diagnostics() {
+    let diagnostic =
+        Diagnostic::warning("the type isn't supported".to_owned()).with_declaration("Foo");
+    let json = diagnostic.to_json();
+    assert_eq!(
+        json,
+        r#"{"level":"warning","message":"the type isn't supported","declaration":"Foo"}"#
+    );
+    let diagnostic = Diagnostic::from_json(&json).unwrap();
+    assert_eq!(*diagnostic.level(), Level::Warning);
+    assert_eq!(
+        diagnostic.to_string(),
+        "warning: the type isn't supported\n  = note: in the declaration `Foo`"
+    );
+    let diagnostic = Diagnostic::from_json(r#"{"level": "error", "message": "boom"}"#).unwrap();
+    assert_eq!(diagnostic.to_string(), "error: boom");
+    assert!(Diagnostic::from_json("thread 'main' panicked").is_none());
+}
+
+#[test]
+fn unsupported_features_are_removed() {
+    let intermediate = |features: &[Feature]| {
+        let out_dir = env::temp_dir().join(format!(
+            "molecule-ci-tests-plugin-{}-{}",
+            std::process::id(),
+            features.len()
+        ));
+        fs::create_dir_all(&out_dir).unwrap();
+        Compiler::new()
+            .input_schema_file("schemas/extensions.mol")
+            .intermediate_features(features)
+            .generate_intermediate(IntermediateFormat::JSON)
+            .output_dir(&out_dir)
+            .run()
+            .unwrap();
+        let mut path = PathBuf::from(&out_dir);
+        path.push("extensions.json");
+        let text = fs::read_to_string(&path).unwrap();
+        fs::remove_dir_all(&out_dir).unwrap();
+        text
+    };
+    let text = intermediate(&Feature::all());
+    assert!(text.contains("\"docs\""));
+    assert!(text.contains("\"attributes\""));
+    let text = intermediate(&[Feature::Attributes]);
+    assert!(!text.contains("\"docs\""));
+    assert!(text.contains("\"attributes\""));
+    let text = intermediate(&[]);
+    assert!(!text.contains("\"docs\""));
+    assert!(!text.contains("\"attributes\""));
+    assert!(IntermediateFormat::JSON.recover(text.as_bytes()).is_ok());
+}
+
+#[test]
+fn manifest_round_trip() {
+    let mut manifest = Manifest::new();
+    manifest
+        .add_file("types.h", "#define TYPES_H\n".to_owned())
+        .add_file("types/impl.c", String::new());
+    let json = manifest.to_json();
+    assert_eq!(
+        json,
+        r##"{"files":[{"path":"types.h","content":"#define TYPES_H\n"},{"path":"types/impl.c","content":""}]}"##
+    );
+    let manifest = Manifest::from_json(json.as_bytes()).unwrap();
+    let paths = manifest
+        .files()
+        .iter()
+        .map(|file| file.path())
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["types.h", "types/impl.c"]);
+    assert!(Manifest::from_json(b"int a;").is_err());
+}
+
+// Runs a fake plugin which outputs the manifest, returns the output directory.
+#[cfg(unix)]
+fn run_manifest_plugin(manifest: &Manifest) -> (PathBuf, Result<(), Error>) {
+    use std::process::{Command, Stdio};
+
+    let out_dir = env::temp_dir().join(format!(
+        "molecule-ci-tests-manifest-{}-{}",
+        std::process::id(),
+        manifest.files().len()
+    ));
+    fs::create_dir_all(&out_dir).unwrap();
+    let script = format!("cat > /dev/null; printf '%s' '{}'", manifest.to_json());
+    let child = Command::new("sh")
+        .arg("-c")
+        .arg(script)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let result = Compiler::new()
+        .input_schema_file("schemas/extensions.mol")
+        .generate_intermediate(IntermediateFormat::JSON)
+        .output_plugin_manifest(child, &out_dir)
+        .run();
+    (out_dir, result)
+}
+
+#[cfg(unix)]
+#[test]
+fn manifest_is_written() {
+    let mut manifest = Manifest::new();
+    manifest
+        .add_file("a.h", "int a;\n".to_owned())
+        .add_file("./nested/b.c", "int b;\n".to_owned());
+    let (out_dir, result) = run_manifest_plugin(&manifest);
+    result.unwrap();
+    assert_eq!(fs::read_to_string(out_dir.join("a.h")).unwrap(), "int a;\n");
+    assert_eq!(
+        fs::read_to_string(out_dir.join("nested").join("b.c")).unwrap(),
+        "int b;\n"
+    );
+    fs::remove_dir_all(&out_dir).unwrap();
+
+    let mut manifest = Manifest::new();
+    manifest
+        .add_file("c.h", String::new())
+        .add_file("../d.h", String::new())
+        .add_file("e.h", String::new());
+    let (out_dir, result) = run_manifest_plugin(&manifest);
+    match result {
+        Err(Error::PluginOutput(ref reason)) => {
+            assert_eq!(reason, "the path `../d.h` is out of the output directory")
+        }
+        _ => panic!("unexpected result: {:?}", result),
+    }
+    // nothing is written if any path is invalid
+    assert!(!out_dir.join("c.h").exists());
+    fs::remove_dir_all(&out_dir).unwrap();
+}
+
+// Runs a fake plugin which outputs the code to stdout directly, returns the error if it fails.
+#[cfg(unix)]
+fn run_plugin(script: &str) -> Option<Error> {
+    use std::process::{Command, Stdio};
+
+    let child = Command::new("sh")
+        .arg("-c")
+        .arg(script)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    Compiler::new()
+        .input_schema_file("../../test/schemas/types.mol")
+        .generate_intermediate(IntermediateFormat::JSON)
+        .output_plugin_process(child)
+        .run()
+        .err()
+}
+
+#[cfg(unix)]
+#[test]
+fn plugin_process() {
+    // the plugin writes a lot to stderr before it reads the input, which is larger than a pipe
+    let script = "yes 'a line' | head -n 100000 >&2; cat > /dev/null";
+    if let Some(err) = run_plugin(script) {
+        panic!("unexpected error: {}", err);
+    }
+
+    let result = run_plugin("cat > /dev/null; exit 3");
+    match result {
+        Some(Error::Plugin(ref reason)) => assert!(reason.contains("exited"), "{}", reason),
+        _ => panic!("unexpected result: {:?}", result),
+    }
+    let diagnostic = Diagnostic::new(Level::Error, "failed".to_owned());
+    let script = format!("cat > /dev/null; echo '{}' >&2", diagnostic.to_json());
+    let result = run_plugin(&script);
+    match result {
+        Some(Error::Plugin(ref reason)) => assert_eq!(reason, "it reported errors"),
+        _ => panic!("unexpected result: {:?}", result),
+    }
+}
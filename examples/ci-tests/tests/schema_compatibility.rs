@@ -0,0 +1,108 @@
+use codegen::{check_compatibility, Compatibility, CompatibilityReport, Parser};
+
+fn report() -> CompatibilityReport {
+    let old = Parser::parse(&"schemas/compat/v1.mol").unwrap();
+    let new = Parser::parse(&"schemas/compat/v2.mol").unwrap();
+    check_compatibility(&old, &new)
+}
+
+fn check(report: &CompatibilityReport, name: &str, expected: Compatibility, reasons: &[&str]) {
+    let change = report
+        .changes()
+        .iter()
+        .find(|change| change.name() == name)
+        .unwrap_or_else(|| panic!("`{}` should be compared", name));
+    assert_eq!(*change.compatibility(), expected, "{}", change);
+    assert_eq!(change.reasons(), reasons, "{}", change);
+}
+
+#[test]
+fn identical_schemas() {
+    let old = Parser::parse(&"schemas/compat/v1.mol").unwrap();
+    let new = Parser::parse(&"schemas/compat/v1.mol").unwrap();
+    let report = check_compatibility(&old, &new);
+    assert_eq!(report.compatibility(), Compatibility::Identical);
+    assert!(report.changes().iter().all(|change| change.is_identical()));
+    assert_eq!(report.to_string(), "the new schema is identical");
+}
+
+#[test]
+fn compatible_changes() {
+    let report = report();
+    check(&report, "Hash", Compatibility::Identical, &[]);
+    check(&report, "Points", Compatibility::Identical, &[]);
+    check(
+        &report,
+        "Message",
+        Compatibility::Compatible,
+        &["the item `Hash` (id 2) is added"],
+    );
+    check(
+        &report,
+        "Color",
+        Compatibility::Compatible,
+        &["the item `Blue` (value 2) is added"],
+    );
+}
+
+#[test]
+fn breaking_changes() {
+    let report = report();
+    assert!(report.is_breaking());
+    check(
+        &report,
+        "Nonce",
+        Compatibility::Breaking,
+        &["the array is resized from 8 to 16 items"],
+    );
+    check(
+        &report,
+        "Point",
+        Compatibility::Breaking,
+        &["the type of the field `y` is changed from `Uint32` to `Uint64`"],
+    );
+    check(
+        &report,
+        "Command",
+        Compatibility::Breaking,
+        &[
+            "the type of the item `Shape` is changed from `Shape` to `Point`",
+            "the item `Shape` is renamed to `Point`",
+            "the type of the item `Point` is changed from `Point` to `Shape`",
+            "the item `Point` is renamed to `Shape`",
+        ],
+    );
+    check(
+        &report,
+        "Removed",
+        Compatibility::Breaking,
+        &["it's removed"],
+    );
+    // the types are compared one by one, the changes of `Nonce` aren't reported by `Shape`
+    check(
+        &report,
+        "Shape",
+        Compatibility::Breaking,
+        &[
+            "the field `nonce` is appended without a default value, so the older data \
+             can't be decoded by the newer schema",
+            "the field `color` is appended",
+        ],
+    );
+    // the older data which miss the field are decoded as different values
+    check(
+        &report,
+        "Profile",
+        Compatibility::Breaking,
+        &["the default value of the field `level` is changed"],
+    );
+    check(
+        &report,
+        "Uint64",
+        Compatibility::Compatible,
+        &["it's added"],
+    );
+    let output = report.to_string();
+    assert!(output.starts_with("breaking   Nonce\n           - the array is resized"));
+    assert!(output.ends_with("the new schema is breaking"));
+}
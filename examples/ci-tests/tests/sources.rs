@@ -0,0 +1,110 @@
+use std::path::Path;
+
+use codegen::{ast::HasName as _, EmbeddedResolver, Error, FileResolver, MemoryResolver, Parser};
+
+const IMPORT_SCHEMAS: EmbeddedResolver = EmbeddedResolver::new(&[
+    ("import/a/a.mol", include_str!("../schemas/import/a/a.mol")),
+    ("import/b/b.mol", include_str!("../schemas/import/b/b.mol")),
+    (
+        "import/b/bb/bb.mol",
+        include_str!("../schemas/import/b/bb/bb.mol"),
+    ),
+    (
+        "import/b/bc/bc.mol",
+        include_str!("../schemas/import/b/bc/bc.mol"),
+    ),
+    (
+        "import/b/test.mol",
+        include_str!("../schemas/import/b/test.mol"),
+    ),
+    ("import/c/c.mol", include_str!("../schemas/import/c/c.mol")),
+    (
+        "import/c/cc/cc.mol",
+        include_str!("../schemas/import/c/cc/cc.mol"),
+    ),
+]);
+
+fn decl_names(ast: &codegen::ast::Ast) -> Vec<String> {
+    ast.decls()
+        .iter()
+        .map(|decl| decl.name().to_owned())
+        .collect()
+}
+
+#[test]
+fn parse_embedded_sources() {
+    let path = "import/b/test.mol";
+    let source = include_str!("../schemas/import/b/test.mol");
+    let embedded = Parser::parse_sources(&path, source, &IMPORT_SCHEMAS).unwrap();
+    let file = Parser::parse(&"schemas/import/b/test.mol").unwrap();
+    assert_eq!(embedded.namespace(), "test");
+    assert_eq!(decl_names(&embedded), decl_names(&file));
+    assert_eq!(embedded.major_decls().len(), 1);
+}
+
+#[test]
+fn parse_memory_sources() {
+    let mut resolver = MemoryResolver::new();
+    resolver
+        .insert("common/basic.mol", "array Uint32 [byte; 4];")
+        .insert("common/hash.mol", "import basic;\narray Hash [byte; 32];");
+    let source = "import common/hash;\nimport common/basic;\n\n\
+                  struct Header {\n    number: Uint32,\n    parent: Hash,\n}\n";
+    let ast = Parser::parse_sources(&"root.mol", source, &resolver).unwrap();
+    assert_eq!(ast.namespace(), "root");
+    assert_eq!(decl_names(&ast), vec!["Header", "Hash", "Uint32"]);
+    assert_eq!(ast.imports().len(), 2);
+}
+
+#[test]
+fn parse_memory_sources_missing_import() {
+    let resolver = MemoryResolver::new();
+    let source = "import missing;\narray Hash [byte; 32];";
+    let err = Parser::parse_sources(&"root.mol", source, &resolver)
+        .err()
+        .unwrap();
+    match err {
+        Error::UnresolvedImport(ref location, ref path, _) => {
+            assert!(path.ends_with("missing.mol"));
+            assert_eq!((location.line(), location.column()), (1, 8));
+            assert_eq!(location.path(), Path::new("root.mol"));
+        }
+        _ => panic!("unexpected error: {}", err),
+    }
+    assert!(err.to_string().contains("--> root.mol:1:8"), "{}", err);
+}
+
+#[test]
+fn parse_with_import_paths() {
+    let path = "schemas/include/uses_a.mol";
+    let err = Parser::parse(&path).err().unwrap();
+    match err {
+        Error::UnresolvedImport(_, ref path, _) => assert!(path.ends_with("a.mol")),
+        _ => panic!("unexpected error: {}", err),
+    }
+    let mut resolver = FileResolver::new();
+    resolver
+        .import_path("schemas/import/b")
+        .import_path("schemas/import/a");
+    let source = std::fs::read_to_string(path).unwrap();
+    let ast = Parser::parse_sources(&path, &source, &resolver).unwrap();
+    assert_eq!(decl_names(&ast), vec!["UsesA", "A"]);
+    let resolved_path = ast.imports()[0].resolved_path().unwrap();
+    assert_eq!(resolved_path, Path::new("schemas/import/a/a.mol"));
+}
+
+#[test]
+fn inline_types_in_imported_schemas() {
+    let mut resolver = MemoryResolver::new();
+    resolver.insert("lib.mol", "table Foo {\n    a: vector<byte>,\n}\n");
+    let source = "import lib;\n\ntable Bar {\n    b: vector<byte>,\n    c: Foo,\n}\n";
+    let ast = Parser::parse_sources(&"root.mol", source, &resolver).unwrap();
+    assert_eq!(decl_names(&ast), vec!["ByteVec", "Bar", "Foo"]);
+    // the inline type is only generated in the imported schema
+    let major = ast
+        .major_decls()
+        .iter()
+        .map(|decl| decl.name().to_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(major, vec!["Bar"]);
+}
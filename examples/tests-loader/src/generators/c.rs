@@ -134,9 +134,8 @@ impl GenTest for types::Union {
                 union
                     .items()
                     .iter()
-                    .enumerate()
-                    .find(|(_, inner_item)| inner_item.typ().name() == item.typ())
-                    .map(|(index, _)| index)
+                    .find(|inner_item| inner_item.typ().name() == item.typ())
+                    .map(|inner_item| inner_item.id())
                     .unwrap()
             } else {
                 panic!("Error: type for {} is incorrect", self.name());
@@ -236,12 +235,12 @@ impl GenTest for types::StructOrTable {
             is_struct = true;
             decl.fields()
                 .iter()
-                .map(|field| (field.name(), field.typ().name()))
+                .map(|field| (field.name(), field.typ().name().to_owned()))
                 .collect::<HashMap<_, _>>()
         } else if let ast::TopDecl::Table(ref decl) = decl.as_ref() {
             decl.fields()
                 .iter()
-                .map(|field| (field.name(), field.typ().name()))
+                .map(|field| (field.name(), field.typ().name().to_owned()))
                 .collect::<HashMap<_, _>>()
         } else {
             panic!("Error: type for {} is incorrect", self.name());
@@ -157,12 +157,12 @@ impl GenTest for types::StructOrTable {
         let field_type_dict = if let ast::TopDecl::Struct(ref decl) = decl.as_ref() {
             decl.fields()
                 .iter()
-                .map(|field| (field.name(), field.typ().name()))
+                .map(|field| (field.name(), field.typ().name().to_owned()))
                 .collect::<HashMap<_, _>>()
         } else if let ast::TopDecl::Table(ref decl) = decl.as_ref() {
             decl.fields()
                 .iter()
-                .map(|field| (field.name(), field.typ().name()))
+                .map(|field| (field.name(), field.typ().name().to_owned()))
                 .collect::<HashMap<_, _>>()
         } else {
             panic!("Error: type for {} is incorrect", self.name());
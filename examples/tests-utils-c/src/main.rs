@@ -11,7 +11,10 @@ fn main() {
     let ast = {
         let filepath = &args[1];
         use codegen::ast::HasName;
-        let ast = codegen::Parser::parse(filepath);
+        let ast = codegen::Parser::parse(filepath).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            process::exit(1);
+        });
         ast.decls()
             .iter()
             .map(|decl| (decl.name().to_owned(), Rc::clone(decl)))
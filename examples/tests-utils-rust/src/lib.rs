@@ -36,7 +36,7 @@ pub fn load_tests(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         let ast = {
             use codegen::ast::HasName;
             let filepath = &input.schema;
-            let ast = codegen::Parser::parse(filepath);
+            let ast = codegen::Parser::parse(filepath).unwrap_or_else(|err| panic!("{}", err));
             ast.decls()
                 .iter()
                 .map(|decl| (decl.name().to_owned(), Rc::clone(decl)))
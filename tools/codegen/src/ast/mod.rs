@@ -2,6 +2,7 @@ pub(crate) mod raw;
 pub(crate) mod verified;
 
 pub use verified::{
-    Array, Ast, DefaultContent, DynVec, FieldDecl, FixVec, HasName, ImportStmt, ItemDecl, Option_,
-    Primitive, Struct, Table, TopDecl, Union,
+    Array, Ast, Attribute, BuiltIn, Const, Constraints, DefaultContent, DynVec, Enum, EnumItemDecl,
+    FieldDecl, FixVec, HasName, ImportStmt, ItemDecl, Option_, Primitive, Struct, Table, TopDecl,
+    Union, UnionItemDecl,
 };
@@ -2,6 +2,8 @@ use std::path::PathBuf;
 
 use property::Property;
 
+use crate::{ast::BuiltIn, error::Location};
+
 mod utils;
 
 #[derive(Debug, Default, Property)]
@@ -9,6 +11,7 @@ pub(crate) struct Ast {
     namespace: String,
     imports: Vec<ImportStmt>,
     decls: Vec<TopDecl>,
+    consts: Vec<ConstDecl>,
 }
 
 #[derive(Debug, Clone, Property)]
@@ -18,6 +21,8 @@ pub(crate) struct ImportStmt {
     path_supers: usize,
     imported_base: PathBuf,
     imported_depth: usize,
+    location: Location,
+    resolved_path: Option<PathBuf>,
 }
 
 #[derive(Debug)]
@@ -28,33 +33,49 @@ pub(crate) enum TopDecl {
     Struct(StructDecl),
     Vector(VectorDecl),
     Table(TableDecl),
+    Enum(EnumDecl),
 }
 
 #[derive(Debug, Property)]
 pub(crate) struct OptionDecl {
     name: String,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
+    location: Location,
     item: ItemDecl,
     imported_depth: usize,
+    anonymous: bool,
 }
 
 #[derive(Debug, Property)]
 pub(crate) struct UnionDecl {
     name: String,
-    items: Vec<ItemDecl>,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
+    location: Location,
+    items: Vec<UnionItemDecl>,
     imported_depth: usize,
 }
 
 #[derive(Debug, Property)]
 pub(crate) struct ArrayDecl {
     name: String,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
+    location: Location,
     item: ItemDecl,
     item_count: usize,
     imported_depth: usize,
+    anonymous: bool,
+    builtin: Option<BuiltIn>,
 }
 
 #[derive(Debug, Property)]
 pub(crate) struct StructDecl {
     name: String,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
+    location: Location,
     fields: Vec<FieldDecl>,
     imported_depth: usize,
 }
@@ -62,26 +83,105 @@ pub(crate) struct StructDecl {
 #[derive(Debug, Property)]
 pub(crate) struct VectorDecl {
     name: String,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
+    location: Location,
     item: ItemDecl,
     imported_depth: usize,
+    anonymous: bool,
+    builtin: Option<BuiltIn>,
+    is_map: bool,
 }
 
 #[derive(Debug, Property)]
 pub(crate) struct TableDecl {
     name: String,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
+    location: Location,
     fields: Vec<FieldDecl>,
     imported_depth: usize,
+    is_map_entry: bool,
+}
+
+#[derive(Debug, Property)]
+pub(crate) struct EnumDecl {
+    name: String,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
+    location: Location,
+    item: ItemDecl,
+    items: Vec<EnumItemDecl>,
+    imported_depth: usize,
+}
+
+#[derive(Debug, Property)]
+pub(crate) struct ConstDecl {
+    name: String,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
+    location: Location,
+    item: ItemDecl,
+    value: ConstValue,
+    value_location: Location,
+    imported_depth: usize,
+}
+
+/// The literal of a constant, as it's written in the schema.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ConstValue {
+    Integer(String),
+    Bytes(String),
+}
+
+/// Declarations of the inline types in a declaration, which are named by the compiler.
+#[derive(Debug, Default)]
+pub(crate) struct AnonymousDecls {
+    imported_depth: usize,
+    decls: Vec<TopDecl>,
+}
+
+/// An attribute, as `#[name]`, `#[name = "value"]` or `#[name(arg, arg = "value")]`.
+#[derive(Debug, Clone, Property)]
+pub(crate) struct Attribute {
+    name: String,
+    value: Option<String>,
+    args: Vec<Attribute>,
+    location: Location,
 }
 
 #[derive(Debug, Property)]
 pub(crate) struct ItemDecl {
     typ: String,
+    location: Location,
+}
+
+#[derive(Debug, Property)]
+pub(crate) struct UnionItemDecl {
+    name: Option<String>,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
+    typ: String,
+    id: Option<usize>,
+    location: Location,
+}
+
+#[derive(Debug, Property)]
+pub(crate) struct EnumItemDecl {
+    name: String,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
+    value: Option<String>,
+    location: Location,
 }
 
 #[derive(Debug, Property)]
 pub(crate) struct FieldDecl {
     name: String,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
     typ: String,
+    location: Location,
 }
 
 impl Ast {
@@ -92,8 +192,35 @@ impl Ast {
     pub(crate) fn add_decl(&mut self, decl: impl Into<TopDecl>) {
         self.decls.push(decl.into());
     }
+
+    pub(crate) fn add_const(&mut self, stmt: ConstDecl) {
+        self.consts.push(stmt);
+    }
+
+    pub(crate) fn add_anonymous_decls(&mut self, mut anonymous: AnonymousDecls) {
+        self.decls.append(&mut anonymous.decls);
+    }
 }
 
+impl ConstDecl {
+    /// Checks whether two constants are the same, regardless of where they are.
+    pub(crate) fn is_identical_to(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.attributes == other.attributes
+            && self.item.typ == other.item.typ
+            && self.value == other.value
+    }
+}
+
+// The locations are ignored, so the same attributes in different schemas are equal.
+impl PartialEq for Attribute {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.value == other.value && self.args == other.args
+    }
+}
+
+impl Eq for Attribute {}
+
 impl TopDecl {
     pub(crate) fn name(&self) -> &str {
         match self {
@@ -103,6 +230,139 @@ impl TopDecl {
             TopDecl::Struct(inner) => inner.name(),
             TopDecl::Vector(inner) => inner.name(),
             TopDecl::Table(inner) => inner.name(),
+            TopDecl::Enum(inner) => inner.name(),
+        }
+    }
+
+    pub(crate) fn attributes(&self) -> &[Attribute] {
+        match self {
+            TopDecl::Option_(inner) => inner.attributes(),
+            TopDecl::Union(inner) => inner.attributes(),
+            TopDecl::Array(inner) => inner.attributes(),
+            TopDecl::Struct(inner) => inner.attributes(),
+            TopDecl::Vector(inner) => inner.attributes(),
+            TopDecl::Table(inner) => inner.attributes(),
+            TopDecl::Enum(inner) => inner.attributes(),
+        }
+    }
+
+    pub(crate) fn imported_depth(&self) -> usize {
+        match self {
+            TopDecl::Option_(inner) => inner.imported_depth(),
+            TopDecl::Union(inner) => inner.imported_depth(),
+            TopDecl::Array(inner) => inner.imported_depth(),
+            TopDecl::Struct(inner) => inner.imported_depth(),
+            TopDecl::Vector(inner) => inner.imported_depth(),
+            TopDecl::Table(inner) => inner.imported_depth(),
+            TopDecl::Enum(inner) => inner.imported_depth(),
+        }
+    }
+
+    /// Whether the declaration is synthesized for an inline type.
+    pub(crate) fn is_anonymous(&self) -> bool {
+        match self {
+            TopDecl::Option_(inner) => inner.anonymous(),
+            TopDecl::Array(inner) => inner.anonymous(),
+            TopDecl::Vector(inner) => inner.anonymous(),
+            TopDecl::Table(inner) => inner.is_map_entry(),
+            TopDecl::Union(_) | TopDecl::Struct(_) | TopDecl::Enum(_) => false,
+        }
+    }
+
+    pub(crate) fn location(&self) -> &Location {
+        match self {
+            TopDecl::Option_(inner) => inner.location(),
+            TopDecl::Union(inner) => inner.location(),
+            TopDecl::Array(inner) => inner.location(),
+            TopDecl::Struct(inner) => inner.location(),
+            TopDecl::Vector(inner) => inner.location(),
+            TopDecl::Table(inner) => inner.location(),
+            TopDecl::Enum(inner) => inner.location(),
+        }
+    }
+
+    /// Checks whether two declarations are the same, regardless of where they are.
+    pub(crate) fn is_identical_to(&self, other: &Self) -> bool {
+        let items_eq = |lhs: &[UnionItemDecl], rhs: &[UnionItemDecl]| {
+            lhs.len() == rhs.len()
+                && lhs.iter().zip(rhs).all(|(l, r)| {
+                    l.name == r.name
+                        && l.attributes == r.attributes
+                        && l.typ == r.typ
+                        && l.id == r.id
+                })
+        };
+        let fields_eq = |lhs: &[FieldDecl], rhs: &[FieldDecl]| {
+            lhs.len() == rhs.len()
+                && lhs.iter().zip(rhs).all(|(l, r)| {
+                    l.name == r.name && l.attributes == r.attributes && l.typ == r.typ
+                })
+        };
+        // the attributes change the generated code, and the defaults of the fields are attributes
+        if self.attributes() != other.attributes() {
+            return false;
+        }
+        match (self, other) {
+            (TopDecl::Option_(lhs), TopDecl::Option_(rhs)) => {
+                lhs.name == rhs.name && lhs.item.typ == rhs.item.typ
+            }
+            (TopDecl::Union(lhs), TopDecl::Union(rhs)) => {
+                lhs.name == rhs.name && items_eq(&lhs.items, &rhs.items)
+            }
+            (TopDecl::Array(lhs), TopDecl::Array(rhs)) => {
+                lhs.name == rhs.name
+                    && lhs.item.typ == rhs.item.typ
+                    && lhs.item_count == rhs.item_count
+                    && lhs.builtin == rhs.builtin
+            }
+            (TopDecl::Struct(lhs), TopDecl::Struct(rhs)) => {
+                lhs.name == rhs.name && fields_eq(&lhs.fields, &rhs.fields)
+            }
+            (TopDecl::Vector(lhs), TopDecl::Vector(rhs)) => {
+                lhs.name == rhs.name
+                    && lhs.item.typ == rhs.item.typ
+                    && lhs.builtin == rhs.builtin
+                    && lhs.is_map == rhs.is_map
+            }
+            (TopDecl::Table(lhs), TopDecl::Table(rhs)) => {
+                lhs.name == rhs.name
+                    && fields_eq(&lhs.fields, &rhs.fields)
+                    && lhs.is_map_entry == rhs.is_map_entry
+            }
+            (TopDecl::Enum(lhs), TopDecl::Enum(rhs)) => {
+                lhs.name == rhs.name
+                    && lhs.item.typ == rhs.item.typ
+                    && lhs.items.len() == rhs.items.len()
+                    && lhs.items.iter().zip(&rhs.items).all(|(l, r)| {
+                        l.name == r.name && l.attributes == r.attributes && l.value == r.value
+                    })
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn dependencies(&self) -> Vec<(&str, &Location)> {
+        match self {
+            TopDecl::Option_(inner) => vec![(inner.item().typ(), inner.item().location())],
+            TopDecl::Union(inner) => inner
+                .items()
+                .iter()
+                .map(|item| (item.typ(), item.location()))
+                .collect(),
+            TopDecl::Array(inner) => vec![(inner.item().typ(), inner.item().location())],
+            TopDecl::Struct(inner) => inner
+                .fields()
+                .iter()
+                .map(|field| (field.typ(), field.location()))
+                .collect(),
+            TopDecl::Vector(inner) => vec![(inner.item().typ(), inner.item().location())],
+            TopDecl::Table(inner) => inner
+                .fields()
+                .iter()
+                .map(|field| (field.typ(), field.location()))
+                .collect(),
+            // the integer types of enums are checked when they are completed
+            TopDecl::Enum(_) => Vec::new(),
         }
     }
 }
@@ -123,3 +383,4 @@ impl_into_top_decl_for!(Array, ArrayDecl);
 impl_into_top_decl_for!(Struct, StructDecl);
 impl_into_top_decl_for!(Vector, VectorDecl);
 impl_into_top_decl_for!(Table, TableDecl);
+impl_into_top_decl_for!(Enum, EnumDecl);
@@ -1,38 +1,148 @@
-use std::{ffi, fs, io::Read as _, path::Path, str::FromStr};
+use std::{
+    ffi,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
-use pest::{error::Error as PestError, iterators::Pairs, Parser as _};
-use same_file::is_same_file;
+use pest::{
+    error::{Error as PestError, ErrorVariant, LineColLocation},
+    iterators::{Pair, Pairs},
+    Parser as _,
+};
 
 use crate::{
-    ast::raw as ast,
-    parser,
+    ast::{raw as ast, BuiltIn},
+    error::{Error, Location, Result},
+    parser::{self, Resolver},
     utils::{self, PairsUtils as _},
 };
 
 impl<'i> utils::PairsUtils for Pairs<'i, parser::Rule> {
+    fn peek_location<P: AsRef<Path>>(&mut self, path: &P) -> Location {
+        Location::new(path, &self.peek().unwrap().as_span())
+    }
+
     fn next_string(&mut self) -> String {
         self.next().unwrap().as_str().to_owned()
     }
 
-    fn next_usize(&mut self) -> usize {
-        usize::from_str(self.next().unwrap().as_str()).unwrap()
+    fn next_usize<P: AsRef<Path>>(&mut self, path: &P) -> Result<usize> {
+        let location = self.peek_location(path);
+        let literal = self.next().unwrap().as_str();
+        usize::from_str(literal).map_err(|_| {
+            let reason = format!("the number `{}` is too large", literal);
+            Error::Syntax(location, reason)
+        })
     }
 
-    fn next_item(&mut self) -> ast::ItemDecl {
-        ast::ItemDecl {
-            typ: self.next_string(),
+    fn next_annotations<P: AsRef<Path>>(&mut self, path: &P) -> (Vec<String>, Vec<ast::Attribute>) {
+        let mut docs = Vec::new();
+        let mut attributes = Vec::new();
+        while let Some(pair) = self.peek() {
+            match pair.as_rule() {
+                parser::Rule::doc_line => {
+                    let line = &pair.as_str()[3..];
+                    let skip = if line.starts_with(' ') { 1 } else { 0 };
+                    docs.push(line[skip..].trim_end().to_owned());
+                }
+                parser::Rule::doc_block => {
+                    let text = pair.as_str();
+                    let mut lines = text[3..text.len() - 2]
+                        .lines()
+                        .map(|line| {
+                            let line = line.trim();
+                            let skip = if line.starts_with('*') { 1 } else { 0 };
+                            line[skip..].trim().to_owned()
+                        })
+                        .collect::<Vec<_>>();
+                    while lines.last().map(String::is_empty).unwrap_or(false) {
+                        lines.pop();
+                    }
+                    let start = lines.iter().take_while(|line| line.is_empty()).count();
+                    docs.extend(lines.drain(start..));
+                }
+                parser::Rule::attribute => {
+                    attributes.push(ast::Attribute::new(path, pair.clone()));
+                }
+                _ => break,
+            }
+            self.next();
         }
+        (docs, attributes)
+    }
+
+    fn next_item<P: AsRef<Path>>(
+        &mut self,
+        path: &P,
+        anonymous: &mut ast::AnonymousDecls,
+    ) -> Result<ast::ItemDecl> {
+        let pair = self.next().unwrap();
+        let location = Location::new(path, &pair.as_span());
+        let typ = anonymous.type_name(pair, path)?;
+        Ok(ast::ItemDecl { typ, location })
     }
 
-    fn next_items(&mut self) -> Vec<ast::ItemDecl> {
+    fn next_union_items<P: AsRef<Path>>(
+        &mut self,
+        path: &P,
+        anonymous: &mut ast::AnonymousDecls,
+    ) -> Result<Vec<ast::UnionItemDecl>> {
         let mut ret = Vec::new();
         for item in self {
-            if item.as_rule() != parser::Rule::item_decl {
+            if item.as_rule() != parser::Rule::union_item_decl {
                 unreachable!()
             }
             let mut pair = item.into_inner();
-            let node = ast::ItemDecl {
-                typ: pair.next_string(),
+            let (docs, attributes) = pair.next_annotations(path);
+            let first = pair.next().unwrap();
+            let (name, type_pair) = match pair.peek() {
+                Some(ref next) if next.as_rule() != parser::Rule::item_id => {
+                    (Some(first.as_str().to_owned()), pair.next().unwrap())
+                }
+                _ => (None, first),
+            };
+            let location = Location::new(path, &type_pair.as_span());
+            let typ = anonymous.type_name(type_pair, path)?;
+            let id = if pair.peek().is_some() {
+                Some(pair.next_usize(path)?)
+            } else {
+                None
+            };
+            let node = ast::UnionItemDecl {
+                name,
+                docs,
+                attributes,
+                typ,
+                id,
+                location,
+            };
+            pair.next_should_be_none();
+            ret.push(node);
+        }
+        Ok(ret)
+    }
+
+    fn next_enum_items<P: AsRef<Path>>(&mut self, path: &P) -> Vec<ast::EnumItemDecl> {
+        let mut ret = Vec::new();
+        for item in self {
+            if item.as_rule() != parser::Rule::enum_item_decl {
+                unreachable!()
+            }
+            let mut pair = item.into_inner();
+            let (docs, attributes) = pair.next_annotations(path);
+            let location = pair.peek_location(path);
+            let name = pair.next_string();
+            let value = if pair.peek().is_some() {
+                Some(pair.next_string())
+            } else {
+                None
+            };
+            let node = ast::EnumItemDecl {
+                name,
+                docs,
+                attributes,
+                value,
+                location,
             };
             pair.next_should_be_none();
             ret.push(node);
@@ -40,21 +150,31 @@ impl<'i> utils::PairsUtils for Pairs<'i, parser::Rule> {
         ret
     }
 
-    fn next_fields(&mut self) -> Vec<ast::FieldDecl> {
+    fn next_fields<P: AsRef<Path>>(
+        &mut self,
+        path: &P,
+        anonymous: &mut ast::AnonymousDecls,
+    ) -> Result<Vec<ast::FieldDecl>> {
         let mut ret = Vec::new();
         for field in self {
             if field.as_rule() != parser::Rule::field_decl {
                 unreachable!()
             }
             let mut pair = field.into_inner();
+            let (docs, attributes) = pair.next_annotations(path);
+            let name = pair.next_string();
+            let ast::ItemDecl { typ, location } = pair.next_item(path, anonymous)?;
             let node = ast::FieldDecl {
-                name: pair.next_string(),
-                typ: pair.next_string(),
+                name,
+                docs,
+                attributes,
+                typ,
+                location,
             };
             pair.next_should_be_none();
             ret.push(node);
         }
-        ret
+        Ok(ret)
     }
 
     fn next_import<P: AsRef<Path>>(
@@ -64,6 +184,7 @@ impl<'i> utils::PairsUtils for Pairs<'i, parser::Rule> {
     ) -> ast::ImportStmt {
         let mut paths = Vec::new();
         let mut path_supers = 0;
+        let location = self.peek_location(imported_base);
         if let Some(inner) = self.next() {
             if inner.as_rule() != parser::Rule::path {
                 unreachable!()
@@ -89,6 +210,8 @@ impl<'i> utils::PairsUtils for Pairs<'i, parser::Rule> {
             path_supers,
             imported_base: imported_base.as_ref().to_path_buf(),
             imported_depth,
+            location,
+            resolved_path: None,
         }
     }
 
@@ -99,8 +222,208 @@ impl<'i> utils::PairsUtils for Pairs<'i, parser::Rule> {
     }
 }
 
+impl ast::Attribute {
+    fn new<P: AsRef<Path>>(path: &P, pair: Pair<parser::Rule>) -> Self {
+        let location = Location::new(path, &pair.as_span());
+        let mut pair = pair.into_inner();
+        let name = pair.next_string();
+        let mut value = None;
+        let mut args = Vec::new();
+        for inner in pair {
+            match inner.as_rule() {
+                parser::Rule::attr_string => value = Some(unquote(inner.as_str())),
+                parser::Rule::attr_arg => args.push(Self::new(path, inner)),
+                _ => unreachable!(),
+            }
+        }
+        Self {
+            name,
+            value,
+            args,
+            location,
+        }
+    }
+}
+
+// Removes the quotes of a string literal and resolves the escaped characters.
+fn unquote(literal: &str) -> String {
+    let mut ret = String::with_capacity(literal.len());
+    let mut chars = literal[1..literal.len() - 1].chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => match chars.next() {
+                Some('n') => ret.push('\n'),
+                Some('t') => ret.push('\t'),
+                Some(escaped) => ret.push(escaped),
+                None => {}
+            },
+            _ => ret.push(ch),
+        }
+    }
+    ret
+}
+
+impl ast::AnonymousDecls {
+    fn new(imported_depth: usize) -> Self {
+        Self {
+            imported_depth,
+            decls: Vec::new(),
+        }
+    }
+
+    // Returns the name of a type, inline types are declared with synthesized names.
+    fn type_name<P: AsRef<Path>>(&mut self, pair: Pair<parser::Rule>, path: &P) -> Result<String> {
+        let rule = pair.as_rule();
+        let location = Location::new(path, &pair.as_span());
+        if rule == parser::Rule::identifier {
+            if let Some(builtin) = BuiltIn::from_name(pair.as_str()) {
+                return Ok(self.builtin_name(builtin, location));
+            }
+            return Ok(pair.as_str().to_owned());
+        }
+        let mut pair = pair.into_inner();
+        let item = pair.next_item(path, self)?;
+        let imported_depth = self.imported_depth;
+        let anonymous = true;
+        let decl: ast::TopDecl = match rule {
+            parser::Rule::vector_type => ast::VectorDecl {
+                name: format!("{}Vec", capitalize(item.typ())),
+                docs: Vec::new(),
+                attributes: Vec::new(),
+                location,
+                item,
+                imported_depth,
+                anonymous,
+                builtin: None,
+                is_map: false,
+            }
+            .into(),
+            parser::Rule::option_type => ast::OptionDecl {
+                name: format!("{}Opt", capitalize(item.typ())),
+                docs: Vec::new(),
+                attributes: Vec::new(),
+                location,
+                item,
+                imported_depth,
+                anonymous,
+            }
+            .into(),
+            parser::Rule::array_type => {
+                let item_count = pair.next_usize(path)?;
+                let name = if item.typ() == "byte" {
+                    format!("Byte{}", item_count)
+                } else {
+                    format!("{}x{}", capitalize(item.typ()), item_count)
+                };
+                ast::ArrayDecl {
+                    name,
+                    docs: Vec::new(),
+                    attributes: Vec::new(),
+                    location,
+                    item,
+                    item_count,
+                    imported_depth,
+                    anonymous,
+                    builtin: None,
+                }
+                .into()
+            }
+            _ => unreachable!(),
+        };
+        pair.next_should_be_none();
+        Ok(self.declare(decl))
+    }
+
+    // Built-in types are declared as arrays or vectors of bytes when they are used.
+    fn builtin_name(&mut self, builtin: BuiltIn, location: Location) -> String {
+        let item = ast::ItemDecl {
+            typ: "byte".to_owned(),
+            location: location.clone(),
+        };
+        let decl = if let Some(item_count) = builtin.size() {
+            ast::ArrayDecl {
+                name: builtin.decl_name(),
+                docs: Vec::new(),
+                attributes: Vec::new(),
+                location,
+                item,
+                item_count,
+                imported_depth: self.imported_depth,
+                anonymous: true,
+                builtin: Some(builtin),
+            }
+            .into()
+        } else {
+            ast::VectorDecl {
+                name: builtin.decl_name(),
+                docs: Vec::new(),
+                attributes: Vec::new(),
+                location,
+                item,
+                imported_depth: self.imported_depth,
+                anonymous: true,
+                builtin: Some(builtin),
+                is_map: false,
+            }
+            .into()
+        };
+        self.declare(decl)
+    }
+
+    // The entries of a map are declared as tables with two fields, `key` and `value`.
+    fn map_entry_name(
+        &mut self,
+        map_name: &str,
+        key: ast::ItemDecl,
+        value: ast::ItemDecl,
+        location: Location,
+    ) -> String {
+        let fields = vec![("key", key), ("value", value)]
+            .into_iter()
+            .map(|(name, item)| ast::FieldDecl {
+                name: name.to_owned(),
+                docs: Vec::new(),
+                attributes: Vec::new(),
+                typ: item.typ,
+                location: item.location,
+            })
+            .collect();
+        let decl = ast::TableDecl {
+            name: format!("{}Entry", map_name),
+            docs: Vec::new(),
+            attributes: Vec::new(),
+            location,
+            fields,
+            imported_depth: self.imported_depth,
+            is_map_entry: true,
+        };
+        self.declare(decl.into())
+    }
+
+    fn declare(&mut self, decl: ast::TopDecl) -> String {
+        let name = decl.name().to_owned();
+        if self.decls.iter().all(|other| !other.is_identical_to(&decl)) {
+            self.decls.push(decl);
+        }
+        name
+    }
+}
+
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+    if let Some(first) = chars.next() {
+        first.to_uppercase().chain(chars).collect()
+    } else {
+        String::new()
+    }
+}
+
 impl utils::ParserUtils for parser::Parser {
-    fn preprocess<P: AsRef<Path>>(path: &P) -> Result<ast::Ast, PestError<parser::Rule>> {
+    fn preprocess<P: AsRef<Path>, R: Resolver>(
+        path: &P,
+        source: &str,
+        resolver: &R,
+    ) -> Result<ast::Ast> {
         let mut ast = ast::Ast::default();
 
         ast.namespace = path
@@ -112,63 +435,114 @@ impl utils::ParserUtils for parser::Parser {
 
         let mut imported_depth = 0;
 
-        Self::preprocess_single(&mut ast, path, imported_depth)?;
+        Self::preprocess_single(&mut ast, path, source, imported_depth)?;
 
-        let mut path_bufs = Vec::new();
+        // all schema files, the first one is the root
+        let mut path_bufs = vec![path.as_ref().to_path_buf()];
+        // the import graph: indexes of imported files and locations of import statements
+        let mut graph: Vec<Vec<(usize, Location)>> = vec![Vec::new()];
 
         let mut imports = Vec::new();
 
         while !ast.imports.is_empty() {
             imported_depth += 1;
             while !ast.imports.is_empty() {
-                let stmt = ast.imports.remove(0);
-                let mut path_buf = stmt.imported_base().clone();
-                path_buf.pop();
+                let mut stmt = ast.imports.remove(0);
+                let mut import = PathBuf::new();
                 for _ in 0..stmt.path_supers() {
-                    path_buf.push("..");
+                    import.push("..");
                 }
                 for p in stmt.paths() {
-                    path_buf.push(p);
+                    import.push(p);
                 }
-                path_buf.push(stmt.name());
-                path_buf.set_extension("mol");
+                import.push(stmt.name());
+                import.set_extension("mol");
+                let location = stmt.location().clone();
+                let path_buf = resolver
+                    .resolve(stmt.imported_base(), &import)
+                    .map_err(|err| Error::UnresolvedImport(location.clone(), import, err))?;
                 let path_new = path_buf.as_path();
-                if is_same_file(path, &path_new).unwrap() {
-                    panic!("found cyclic dependencie");
-                }
+                let import_error =
+                    |err| Error::UnresolvedImport(location.clone(), path_new.to_path_buf(), err);
 
-                if path_bufs
+                let importer = path_bufs
                     .iter()
-                    .any(|ref path_old| is_same_file(&path_old, &path_new).unwrap())
-                {
-                    continue;
+                    .position(|path_old| path_old == stmt.imported_base())
+                    .unwrap();
+                let mut imported = None;
+                for (index, path_old) in path_bufs.iter().enumerate() {
+                    if resolver.is_same(path_old, path_new).map_err(import_error)? {
+                        imported = Some(index);
+                        break;
+                    }
+                }
+                let imported = if let Some(index) = imported {
+                    index
                 } else {
+                    stmt.resolved_path = Some(path_buf.clone());
                     imports.push(stmt);
-                    Self::preprocess_single(&mut ast, &path_new, imported_depth)?;
+                    let source = resolver.load(path_new).map_err(import_error)?;
+                    Self::preprocess_single(&mut ast, &path_new, &source, imported_depth)?;
                     path_bufs.push(path_buf);
-                }
+                    graph.push(Vec::new());
+                    path_bufs.len() - 1
+                };
+                graph[importer].push((imported, location));
             }
         }
 
+        let mut visited = vec![false; path_bufs.len()];
+        let mut chain = Vec::new();
+        if let Some(location) = find_cyclic_import(&graph, 0, &mut visited, &mut chain) {
+            let chain = chain.into_iter().map(|i| path_bufs[i].clone()).collect();
+            return Err(Error::CyclicImport(location.clone(), chain));
+        }
+
         ast.imports = imports;
 
         Ok(ast)
     }
 }
 
+// Depth-first search in the import graph.
+//
+// Files which are imported through different paths (diamond imports) are
+// visited only once, only an import of a file in the current chain is a cycle.
+// When a cycle is found, `chain` is the import chain from the root file, which
+// ends with the file imported twice.
+fn find_cyclic_import<'a>(
+    graph: &'a [Vec<(usize, Location)>],
+    current: usize,
+    visited: &mut [bool],
+    chain: &mut Vec<usize>,
+) -> Option<&'a Location> {
+    visited[current] = true;
+    chain.push(current);
+    for (imported, location) in &graph[current] {
+        if chain.contains(imported) {
+            chain.push(*imported);
+            return Some(location);
+        }
+        if !visited[*imported] {
+            let result = find_cyclic_import(graph, *imported, visited, chain);
+            if result.is_some() {
+                return result;
+            }
+        }
+    }
+    chain.pop();
+    None
+}
+
 impl parser::Parser {
     fn preprocess_single<P: AsRef<Path>>(
         ast: &mut ast::Ast,
         path: &P,
+        source: &str,
         imported_depth: usize,
-    ) -> Result<(), PestError<parser::Rule>> {
-        let buffer = {
-            let mut buffer = String::new();
-            let mut file_in = fs::OpenOptions::new().read(true).open(&path).unwrap();
-            file_in.read_to_string(&mut buffer).unwrap();
-            buffer
-        };
-        let mut file_content = parser::InnerParser::parse(parser::Rule::grammar, &buffer)?;
+    ) -> Result<()> {
+        let mut file_content = parser::InnerParser::parse(parser::Rule::grammar, source)
+            .map_err(|err| Self::syntax_error(path, source, err))?;
         let grammar = file_content
             .next()
             .unwrap_or_else(|| panic!("grammar should only have one pair"));
@@ -180,88 +554,243 @@ impl parser::Parser {
             if eoi {
                 panic!("grammar should have only one EOI");
             }
-            match pair.as_rule() {
+            let mut anonymous = ast::AnonymousDecls::new(imported_depth);
+            let decl: ast::TopDecl = match pair.as_rule() {
                 parser::Rule::import_stmt => {
                     let mut pair = pair.into_inner();
                     let node = pair.next_import(path, imported_depth);
                     pair.next_should_be_none();
                     ast.add_import(node);
+                    continue;
                 }
                 parser::Rule::option_decl => {
                     let mut pair = pair.into_inner();
+                    let (docs, attributes) = pair.next_annotations(path);
                     let node = ast::OptionDecl {
+                        docs,
+                        attributes,
+                        location: pair.peek_location(path),
                         name: pair.next_string(),
-                        item: pair.next_item(),
+                        item: pair.next_item(path, &mut anonymous)?,
                         imported_depth,
+                        anonymous: false,
                     };
                     pair.next_should_be_none();
-                    ast.add_decl(node);
+                    node.into()
                 }
                 parser::Rule::union_decl => {
                     let mut pair = pair.into_inner();
+                    let (docs, attributes) = pair.next_annotations(path);
                     let node = ast::UnionDecl {
+                        docs,
+                        attributes,
+                        location: pair.peek_location(path),
                         name: pair.next_string(),
-                        items: pair.next_items(),
+                        items: pair.next_union_items(path, &mut anonymous)?,
                         imported_depth,
                     };
                     pair.next_should_be_none();
-                    ast.add_decl(node);
+                    node.into()
                 }
                 parser::Rule::array_decl => {
                     let mut pair = pair.into_inner();
+                    let (docs, attributes) = pair.next_annotations(path);
                     let node = ast::ArrayDecl {
+                        docs,
+                        attributes,
+                        location: pair.peek_location(path),
                         name: pair.next_string(),
-                        item: pair.next_item(),
-                        item_count: pair.next_usize(),
+                        item: pair.next_item(path, &mut anonymous)?,
+                        item_count: pair.next_usize(path)?,
                         imported_depth,
+                        anonymous: false,
+                        builtin: None,
                     };
                     pair.next_should_be_none();
-                    ast.add_decl(node);
+                    node.into()
                 }
                 parser::Rule::struct_decl => {
                     let mut pair = pair.into_inner();
+                    let (docs, attributes) = pair.next_annotations(path);
                     let node = ast::StructDecl {
+                        docs,
+                        attributes,
+                        location: pair.peek_location(path),
                         name: pair.next_string(),
-                        fields: pair.next_fields(),
+                        fields: pair.next_fields(path, &mut anonymous)?,
                         imported_depth,
                     };
                     pair.next_should_be_none();
-                    ast.add_decl(node);
+                    node.into()
                 }
                 parser::Rule::vector_decl => {
                     let mut pair = pair.into_inner();
+                    let (docs, attributes) = pair.next_annotations(path);
                     let node = ast::VectorDecl {
+                        docs,
+                        attributes,
+                        location: pair.peek_location(path),
                         name: pair.next_string(),
-                        item: pair.next_item(),
+                        item: pair.next_item(path, &mut anonymous)?,
+                        imported_depth,
+                        anonymous: false,
+                        builtin: None,
+                        is_map: false,
+                    };
+                    pair.next_should_be_none();
+                    node.into()
+                }
+                parser::Rule::map_decl => {
+                    let mut pair = pair.into_inner();
+                    let (docs, attributes) = pair.next_annotations(path);
+                    let location = pair.peek_location(path);
+                    let name = pair.next_string();
+                    let key = pair.next_item(path, &mut anonymous)?;
+                    let value = pair.next_item(path, &mut anonymous)?;
+                    let item = ast::ItemDecl {
+                        typ: anonymous.map_entry_name(&name, key, value, location.clone()),
+                        location: location.clone(),
+                    };
+                    let node = ast::VectorDecl {
+                        name,
+                        docs,
+                        attributes,
+                        location,
+                        item,
                         imported_depth,
+                        anonymous: false,
+                        builtin: None,
+                        is_map: true,
                     };
                     pair.next_should_be_none();
-                    ast.add_decl(node);
+                    node.into()
                 }
                 parser::Rule::table_decl => {
                     let mut pair = pair.into_inner();
+                    let (docs, attributes) = pair.next_annotations(path);
                     let node = ast::TableDecl {
+                        docs,
+                        attributes,
+                        location: pair.peek_location(path),
                         name: pair.next_string(),
-                        fields: pair.next_fields(),
+                        fields: pair.next_fields(path, &mut anonymous)?,
+                        imported_depth,
+                        is_map_entry: false,
+                    };
+                    pair.next_should_be_none();
+                    node.into()
+                }
+                parser::Rule::enum_decl => {
+                    let mut pair = pair.into_inner();
+                    let (docs, attributes) = pair.next_annotations(path);
+                    let location = pair.peek_location(path);
+                    let name = pair.next_string();
+                    // the integer type is not a dependency, so it's not declared as a built-in type
+                    let item = ast::ItemDecl {
+                        location: pair.peek_location(path),
+                        typ: pair.next_string(),
+                    };
+                    let node = ast::EnumDecl {
+                        name,
+                        docs,
+                        attributes,
+                        location,
+                        item,
+                        items: pair.next_enum_items(path),
                         imported_depth,
                     };
                     pair.next_should_be_none();
-                    ast.add_decl(node);
+                    node.into()
+                }
+                parser::Rule::const_decl => {
+                    let mut pair = pair.into_inner();
+                    let (docs, attributes) = pair.next_annotations(path);
+                    let location = pair.peek_location(path);
+                    let name = pair.next_string();
+                    let item = pair.next_item(path, &mut anonymous)?;
+                    let value_location = pair.peek_location(path);
+                    let literal = pair.next().unwrap();
+                    let value = match literal.as_rule() {
+                        parser::Rule::integer => {
+                            ast::ConstValue::Integer(literal.as_str().to_owned())
+                        }
+                        parser::Rule::hex_literal => {
+                            ast::ConstValue::Bytes(literal.as_str()[2..].to_owned())
+                        }
+                        _ => unreachable!(),
+                    };
+                    let node = ast::ConstDecl {
+                        name,
+                        docs,
+                        attributes,
+                        location,
+                        item,
+                        value,
+                        value_location,
+                        imported_depth,
+                    };
+                    pair.next_should_be_none();
+                    ast.add_anonymous_decls(anonymous);
+                    ast.add_const(node);
+                    continue;
                 }
                 parser::Rule::EOI => {
                     if eoi {
                         panic!("grammar could not have more than one EOI");
                     }
                     eoi = true;
+                    continue;
                 }
                 _ => {
                     unreachable!();
                 }
-            }
+            };
+            ast.add_anonymous_decls(anonymous);
+            ast.add_decl(decl);
         }
         if !eoi {
             panic!("grammar should have only one EOI");
         }
         Ok(())
     }
+
+    fn syntax_error<P: AsRef<Path>>(path: &P, buffer: &str, err: PestError<parser::Rule>) -> Error {
+        let (line, column, length) = match err.line_col {
+            LineColLocation::Pos((line, column)) => (line, column, 1),
+            LineColLocation::Span((line, column), (line_end, column_end)) => {
+                if line == line_end && column_end > column {
+                    (line, column, column_end - column)
+                } else {
+                    (line, column, 1)
+                }
+            }
+        };
+        let location = Location::from_line_col(path, buffer, line, column, length);
+        let message = match err.variant {
+            ErrorVariant::ParsingError {
+                positives,
+                negatives,
+            } => {
+                let join = |rules: Vec<parser::Rule>| {
+                    rules
+                        .iter()
+                        .map(|rule| format!("{:?}", rule))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                match (negatives.is_empty(), positives.is_empty()) {
+                    (false, false) => format!(
+                        "unexpected {}; expected {}",
+                        join(negatives),
+                        join(positives)
+                    ),
+                    (false, true) => format!("unexpected {}", join(negatives)),
+                    (true, false) => format!("expected {}", join(positives)),
+                    (true, true) => "unknown parsing error".to_owned(),
+                }
+            }
+            ErrorVariant::CustomError { message } => message,
+        };
+        Error::Syntax(location, message)
+    }
 }
@@ -1,80 +1,153 @@
 use std::{
     collections::{HashMap, HashSet},
+    convert::TryFrom,
     rc::Rc,
+    str::FromStr,
 };
 
-use super::super::raw;
+use molecule::Number;
+
+use super::{super::raw, HasName as _};
+use crate::error::{Error, Location, Result};
 
 trait CompleteRawDecl {
-    fn complete(&self, deps: &super::Deps) -> Option<super::TopDecl>;
+    fn complete(&self, deps: &super::Deps) -> Result<Option<super::TopDecl>>;
 }
 
 impl CompleteRawDecl for raw::OptionDecl {
-    fn complete(&self, deps: &super::Deps) -> Option<super::TopDecl> {
-        deps.get(self.item().typ()).map(|dep| {
+    fn complete(&self, deps: &super::Deps) -> Result<Option<super::TopDecl>> {
+        let decl = deps.get(self.item().typ()).map(|dep| {
             let name = self.name().to_owned();
             let item = super::ItemDecl::new(dep);
             super::Option_ {
                 name,
+                docs: self.docs().to_owned(),
+                attributes: complete_attributes(self.attributes()),
                 item,
                 imported_depth: self.imported_depth(),
             }
             .into()
-        })
+        });
+        Ok(decl)
     }
 }
 
 impl CompleteRawDecl for raw::UnionDecl {
-    fn complete(&self, deps: &super::Deps) -> Option<super::TopDecl> {
+    fn complete(&self, deps: &super::Deps) -> Result<Option<super::TopDecl>> {
         if self.items().is_empty() {
-            panic!("the union ({}) is empty", self.name());
+            let location = self.location().clone();
+            return Err(Error::EmptyUnion(location, self.name().to_owned()));
+        }
+        let mut variants: HashMap<&str, &raw::UnionItemDecl> = HashMap::new();
+        let mut ids: HashMap<usize, &raw::UnionItemDecl> = HashMap::new();
+        let mut item_ids = Vec::with_capacity(self.items().len());
+        // an item without an explicit id follows the previous item
+        let mut next_id = 0;
+        for raw_item in self.items() {
+            let variant = raw_item
+                .name()
+                .map(String::as_str)
+                .unwrap_or_else(|| raw_item.typ());
+            if let Some(previous) = variants.insert(variant, raw_item) {
+                return Err(Error::DuplicateName(
+                    raw_item.location().clone(),
+                    Box::new(previous.location().clone()),
+                    variant.to_owned(),
+                ));
+            }
+            let id = raw_item.id().unwrap_or(next_id);
+            if Number::try_from(id).is_err() {
+                let location = raw_item.location().clone();
+                return Err(Error::ItemIdOutOfRange(
+                    location,
+                    self.name().to_owned(),
+                    id,
+                ));
+            }
+            if let Some(previous) = ids.insert(id, raw_item) {
+                return Err(Error::DuplicateItemId(
+                    raw_item.location().clone(),
+                    Box::new(previous.location().clone()),
+                    self.name().to_owned(),
+                    id,
+                ));
+            }
+            item_ids.push(id);
+            next_id = id.saturating_add(1);
         }
-        self.items()
+        let decl = self
+            .items()
             .iter()
-            .map(|raw_item| deps.get(raw_item.typ()).map(super::ItemDecl::new))
+            .zip(item_ids)
+            .map(|(raw_item, id)| {
+                let name = raw_item.name().map(String::as_str);
+                deps.get(raw_item.typ()).map(|dep| {
+                    let attributes = complete_attributes(raw_item.attributes());
+                    super::UnionItemDecl::new(name, raw_item.docs(), attributes, dep, id)
+                })
+            })
             .collect::<Option<Vec<_>>>()
             .map(|items| {
                 let name = self.name().to_owned();
                 super::Union {
                     name,
+                    docs: self.docs().to_owned(),
+                    attributes: complete_attributes(self.attributes()),
                     items,
                     imported_depth: self.imported_depth(),
                 }
                 .into()
-            })
+            });
+        Ok(decl)
     }
 }
 
 impl CompleteRawDecl for raw::ArrayDecl {
-    fn complete(&self, deps: &super::Deps) -> Option<super::TopDecl> {
-        deps.get(self.item().typ()).map(|dep| {
-            let item_size = dep.total_size().unwrap_or_else(|| {
-                panic!(
-                    "the item type ({}) of array ({}) doesn't have fixed size",
-                    self.item().typ(),
-                    self.name(),
-                );
-            });
-            if item_size == 0 {
-                panic!("the array ({}) has no size", self.name());
-            }
-            let name = self.name().to_owned();
-            let item = super::ItemDecl::new(dep);
-            let item_count = self.item_count();
-            super::Array {
-                name,
-                item,
-                item_count,
-                imported_depth: self.imported_depth(),
-                item_size,
-            }
-            .into()
-        })
+    fn complete(&self, deps: &super::Deps) -> Result<Option<super::TopDecl>> {
+        let dep = if let Some(dep) = deps.get(self.item().typ()) {
+            dep
+        } else {
+            return Ok(None);
+        };
+        let item_size = dep.total_size().ok_or_else(|| {
+            Error::NotFixedSize(
+                self.item().location().clone(),
+                self.name().to_owned(),
+                self.item().typ().to_owned(),
+            )
+        })?;
+        if item_size == 0 {
+            let location = self.location().clone();
+            return Err(Error::ZeroSize(location, self.name().to_owned()));
+        }
+        let name = self.name().to_owned();
+        let item = super::ItemDecl::new(dep);
+        let item_count = self.item_count();
+        let target = if dep.get().is_byte() && item_count <= 8 {
+            ConstraintTarget::Value(item_count)
+        } else {
+            ConstraintTarget::Nothing
+        };
+        let (attributes, constraints) =
+            complete_constraints(self.name(), self.location(), self.attributes(), target)?;
+        let decl = super::Array {
+            name,
+            docs: self.docs().to_owned(),
+            attributes,
+            item,
+            item_count,
+            imported_depth: self.imported_depth(),
+            item_size,
+            builtin: self.builtin().copied(),
+            constraints,
+        }
+        .into();
+        Ok(Some(decl))
     }
 }
 
 impl CompleteRawDecl for raw::StructDecl {
-    fn complete(&self, deps: &super::Deps) -> Option<super::TopDecl> {
+    fn complete(&self, deps: &super::Deps) -> Result<Option<super::TopDecl>> {
         let mut fields = Vec::with_capacity(self.fields().len());
         let mut field_sizes = Vec::with_capacity(self.fields().len());
         for raw_field in self.fields() {
@@ -83,123 +156,354 @@ impl CompleteRawDecl for raw::StructDecl {
                 if let Some(field_size) = dep.total_size() {
                     field_sizes.push(field_size);
                 } else {
-                    panic!(
-                        "the filed type ({}) in struct ({}) doesn't have fixed size",
-                        field_name,
-                        self.name(),
-                    );
+                    return Err(Error::NotFixedSize(
+                        raw_field.location().clone(),
+                        self.name().to_owned(),
+                        raw_field.typ().to_owned(),
+                    ));
+                }
+                if raw_field
+                    .attributes()
+                    .iter()
+                    .any(|attr| attr.name() == "default")
+                {
+                    let name = format!("{}.{}", self.name(), field_name);
+                    let location = raw_field.location().clone();
+                    let reason = "only the fields of tables could have default values".to_owned();
+                    return Err(Error::InvalidDefault(location, name, reason));
                 }
-                let field = super::FieldDecl::new(field_name, dep);
+                let field = {
+                    let attributes = complete_attributes(raw_field.attributes());
+                    super::FieldDecl::new(field_name, raw_field.docs(), attributes, dep)
+                };
                 fields.push(field);
             } else {
                 break;
             }
         }
         if fields.len() != self.fields().len() {
-            return None;
+            return Ok(None);
         }
         if field_sizes.iter().sum::<usize>() == 0 {
-            panic!("the struct ({}) has no size", self.name());
+            let location = self.location().clone();
+            return Err(Error::ZeroSize(location, self.name().to_owned()));
         }
         let name = self.name().to_owned();
-        Some(
-            super::Struct {
-                name,
-                fields,
-                imported_depth: self.imported_depth(),
-                field_sizes,
-            }
-            .into(),
-        )
+        let decl = super::Struct {
+            name,
+            docs: self.docs().to_owned(),
+            attributes: complete_attributes(self.attributes()),
+            fields,
+            imported_depth: self.imported_depth(),
+            field_sizes,
+        }
+        .into();
+        Ok(Some(decl))
     }
 }
 
 impl CompleteRawDecl for raw::VectorDecl {
-    fn complete(&self, deps: &super::Deps) -> Option<super::TopDecl> {
-        deps.get(self.item().typ()).map(|dep| {
+    fn complete(&self, deps: &super::Deps) -> Result<Option<super::TopDecl>> {
+        let (attributes, constraints) = complete_constraints(
+            self.name(),
+            self.location(),
+            self.attributes(),
+            ConstraintTarget::Items,
+        )?;
+        let decl = deps.get(self.item().typ()).map(|dep| {
             let name = self.name().to_owned();
             let item = super::ItemDecl::new(dep);
             if let Some(item_size) = dep.total_size() {
                 super::FixVec {
                     name,
+                    docs: self.docs().to_owned(),
+                    attributes,
                     item,
                     imported_depth: self.imported_depth(),
                     item_size,
+                    builtin: self.builtin().copied(),
+                    is_map: self.is_map(),
+                    constraints,
                 }
                 .into()
             } else {
                 super::DynVec {
                     name,
+                    docs: self.docs().to_owned(),
+                    attributes,
                     item,
                     imported_depth: self.imported_depth(),
+                    is_map: self.is_map(),
+                    constraints,
                 }
                 .into()
             }
-        })
+        });
+        Ok(decl)
     }
 }
 
 impl CompleteRawDecl for raw::TableDecl {
-    fn complete(&self, deps: &super::Deps) -> Option<super::TopDecl> {
-        self.fields()
-            .iter()
-            .map(|raw_field| {
-                let field_name = raw_field.name();
-                deps.get(raw_field.typ())
-                    .map(|dep| super::FieldDecl::new(field_name, dep))
-            })
-            .collect::<Option<Vec<_>>>()
-            .map(|fields| {
-                let name = self.name().to_owned();
-                super::Table {
+    fn complete(&self, deps: &super::Deps) -> Result<Option<super::TopDecl>> {
+        let mut fields = Vec::with_capacity(self.fields().len());
+        let mut first_default: Option<&str> = None;
+        for raw_field in self.fields() {
+            let dep = if let Some(dep) = deps.get(raw_field.typ()) {
+                dep
+            } else {
+                return Ok(None);
+            };
+            let (attributes, default) = complete_field_default(self.name(), raw_field, dep)?;
+            // the missing fields of an older encoding are always the trailing fields
+            match (first_default, &default) {
+                (None, Some(_)) => first_default = Some(raw_field.name()),
+                (Some(previous), None) => {
+                    let reason = format!(
+                        "it should have a default value, since the field `{}` before it has one",
+                        previous
+                    );
+                    let name = format!("{}.{}", self.name(), raw_field.name());
+                    let location = raw_field.location().clone();
+                    return Err(Error::InvalidDefault(location, name, reason));
+                }
+                _ => {}
+            }
+            let field = super::FieldDecl::new(raw_field.name(), raw_field.docs(), attributes, dep);
+            let field = match default {
+                Some(FieldDefault::Value(value)) => field.with_default(Some(value)),
+                Some(FieldDefault::OfType) => field.with_default_of_type(),
+                None => field,
+            };
+            fields.push(field);
+        }
+        let name = self.name().to_owned();
+        // the entries of a map are structs if both the keys and the values are fixed size
+        if self.is_map_entry() {
+            let field_sizes = fields
+                .iter()
+                .map(|field| field.typ.total_size())
+                .collect::<Option<Vec<_>>>();
+            if let Some(field_sizes) = field_sizes {
+                let decl = super::Struct {
                     name,
+                    docs: self.docs().to_owned(),
+                    attributes: complete_attributes(self.attributes()),
                     fields,
                     imported_depth: self.imported_depth(),
+                    field_sizes,
                 }
-                .into()
-            })
+                .into();
+                return Ok(Some(decl));
+            }
+        }
+        let decl = super::Table {
+            name,
+            docs: self.docs().to_owned(),
+            attributes: complete_attributes(self.attributes()),
+            fields,
+            imported_depth: self.imported_depth(),
+        }
+        .into();
+        Ok(Some(decl))
+    }
+}
+
+impl CompleteRawDecl for raw::EnumDecl {
+    fn complete(&self, _deps: &super::Deps) -> Result<Option<super::TopDecl>> {
+        let invalid = |location: &Location, reason: String| {
+            Error::InvalidEnum(location.clone(), self.name().to_owned(), reason)
+        };
+        let typ = self.item().typ();
+        let total_size = match super::BuiltIn::from_name(typ) {
+            _ if typ == "byte" => 1,
+            Some(super::BuiltIn::Integer {
+                signed: false,
+                size,
+            }) if size <= 8 => size,
+            _ => {
+                let reason = format!(
+                    "the type should be `byte`, `uint8`, `uint16`, `uint32` or `uint64`, \
+                    but got `{}`",
+                    typ
+                );
+                return Err(invalid(self.item().location(), reason));
+            }
+        };
+        if self.items().is_empty() {
+            let reason = "it should have at least one item".to_owned();
+            return Err(invalid(self.location(), reason));
+        }
+        let mut names: HashMap<&str, &raw::EnumItemDecl> = HashMap::new();
+        let mut values: HashMap<u64, &raw::EnumItemDecl> = HashMap::new();
+        let mut items = Vec::with_capacity(self.items().len());
+        // an item without an explicit value follows the previous item
+        let mut next_value = Some(0);
+        for raw_item in self.items() {
+            if let Some(previous) = names.insert(raw_item.name(), raw_item) {
+                return Err(Error::DuplicateName(
+                    raw_item.location().clone(),
+                    Box::new(previous.location().clone()),
+                    raw_item.name().to_owned(),
+                ));
+            }
+            let value = if let Some(literal) = raw_item.value() {
+                u64::from_str(literal).ok()
+            } else {
+                next_value
+            }
+            .filter(|value| total_size == 8 || *value >> (total_size * 8) == 0)
+            .ok_or_else(|| {
+                let reason = format!(
+                    "the value of `{}` doesn't fit in {} bytes",
+                    raw_item.name(),
+                    total_size
+                );
+                invalid(raw_item.location(), reason)
+            })?;
+            if let Some(previous) = values.insert(value, raw_item) {
+                let reason = format!(
+                    "the value {} is used by both `{}` and `{}`",
+                    value,
+                    previous.name(),
+                    raw_item.name()
+                );
+                return Err(invalid(raw_item.location(), reason));
+            }
+            items.push(super::EnumItemDecl {
+                name: raw_item.name().to_owned(),
+                docs: raw_item.docs().to_owned(),
+                attributes: complete_attributes(raw_item.attributes()),
+                value,
+            });
+            next_value = value.checked_add(1);
+        }
+        let decl = super::Enum {
+            name: self.name().to_owned(),
+            docs: self.docs().to_owned(),
+            attributes: complete_attributes(self.attributes()),
+            items,
+            imported_depth: self.imported_depth(),
+            total_size,
+        }
+        .into();
+        Ok(Some(decl))
     }
 }
 
 impl super::Ast {
-    pub(crate) fn complete(raw: raw::Ast) -> Self {
-        let mut decls_idx = HashMap::new();
+    pub(crate) fn complete(raw: raw::Ast, allow_identical_redeclarations: bool) -> Result<Self> {
+        let mut decls_idx: HashMap<_, &raw::TopDecl> = HashMap::new();
         let mut decls_keys = HashSet::new();
         for decl in raw.decls() {
             let name = decl.name();
-            if super::TopDecl::new_primitive(name.to_lowercase().as_str()).is_some() {
-                panic!("the name `{}` is reserved", name);
+            if super::TopDecl::new_primitive(name.to_lowercase().as_str()).is_some()
+                || super::BuiltIn::from_name(name).is_some()
+            {
+                let location = decl.location().clone();
+                return Err(Error::ReservedName(location, name.to_owned()));
+            }
+            if let Some(&previous) = decls_idx.get(name) {
+                // inline types are always allowed to be declared more than once
+                let allowed = allow_identical_redeclarations
+                    || previous.is_anonymous()
+                    || decl.is_anonymous();
+                if allowed && previous.is_identical_to(decl) {
+                    // prefer the imported one, then it's only generated in the imported file
+                    if decl.imported_depth() > previous.imported_depth() {
+                        decls_idx.insert(name, decl);
+                    }
+                    continue;
+                }
+                let location = decl.location().clone();
+                let previous_location = Box::new(previous.location().clone());
+                return Err(Error::DuplicateName(
+                    location,
+                    previous_location,
+                    name.to_owned(),
+                ));
+            }
+            decls_idx.insert(name, decl);
+            decls_keys.insert(name);
+        }
+        let mut consts_idx: HashMap<_, &raw::ConstDecl> = HashMap::new();
+        let mut consts_raw = Vec::with_capacity(raw.consts().len());
+        for raw_const in raw.consts() {
+            let name = raw_const.name();
+            if super::TopDecl::new_primitive(name.to_lowercase().as_str()).is_some()
+                || super::BuiltIn::from_name(name).is_some()
+            {
+                let location = raw_const.location().clone();
+                return Err(Error::ReservedName(location, name.to_owned()));
             }
-            if decls_idx.insert(name, decl).is_some() || !decls_keys.insert(name) {
-                panic!("the name `{}` is used more than once", name);
+            let previous_location = if let Some(previous) = decls_idx.get(name) {
+                Some(previous.location())
+            } else if let Some(previous) = consts_idx.get(name) {
+                if allow_identical_redeclarations && previous.is_identical_to(raw_const) {
+                    continue;
+                }
+                Some(previous.location())
+            } else {
+                None
             };
+            if let Some(previous_location) = previous_location {
+                return Err(Error::DuplicateName(
+                    raw_const.location().clone(),
+                    Box::new(previous_location.clone()),
+                    name.to_owned(),
+                ));
+            }
+            consts_idx.insert(name, raw_const);
+            consts_raw.push(raw_const);
+        }
+        let consts_deps = consts_raw
+            .iter()
+            .map(|raw_const| (raw_const.item().typ(), raw_const.item().location()));
+        let deps = raw
+            .decls()
+            .iter()
+            .flat_map(raw::TopDecl::dependencies)
+            .chain(consts_deps);
+        for (typ, location) in deps {
+            if !decls_idx.contains_key(typ) && super::TopDecl::new_primitive(typ).is_none() {
+                return Err(Error::UnknownType(location.clone(), typ.to_owned()));
+            }
         }
         let mut decls_result = HashMap::new();
         decls_result.insert(
             "byte",
-            Rc::new(super::TopDecl::new_primitive("byte").unwrap()),
+            super::TypeRef::resolved(Rc::new(super::TopDecl::new_primitive("byte").unwrap())),
         );
         loop {
             if decls_keys.is_empty() {
                 break;
             }
             let incompleted = decls_keys.len();
-            decls_keys.retain(|&name| {
+            let mut completed = Vec::new();
+            for &name in &decls_keys {
                 let decl_raw = decls_idx.get(name).unwrap();
-                if let Some(decl) = super::TopDecl::complete(decl_raw, &decls_result) {
-                    decls_result.insert(name, Rc::new(decl));
-                    false
-                } else {
-                    true
+                if let Some(decl) = super::TopDecl::complete(decl_raw, &decls_result)? {
+                    completed.push((name, decl));
                 }
-            });
+            }
+            for (name, decl) in completed {
+                decls_keys.remove(name);
+                decls_result.insert(name, super::TypeRef::resolved(Rc::new(decl)));
+            }
             if decls_keys.len() == incompleted {
-                panic!(
-                    "there are {} types which are unable to be completed: {:?}",
-                    incompleted, decls_keys
-                );
+                // keep the order of declarations
+                let mut remaining = Vec::with_capacity(decls_keys.len());
+                for decl in raw.decls() {
+                    if decls_keys.remove(decl.name()) {
+                        remaining.push(*decls_idx.get(decl.name()).unwrap());
+                    }
+                }
+                complete_recursive_decls(&remaining, &mut decls_result)?;
             }
         }
+        let consts = consts_raw
+            .into_iter()
+            .map(|raw_const| super::Const::complete(raw_const, &decls_result))
+            .collect::<Result<Vec<_>>>()?;
         let namespace = raw.namespace().to_owned();
         let imports = raw
             .imports()
@@ -207,18 +511,389 @@ impl super::Ast {
             .filter(|r| r.imported_depth() == 0)
             .map(super::ImportStmt::complete)
             .collect();
-        // remove the primitive types and keep the order
+        // remove the primitive types and the redeclarations, and keep the order
         let mut decls = Vec::with_capacity(raw.decls().len());
         for decl in raw.decls() {
-            let result = decls_result.get(decl.name()).unwrap();
-            decls.push(Rc::clone(result));
+            if let Some(result) = decls_result.remove(decl.name()) {
+                decls.push(result.get());
+            }
         }
-        Self {
+        Ok(Self {
             namespace,
             imports,
             decls,
+            consts,
+        })
+    }
+}
+
+// `matches!` isn't available in the minimum supported version.
+#[allow(clippy::match_like_matches_macro)]
+fn is_fixed_size(decl: &raw::TopDecl) -> bool {
+    match decl {
+        raw::TopDecl::Array(_) | raw::TopDecl::Struct(_) => true,
+        _ => false,
+    }
+}
+
+// The remaining declarations depend on each other, or depend on the declarations which depend on
+// each other.
+//
+// The recursion is only allowed through the types which aren't fixed size, and the default values
+// of the types should be finite, for example, a table which has a field of an option of itself.
+fn complete_recursive_decls<'a>(
+    remaining: &[&'a raw::TopDecl],
+    deps: &mut super::Deps<'a>,
+) -> Result<()> {
+    let remaining_names = remaining
+        .iter()
+        .map(|decl| (decl.name(), *decl))
+        .collect::<HashMap<_, _>>();
+    // a fixed size type couldn't contain a type which isn't fixed size, nor contain itself
+    let fixed_size_decls = remaining
+        .iter()
+        .filter(|decl| is_fixed_size(decl))
+        .collect::<Vec<_>>();
+    if !fixed_size_decls.is_empty() {
+        for decl in &fixed_size_decls {
+            for (typ, location) in decl.dependencies() {
+                if let Some(dep) = remaining_names.get(typ) {
+                    if !is_fixed_size(dep) {
+                        let name = decl.name().to_owned();
+                        return Err(Error::NotFixedSize(location.clone(), name, typ.to_owned()));
+                    }
+                }
+            }
+        }
+        let location = fixed_size_decls[0].location().clone();
+        let names = fixed_size_decls
+            .iter()
+            .map(|decl| decl.name().to_owned())
+            .collect();
+        return Err(Error::CyclicDependency(location, names));
+    }
+    for decl in remaining {
+        deps.insert(decl.name(), super::TypeRef::unresolved(decl.name()));
+    }
+    let mut completed = Vec::with_capacity(remaining.len());
+    for decl_raw in remaining {
+        let decl = super::TopDecl::complete(decl_raw, deps)?
+            .map(Rc::new)
+            .expect("all dependencies should be declared");
+        completed.push((decl_raw, decl));
+    }
+    for (decl_raw, decl) in &completed {
+        let name = decl_raw.name();
+        deps.get(name).unwrap().resolve(decl);
+        // the declarations refer to each other weakly, so the dependencies own them
+        deps.insert(name, super::TypeRef::resolved(Rc::clone(decl)));
+    }
+    for (decl_raw, decl) in &completed {
+        if let Some(names) = super::default_content::find_infinite_default(decl) {
+            let location = decl_raw.location().clone();
+            return Err(Error::CyclicDependency(location, names));
+        }
+    }
+    Ok(())
+}
+
+impl super::Const {
+    fn complete(raw: &raw::ConstDecl, deps: &super::Deps) -> Result<Self> {
+        let name = raw.name().to_owned();
+        let typ = deps.get(raw.item().typ()).unwrap().get();
+        let size = typ.total_size().ok_or_else(|| {
+            Error::NotFixedSize(
+                raw.item().location().clone(),
+                name.clone(),
+                raw.item().typ().to_owned(),
+            )
+        })?;
+        let value = match raw.value() {
+            raw::ConstValue::Integer(literal) => encode_integer(literal, size),
+            raw::ConstValue::Bytes(literal) => super::decode_hex(literal)
+                .ok_or_else(|| "the hex string should have an even length".to_owned())
+                .and_then(|value| {
+                    if value.len() == size {
+                        Ok(value)
+                    } else {
+                        Err(format!("expect {} bytes but got {}", size, value.len()))
+                    }
+                }),
+        }
+        .and_then(|value| check_enum_value(&typ, value))
+        .map_err(|reason| {
+            Error::InvalidConstant(raw.value_location().clone(), name.clone(), reason)
+        })?;
+        Ok(Self {
+            name,
+            docs: raw.docs().to_owned(),
+            attributes: complete_attributes(raw.attributes()),
+            typ,
+            value,
+            imported_depth: raw.imported_depth(),
+        })
+    }
+}
+
+// Serializes an unsigned integer in little-endian.
+fn encode_integer(literal: &str, size: usize) -> ::std::result::Result<Vec<u8>, String> {
+    let overflow = || format!("the integer doesn't fit in {} bytes", size);
+    let number = u128::from_str(literal).map_err(|_| overflow())?;
+    let bytes = number.to_le_bytes();
+    if size < bytes.len() && bytes[size..].iter().any(|b| *b != 0) {
+        return Err(overflow());
+    }
+    let mut value = vec![0; size];
+    let len = size.min(bytes.len());
+    value[..len].copy_from_slice(&bytes[..len]);
+    Ok(value)
+}
+
+// The value of an enum should be one of its items.
+fn check_enum_value(
+    typ: &super::TopDecl,
+    value: Vec<u8>,
+) -> ::std::result::Result<Vec<u8>, String> {
+    if let super::TopDecl::Enum(inner) = typ {
+        let mut bytes = [0u8; 8];
+        bytes[..value.len()].copy_from_slice(&value);
+        let number = u64::from_le_bytes(bytes);
+        if inner.item_by_value(number).is_none() {
+            return Err(format!(
+                "the value {} isn't an item of the enum `{}`",
+                number,
+                inner.name()
+            ));
+        }
+    }
+    Ok(value)
+}
+
+enum FieldDefault {
+    Value(Vec<u8>),
+    // the default value of the type, it's resolved later since the type could be recursive
+    OfType,
+}
+
+// The default value of a table field is declared as `#[default]`, which is the default value of
+// the type, or `#[default = "value"]`, which is an unsigned integer or a hex string as a constant.
+// The attribute is removed from the attributes of the field.
+fn complete_field_default(
+    decl_name: &str,
+    raw: &raw::FieldDecl,
+    typ: &super::TypeRef,
+) -> Result<(Vec<super::Attribute>, Option<FieldDefault>)> {
+    let invalid = |reason: String| {
+        let name = format!("{}.{}", decl_name, raw.name());
+        Error::InvalidDefault(raw.location().clone(), name, reason)
+    };
+    let mut attributes = Vec::with_capacity(raw.attributes().len());
+    let mut default = None;
+    for attr in raw.attributes() {
+        if attr.name() != "default" {
+            attributes.push(attr.clone());
+            continue;
+        }
+        if default.is_some() {
+            return Err(invalid(
+                "`#[default]` is declared more than once".to_owned(),
+            ));
+        }
+        if !attr.args().is_empty() {
+            let reason = "`#[default]` doesn't have arguments, the value is declared \
+                as `#[default = \"1\"]`";
+            return Err(invalid(reason.to_owned()));
+        }
+        let value = match (attr.value(), typ.try_get()) {
+            (Some(literal), Some(typ)) => {
+                FieldDefault::Value(decode_default_value(literal, &typ).map_err(invalid)?)
+            }
+            // an unresolved type isn't fixed size
+            (Some(_), None) => {
+                let reason = format!(
+                    "`{}` isn't fixed size, only `#[default]` is allowed",
+                    raw.typ()
+                );
+                return Err(invalid(reason));
+            }
+            (None, _) => FieldDefault::OfType,
+        };
+        default = Some(value);
+    }
+    Ok((complete_attributes(&attributes), default))
+}
+
+fn decode_default_value(
+    literal: &str,
+    typ: &super::TopDecl,
+) -> ::std::result::Result<Vec<u8>, String> {
+    let size = typ.total_size().ok_or_else(|| {
+        format!(
+            "`{}` isn't fixed size, only `#[default]` is allowed",
+            typ.name()
+        )
+    })?;
+    let value = if literal.get(..2) == Some("0x") {
+        let value = super::decode_hex(&literal[2..])
+            .ok_or_else(|| "the hex string should have an even length".to_owned())?;
+        if value.len() != size {
+            return Err(format!("expect {} bytes but got {}", size, value.len()));
+        }
+        value
+    } else {
+        encode_integer(literal, size)?
+    };
+    let value = check_enum_value(typ, value)?;
+    match typ {
+        super::TopDecl::Enum(_) => Ok(value),
+        super::TopDecl::Array(inner) if inner.builtin() == Some(super::BuiltIn::Bool) => {
+            if value[0] > 1 {
+                Err("a `bool` should be 0 or 1".to_owned())
+            } else {
+                Ok(value)
+            }
+        }
+        super::TopDecl::Array(inner) if inner.constraints().has_range() => {
+            let mut bytes = [0u8; 8];
+            bytes[..value.len()].copy_from_slice(&value);
+            let number = u64::from_le_bytes(bytes);
+            let constraints = inner.constraints();
+            let too_small = constraints.min_value().into_iter().any(|min| number < min);
+            let too_large = constraints.max_value().into_iter().any(|max| number > max);
+            if too_small || too_large {
+                Err(format!(
+                    "the value {} violates the range of `{}`",
+                    number,
+                    inner.name()
+                ))
+            } else {
+                Ok(value)
+            }
+        }
+        _ if typ.has_restricted_values() => Err(format!(
+            "some values of `{}` are invalid, only `#[default]` is allowed",
+            typ.name()
+        )),
+        _ => Ok(value),
+    }
+}
+
+// What the constraints of a declaration could be applied to.
+enum ConstraintTarget {
+    Nothing,
+    Items,
+    // an unsigned integer in little-endian, the size is at most 8 bytes
+    Value(usize),
+}
+
+// The constraints are declared as attributes, they are removed from the attributes of the
+// declaration after they are checked.
+fn complete_constraints(
+    name: &str,
+    location: &Location,
+    raw: &[raw::Attribute],
+    target: ConstraintTarget,
+) -> Result<(Vec<super::Attribute>, super::Constraints)> {
+    let invalid =
+        |reason: String| Error::InvalidConstraint(location.clone(), name.to_owned(), reason);
+    let parse = |attr: &raw::Attribute, literal: Option<&String>| {
+        literal
+            .and_then(|literal| u64::from_str(literal).ok())
+            .filter(|_| attr.args().is_empty())
+            .ok_or_else(|| {
+                invalid(format!(
+                    "`{}` should have an unsigned integer as the value, as `{} = \"16\"`",
+                    attr.name(),
+                    attr.name()
+                ))
+            })
+    };
+    let mut attributes = Vec::with_capacity(raw.len());
+    let mut constraints = super::Constraints::default();
+    let mut declared = HashSet::new();
+    for attr in raw {
+        let attr_name = attr.name();
+        if !["max_items", "non_empty", "range"].contains(&attr_name) {
+            attributes.push(attr.clone());
+            continue;
+        }
+        if !declared.insert(attr_name) {
+            let reason = format!("`#[{}]` is declared more than once", attr_name);
+            return Err(invalid(reason));
+        }
+        match (attr_name, &target) {
+            ("max_items", ConstraintTarget::Items) => {
+                let max_items = parse(attr, attr.value())?;
+                constraints.max_items = Some(max_items as usize);
+            }
+            ("non_empty", ConstraintTarget::Items) => {
+                if attr.value().is_some() || !attr.args().is_empty() {
+                    return Err(invalid("`#[non_empty]` has no value".to_owned()));
+                }
+                constraints.non_empty = true;
+            }
+            ("range", &ConstraintTarget::Value(size)) => {
+                if attr.value().is_some() || attr.args().is_empty() {
+                    let reason = "`#[range]` should have the arguments `min` or `max`, \
+                        as `#[range(min = \"1\", max = \"16\")]`";
+                    return Err(invalid(reason.to_owned()));
+                }
+                for arg in attr.args() {
+                    let value = parse(arg, arg.value())?;
+                    if size < 8 && value >> (size * 8) != 0 {
+                        let reason = format!("`{}` doesn't fit in {} bytes", arg.name(), size);
+                        return Err(invalid(reason));
+                    }
+                    match arg.name() {
+                        "min" if constraints.min_value.is_none() => {
+                            constraints.min_value = Some(value)
+                        }
+                        "max" if constraints.max_value.is_none() => {
+                            constraints.max_value = Some(value)
+                        }
+                        _ => {
+                            let reason = format!(
+                                "`{}` is unknown or declared more than once, \
+                                only `min` and `max` are allowed",
+                                arg.name()
+                            );
+                            return Err(invalid(reason));
+                        }
+                    }
+                }
+                if let (Some(min), Some(max)) = (constraints.min_value, constraints.max_value) {
+                    if min > max {
+                        let reason = format!("`min` ({}) is greater than `max` ({})", min, max);
+                        return Err(invalid(reason));
+                    }
+                }
+            }
+            ("range", _) => {
+                let reason = "`#[range]` is only allowed on arrays of at most 8 bytes";
+                return Err(invalid(reason.to_owned()));
+            }
+            _ => {
+                let reason = format!("`#[{}]` is only allowed on vectors", attr_name);
+                return Err(invalid(reason));
+            }
         }
     }
+    if constraints.non_empty && constraints.max_items == Some(0) {
+        let reason = "`max_items` should be at least 1 for a non-empty vector".to_owned();
+        return Err(invalid(reason));
+    }
+    Ok((complete_attributes(&attributes), constraints))
+}
+
+fn complete_attributes(raw: &[raw::Attribute]) -> Vec<super::Attribute> {
+    raw.iter()
+        .map(|attr| super::Attribute {
+            name: attr.name().to_owned(),
+            value: attr.value().cloned(),
+            args: complete_attributes(attr.args()),
+            location: Some(attr.location().clone()),
+        })
+        .collect()
 }
 
 impl super::ImportStmt {
@@ -227,12 +902,21 @@ impl super::ImportStmt {
             name: raw.name().to_owned(),
             paths: raw.paths().to_owned(),
             path_supers: raw.path_supers(),
+            resolved_path: raw.resolved_path().cloned(),
         }
     }
 }
 
 impl super::TopDecl {
-    fn complete(raw: &raw::TopDecl, deps: &super::Deps) -> Option<Self> {
+    fn complete(raw: &raw::TopDecl, deps: &super::Deps) -> Result<Option<Self>> {
+        match raw {
+            raw::TopDecl::Array(_) | raw::TopDecl::Vector(_) => {}
+            _ => {
+                // only arrays and vectors could have constraints
+                let target = ConstraintTarget::Nothing;
+                complete_constraints(raw.name(), raw.location(), raw.attributes(), target)?;
+            }
+        }
         match raw {
             raw::TopDecl::Option_(inner) => inner.complete(deps),
             raw::TopDecl::Union(inner) => inner.complete(deps),
@@ -240,6 +924,7 @@ impl super::TopDecl {
             raw::TopDecl::Struct(inner) => inner.complete(deps),
             raw::TopDecl::Vector(inner) => inner.complete(deps),
             raw::TopDecl::Table(inner) => inner.complete(deps),
+            raw::TopDecl::Enum(inner) => inner.complete(deps),
         }
     }
 }
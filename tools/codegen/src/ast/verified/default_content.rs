@@ -1,5 +1,9 @@
+use std::rc::Rc;
+
 use molecule::{pack_number, Number, NUMBER_SIZE};
 
+use super::HasName as _;
+
 pub trait DefaultContent {
     fn default_content(&self) -> Vec<u8>;
 }
@@ -18,11 +22,11 @@ impl DefaultContent for super::Option_ {
 
 impl DefaultContent for super::Union {
     fn default_content(&self) -> Vec<u8> {
-        let item_id = 0;
-        let inner_content = self.items()[item_id].typ.default_content();
+        let item = &self.items()[0];
+        let inner_content = item.typ().default_content();
         let total_size = NUMBER_SIZE + inner_content.len();
         let mut content = Vec::with_capacity(total_size);
-        content.extend_from_slice(&pack_number(item_id as Number));
+        content.extend_from_slice(&pack_number(item.id as Number));
         content.extend_from_slice(&inner_content);
         content
     }
@@ -30,18 +34,39 @@ impl DefaultContent for super::Union {
 
 impl DefaultContent for super::Array {
     fn default_content(&self) -> Vec<u8> {
-        vec![0; self.total_size()]
+        // the default value of an integer is the min value of its range
+        if let Some(min_value) = self.constraints().min_value() {
+            return min_value.to_le_bytes()[..self.total_size()].to_vec();
+        }
+        let item_content = self.item().typ().default_content();
+        let mut content = Vec::with_capacity(self.total_size());
+        for _ in 0..self.item_count() {
+            content.extend_from_slice(&item_content);
+        }
+        content
     }
 }
 
 impl DefaultContent for super::Struct {
     fn default_content(&self) -> Vec<u8> {
-        vec![0; self.total_size()]
+        let mut content = Vec::with_capacity(self.total_size());
+        for field in self.fields() {
+            content.extend_from_slice(&field.typ().default_content());
+        }
+        content
     }
 }
 
+// A non-empty vector has one default item by default.
 impl DefaultContent for super::FixVec {
     fn default_content(&self) -> Vec<u8> {
+        if self.constraints().non_empty() {
+            let item_content = self.item().typ().default_content();
+            let mut content = Vec::with_capacity(NUMBER_SIZE + item_content.len());
+            content.extend_from_slice(&pack_number(1));
+            content.extend_from_slice(&item_content);
+            return content;
+        }
         let item_count = 0;
         let mut content = Vec::with_capacity(NUMBER_SIZE);
         content.extend_from_slice(&pack_number(item_count as Number));
@@ -51,6 +76,15 @@ impl DefaultContent for super::FixVec {
 
 impl DefaultContent for super::DynVec {
     fn default_content(&self) -> Vec<u8> {
+        if self.constraints().non_empty() {
+            let item_content = self.item().typ().default_content();
+            let total_size = NUMBER_SIZE * 2 + item_content.len();
+            let mut content = Vec::with_capacity(total_size);
+            content.extend_from_slice(&pack_number(total_size as Number));
+            content.extend_from_slice(&pack_number((NUMBER_SIZE * 2) as Number));
+            content.extend_from_slice(&item_content);
+            return content;
+        }
         let total_size = NUMBER_SIZE;
         let mut content = Vec::with_capacity(NUMBER_SIZE);
         content.extend_from_slice(&pack_number(total_size as Number));
@@ -75,7 +109,7 @@ impl DefaultContent for super::Table {
                 ),
                 |(mut current_offset, mut offsets, mut field_data), field| {
                     offsets.push(current_offset);
-                    let data = field.typ.default_content();
+                    let data = field.typ().default_content();
                     current_offset += data.len();
                     field_data.push(data);
                     (current_offset, offsets, field_data)
@@ -96,6 +130,14 @@ impl DefaultContent for super::Table {
     }
 }
 
+/// The default value of an enum is its first item.
+impl DefaultContent for super::Enum {
+    fn default_content(&self) -> Vec<u8> {
+        let value = self.items()[0].value().to_le_bytes();
+        value[..self.total_size()].to_vec()
+    }
+}
+
 impl DefaultContent for super::TopDecl {
     fn default_content(&self) -> Vec<u8> {
         match self {
@@ -107,6 +149,37 @@ impl DefaultContent for super::TopDecl {
             super::TopDecl::FixVec(inner) => inner.default_content(),
             super::TopDecl::DynVec(inner) => inner.default_content(),
             super::TopDecl::Table(inner) => inner.default_content(),
+            super::TopDecl::Enum(inner) => inner.default_content(),
         }
     }
 }
+
+// Finds a cycle of the types which are contained by the default value of a recursive type, then
+// the default value is infinite, for example, a table which has a field of itself.
+pub(super) fn find_infinite_default(decl: &super::TopDecl) -> Option<Vec<String>> {
+    fn visit(decl: &super::TopDecl, path: &mut Vec<String>) -> Option<Vec<String>> {
+        if let Some(start) = path.iter().position(|name| name == decl.name()) {
+            return Some(path[start..].to_owned());
+        }
+        let deps: Vec<Rc<super::TopDecl>> = match decl {
+            super::TopDecl::Union(inner) => vec![inner.items()[0].typ()],
+            super::TopDecl::Array(inner) if inner.constraints().min_value().is_none() => {
+                vec![inner.item().typ()]
+            }
+            super::TopDecl::Struct(inner) => inner.fields().iter().map(|f| f.typ()).collect(),
+            super::TopDecl::FixVec(inner) if inner.constraints().non_empty() => {
+                vec![inner.item().typ()]
+            }
+            super::TopDecl::DynVec(inner) if inner.constraints().non_empty() => {
+                vec![inner.item().typ()]
+            }
+            super::TopDecl::Table(inner) => inner.fields().iter().map(|f| f.typ()).collect(),
+            _ => Vec::new(),
+        };
+        path.push(decl.name().to_owned());
+        let cycle = deps.into_iter().find_map(|dep| visit(&dep, path));
+        path.pop();
+        cycle
+    }
+    visit(decl, &mut Vec::new())
+}
@@ -24,6 +24,7 @@ impl_has_name_for_decl!(Struct, Struct);
 impl_has_name_for_decl!(FixVec, FixVec);
 impl_has_name_for_decl!(DynVec, DynVec);
 impl_has_name_for_decl!(Table, Table);
+impl_has_name_for_decl!(Enum, Enum);
 
 impl HasName for super::TopDecl {
     fn name(&self) -> &str {
@@ -36,6 +37,7 @@ impl HasName for super::TopDecl {
             super::TopDecl::FixVec(inner) => inner.name(),
             super::TopDecl::DynVec(inner) => inner.name(),
             super::TopDecl::Table(inner) => inner.name(),
+            super::TopDecl::Enum(inner) => inner.name(),
         }
     }
 
@@ -49,6 +51,7 @@ impl HasName for super::TopDecl {
             super::TopDecl::FixVec(inner) => inner.type_name(),
             super::TopDecl::DynVec(inner) => inner.type_name(),
             super::TopDecl::Table(inner) => inner.type_name(),
+            super::TopDecl::Enum(inner) => inner.type_name(),
         }
     }
 }
@@ -1,7 +1,15 @@
-use std::{collections::HashMap, rc::Rc};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    path::PathBuf,
+    rc::{Rc, Weak},
+};
 
+use once_cell::unsync::OnceCell;
 use property::Property;
 
+use crate::error::Location;
+
 mod complete;
 mod default_content;
 mod has_name;
@@ -12,14 +20,32 @@ mod recover;
 pub use default_content::DefaultContent;
 pub use has_name::HasName;
 
-type Deps<'a> = HashMap<&'a str, Rc<super::TopDecl>>;
+type Deps<'a> = HashMap<&'a str, TypeRef>;
+
+/// A reference to a declaration.
+///
+/// Types could refer to each other through the types which aren't fixed size, so a reference
+/// could be unresolved until all declarations in the cycle are completed.
+///
+/// The references in a cycle are weak, otherwise, the declarations are never freed. They are
+/// owned by the `Ast`, so they should be used while the `Ast` is alive.
+#[derive(Clone)]
+enum TypeRef {
+    Resolved(Rc<TopDecl>),
+    Recursive(Rc<str>, Rc<OnceCell<Weak<TopDecl>>>),
+}
 
+/// The verified AST.
+///
+/// The declarations refer to each other through their types, the recursive references are weak
+/// and owned by the `Ast`, so the `Ast` should be kept alive while the declarations are used.
 #[derive(Debug, Property)]
 #[property(get(public))]
 pub struct Ast {
     namespace: String,
     imports: Vec<ImportStmt>,
     decls: Vec<Rc<TopDecl>>,
+    consts: Vec<Const>,
 }
 
 #[derive(Debug, Clone, Property)]
@@ -28,6 +54,46 @@ pub struct ImportStmt {
     name: String,
     paths: Vec<String>,
     path_supers: usize,
+    resolved_path: Option<PathBuf>,
+}
+
+/// A constant, the value is already serialized as the type.
+#[derive(Debug, Property)]
+#[property(get(public))]
+pub struct Const {
+    name: String,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
+    typ: Rc<TopDecl>,
+    value: Vec<u8>,
+    imported_depth: usize,
+}
+
+/// An attribute of a declaration, a field or a union item.
+///
+/// The arguments are also attributes, but they don't have arguments.
+#[derive(Debug, Clone, Property)]
+#[property(get(public))]
+pub struct Attribute {
+    name: String,
+    #[property(get(disable))]
+    value: Option<String>,
+    args: Vec<Attribute>,
+    #[property(get(disable))]
+    location: Option<Location>,
+}
+
+/// The constraints of the values, they are checked by the verification besides the layout.
+///
+/// They are declared by the attributes `#[max_items = "N"]` and `#[non_empty]` on vectors, and
+/// `#[range(min = "A", max = "B")]` on arrays of bytes, which are unsigned integers in
+/// little-endian.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Constraints {
+    max_items: Option<usize>,
+    non_empty: bool,
+    min_value: Option<u64>,
+    max_value: Option<u64>,
 }
 
 #[derive(Debug)]
@@ -40,6 +106,7 @@ pub enum TopDecl {
     FixVec(FixVec),
     DynVec(DynVec),
     Table(Table),
+    Enum(Enum),
 }
 
 #[derive(Debug, Property)]
@@ -53,6 +120,8 @@ pub struct Primitive {
 #[property(get(public))]
 pub struct Option_ {
     name: String,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
     item: ItemDecl,
     imported_depth: usize,
 }
@@ -61,7 +130,9 @@ pub struct Option_ {
 #[property(get(public))]
 pub struct Union {
     name: String,
-    items: Vec<ItemDecl>,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
+    items: Vec<UnionItemDecl>,
     imported_depth: usize,
 }
 
@@ -69,16 +140,35 @@ pub struct Union {
 #[property(get(public))]
 pub struct Array {
     name: String,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
     item: ItemDecl,
     item_count: usize,
     imported_depth: usize,
     item_size: usize,
+    #[property(get(disable))]
+    builtin: Option<BuiltIn>,
+    constraints: Constraints,
+}
+
+/// The built-in types which are declared by the compiler, they have the same layouts as the
+/// arrays or the vectors of bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuiltIn {
+    /// An integer in little-endian, from `uint8` to `uint128` and from `int8` to `int128`.
+    Integer { signed: bool, size: usize },
+    /// A boolean, only `0x00` and `0x01` are valid.
+    Bool,
+    /// A UTF-8 string, it's a vector of bytes.
+    String,
 }
 
 #[derive(Debug, Property)]
 #[property(get(public))]
 pub struct Struct {
     name: String,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
     fields: Vec<FieldDecl>,
     imported_depth: usize,
     field_sizes: Vec<usize>,
@@ -88,38 +178,91 @@ pub struct Struct {
 #[property(get(public))]
 pub struct FixVec {
     name: String,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
     item: ItemDecl,
     imported_depth: usize,
     item_size: usize,
+    #[property(get(disable))]
+    builtin: Option<BuiltIn>,
+    is_map: bool,
+    constraints: Constraints,
 }
 
 #[derive(Debug, Property)]
 #[property(get(public))]
 pub struct DynVec {
     name: String,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
     item: ItemDecl,
     imported_depth: usize,
+    is_map: bool,
+    constraints: Constraints,
 }
 
 #[derive(Debug, Property)]
 #[property(get(public))]
 pub struct Table {
     name: String,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
     fields: Vec<FieldDecl>,
     imported_depth: usize,
 }
 
+/// An enumeration, it's serialized as an unsigned integer in little-endian.
+#[derive(Debug, Property)]
+#[property(get(public))]
+pub struct Enum {
+    name: String,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
+    items: Vec<EnumItemDecl>,
+    imported_depth: usize,
+    /// The size of the integer in bytes.
+    total_size: usize,
+}
+
 #[derive(Debug, Property)]
 #[property(get(public))]
 pub struct ItemDecl {
-    typ: Rc<TopDecl>,
+    #[property(get(disable))]
+    typ: TypeRef,
+}
+
+#[derive(Debug, Property)]
+#[property(get(public))]
+pub struct UnionItemDecl {
+    #[property(get(disable))]
+    name: Option<String>,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
+    #[property(get(disable))]
+    typ: TypeRef,
+    id: usize,
 }
 
 #[derive(Debug, Property)]
 #[property(get(public))]
+pub struct EnumItemDecl {
+    name: String,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
+    value: u64,
+}
+
+#[derive(Debug, Clone, Property)]
+#[property(get(public))]
 pub struct FieldDecl {
     name: String,
-    typ: Rc<TopDecl>,
+    docs: Vec<String>,
+    attributes: Vec<Attribute>,
+    #[property(get(disable))]
+    typ: TypeRef,
+    // the default value of the type is resolved lazily, since the type could be recursive
+    #[property(get(disable))]
+    default: Option<OnceCell<Vec<u8>>>,
 }
 
 impl Ast {
@@ -130,6 +273,13 @@ impl Ast {
             .map(Rc::clone)
             .collect()
     }
+
+    pub fn major_consts(&self) -> Vec<&Const> {
+        self.consts
+            .iter()
+            .filter(|c| c.imported_depth() == 0)
+            .collect()
+    }
 }
 
 impl TopDecl {
@@ -152,6 +302,36 @@ impl TopDecl {
         }
     }
 
+    /// The doc comments of the declaration, primitive types don't have them.
+    pub fn docs(&self) -> &[String] {
+        match self {
+            Self::Primitive(_) => &[],
+            Self::Option_(inner) => inner.docs(),
+            Self::Union(inner) => inner.docs(),
+            Self::Array(inner) => inner.docs(),
+            Self::Struct(inner) => inner.docs(),
+            Self::FixVec(inner) => inner.docs(),
+            Self::DynVec(inner) => inner.docs(),
+            Self::Table(inner) => inner.docs(),
+            Self::Enum(inner) => inner.docs(),
+        }
+    }
+
+    /// The attributes of the declaration, primitive types don't have them.
+    pub fn attributes(&self) -> &[Attribute] {
+        match self {
+            Self::Primitive(_) => &[],
+            Self::Option_(inner) => inner.attributes(),
+            Self::Union(inner) => inner.attributes(),
+            Self::Array(inner) => inner.attributes(),
+            Self::Struct(inner) => inner.attributes(),
+            Self::FixVec(inner) => inner.attributes(),
+            Self::DynVec(inner) => inner.attributes(),
+            Self::Table(inner) => inner.attributes(),
+            Self::Enum(inner) => inner.attributes(),
+        }
+    }
+
     fn imported_depth(&self) -> usize {
         match self {
             Self::Primitive(_) => usize::max_value(),
@@ -162,10 +342,44 @@ impl TopDecl {
             Self::FixVec(inner) => inner.imported_depth,
             Self::DynVec(inner) => inner.imported_depth,
             Self::Table(inner) => inner.imported_depth,
+            Self::Enum(inner) => inner.imported_depth,
         }
     }
 
-    fn total_size(&self) -> Option<usize> {
+    pub fn is_fixed_size(&self) -> bool {
+        self.total_size().is_some()
+    }
+
+    /// Checks whether a fixed size type has invalid bytes, so it should be verified besides
+    /// the size, for example, a `bool`, an enum or a struct which has a `bool` field.
+    pub fn has_restricted_values(&self) -> bool {
+        match self {
+            Self::Array(inner) => inner.has_restricted_values(),
+            Self::Struct(inner) => inner.has_restricted_values(),
+            Self::Enum(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Checks whether the type contains itself through the types which aren't fixed size, then
+    /// the depth of the nested data is limited when it's verified.
+    pub fn is_recursive(&self) -> bool {
+        contains_itself(self.name(), self.dependencies())
+    }
+
+    // The types which could contain this type, a fixed size type can't contain itself.
+    fn dependencies(&self) -> Vec<Rc<TopDecl>> {
+        match self {
+            TopDecl::Option_(inner) => vec![inner.item().typ()],
+            TopDecl::Union(inner) => inner.items().iter().map(UnionItemDecl::typ).collect(),
+            TopDecl::DynVec(inner) => vec![inner.item().typ()],
+            TopDecl::Table(inner) => inner.fields().iter().map(FieldDecl::typ).collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// The size in bytes, if it's fixed size.
+    pub fn total_size(&self) -> Option<usize> {
         match self {
             Self::Primitive(inner) => Some(inner.size),
             Self::Option_(_) => None,
@@ -175,20 +389,215 @@ impl TopDecl {
             Self::FixVec(_) => None,
             Self::DynVec(_) => None,
             Self::Table(_) => None,
+            Self::Enum(inner) => Some(inner.total_size()),
         }
     }
 }
 
+impl Option_ {
+    /// Checks whether the option contains itself, see [`TopDecl::is_recursive`].
+    pub fn is_recursive(&self) -> bool {
+        contains_itself(self.name(), vec![self.item().typ()])
+    }
+}
+
+impl Union {
+    /// Checks whether the union contains itself, see [`TopDecl::is_recursive`].
+    pub fn is_recursive(&self) -> bool {
+        contains_itself(
+            self.name(),
+            self.items().iter().map(UnionItemDecl::typ).collect(),
+        )
+    }
+}
+
 impl Array {
     pub fn total_size(&self) -> usize {
         self.item_size() * self.item_count()
     }
+
+    /// The built-in type which the array is declared for.
+    pub fn builtin(&self) -> Option<BuiltIn> {
+        self.builtin
+    }
+
+    pub fn has_restricted_values(&self) -> bool {
+        self.constraints.has_range()
+            || self
+                .builtin
+                .map(BuiltIn::has_restricted_values)
+                .unwrap_or_else(|| self.item().typ().has_restricted_values())
+    }
+}
+
+impl BuiltIn {
+    const INTEGER_SIZES: [usize; 5] = [1, 2, 4, 8, 16];
+
+    /// Finds the built-in type by its name in the schema.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::INTEGER_SIZES
+            .iter()
+            .flat_map(|&size| {
+                let unsigned = Self::Integer {
+                    signed: false,
+                    size,
+                };
+                let signed = Self::Integer { signed: true, size };
+                vec![unsigned, signed]
+            })
+            .chain(vec![Self::Bool, Self::String])
+            .find(|builtin| builtin.name() == name)
+    }
+
+    /// The name in the schema, for example, `uint32`.
+    pub fn name(self) -> String {
+        match self {
+            Self::Integer { signed, size } => {
+                let prefix = if signed { "int" } else { "uint" };
+                format!("{}{}", prefix, size * 8)
+            }
+            Self::Bool => "bool".to_owned(),
+            Self::String => "string".to_owned(),
+        }
+    }
+
+    /// The name of the declaration, for example, `Uint32`.
+    ///
+    /// `bool` and `string` are declared as `Boolean` and `Utf8String`, so the generated types
+    /// don't shadow `bool` and `String` of Rust when they are imported by a glob.
+    pub fn decl_name(self) -> String {
+        match self {
+            Self::Integer { .. } => {
+                let name = self.name();
+                let mut chars = name.chars();
+                let first = chars.next().unwrap().to_ascii_uppercase();
+                ::std::iter::once(first).chain(chars).collect()
+            }
+            Self::Bool => "Boolean".to_owned(),
+            Self::String => "Utf8String".to_owned(),
+        }
+    }
+
+    /// The size of the built-in type in bytes, `None` if it isn't fixed size.
+    pub fn size(self) -> Option<usize> {
+        match self {
+            Self::Integer { size, .. } => Some(size),
+            Self::Bool => Some(1),
+            Self::String => None,
+        }
+    }
+
+    /// Checks whether some bytes are invalid for the built-in type, besides the size.
+    pub fn has_restricted_values(self) -> bool {
+        match self {
+            Self::Integer { .. } => false,
+            Self::Bool | Self::String => true,
+        }
+    }
+}
+
+impl FixVec {
+    /// The built-in type which the vector is declared for.
+    pub fn builtin(&self) -> Option<BuiltIn> {
+        self.builtin
+    }
+
+    pub fn has_restricted_values(&self) -> bool {
+        self.builtin
+            .map(BuiltIn::has_restricted_values)
+            .unwrap_or_else(|| self.item().typ().has_restricted_values())
+    }
+
+    /// The fields `key` and `value` of the entries, if the vector is declared as a map.
+    pub fn map_entry(&self) -> Option<(FieldDecl, FieldDecl)> {
+        map_entry_of(self.is_map, &self.item)
+    }
+}
+
+impl DynVec {
+    /// Checks whether the vector contains itself, see [`TopDecl::is_recursive`].
+    pub fn is_recursive(&self) -> bool {
+        contains_itself(self.name(), vec![self.item().typ()])
+    }
+
+    /// The fields `key` and `value` of the entries, if the vector is declared as a map.
+    pub fn map_entry(&self) -> Option<(FieldDecl, FieldDecl)> {
+        map_entry_of(self.is_map, &self.item)
+    }
+}
+
+fn contains_itself(name: &str, mut pending: Vec<Rc<TopDecl>>) -> bool {
+    let mut visited = HashSet::new();
+    while let Some(decl) = pending.pop() {
+        if decl.name() == name {
+            return true;
+        }
+        if visited.insert(decl.name().to_owned()) {
+            pending.extend(decl.dependencies());
+        }
+    }
+    false
+}
+
+fn map_entry_of(is_map: bool, item: &ItemDecl) -> Option<(FieldDecl, FieldDecl)> {
+    if !is_map {
+        return None;
+    }
+    let entry = item.typ();
+    let fields = match entry.as_ref() {
+        TopDecl::Struct(inner) => inner.fields(),
+        TopDecl::Table(inner) => inner.fields(),
+        _ => return None,
+    };
+    match fields {
+        [key, value] => Some((key.clone(), value.clone())),
+        _ => None,
+    }
+}
+
+impl Table {
+    /// Checks whether the table contains itself, see [`TopDecl::is_recursive`].
+    pub fn is_recursive(&self) -> bool {
+        contains_itself(
+            self.name(),
+            self.fields().iter().map(FieldDecl::typ).collect(),
+        )
+    }
+
+    /// The number of the leading fields which don't have default values.
+    ///
+    /// In compatible mode, an older encoding which has fewer fields is valid if it has at least
+    /// these fields, the missing fields are the default values.
+    pub fn min_field_count(&self) -> usize {
+        self.fields()
+            .iter()
+            .take_while(|field| field.default().is_none())
+            .count()
+    }
 }
 
 impl Struct {
     pub fn total_size(&self) -> usize {
         self.field_sizes().iter().sum::<usize>()
     }
+
+    /// The offsets of the fields in bytes.
+    pub fn field_offsets(&self) -> Vec<usize> {
+        self.field_sizes()
+            .iter()
+            .scan(0, |offset, size| {
+                let current = *offset;
+                *offset += size;
+                Some(current)
+            })
+            .collect()
+    }
+
+    pub fn has_restricted_values(&self) -> bool {
+        self.fields()
+            .iter()
+            .any(|field| field.typ().has_restricted_values())
+    }
 }
 
 macro_rules! impl_into_top_decl_for {
@@ -209,20 +618,267 @@ impl_into_top_decl_for!(Struct);
 impl_into_top_decl_for!(FixVec);
 impl_into_top_decl_for!(DynVec);
 impl_into_top_decl_for!(Table);
+impl_into_top_decl_for!(Enum);
+
+impl Constraints {
+    /// The max count of the items of a vector.
+    pub fn max_items(&self) -> Option<usize> {
+        self.max_items
+    }
+
+    /// Whether a vector should have at least one item.
+    pub fn non_empty(&self) -> bool {
+        self.non_empty
+    }
+
+    /// The min value of an array, as an unsigned integer in little-endian.
+    pub fn min_value(&self) -> Option<u64> {
+        self.min_value
+    }
+
+    /// The max value of an array, as an unsigned integer in little-endian.
+    pub fn max_value(&self) -> Option<u64> {
+        self.max_value
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.has_items_limits() && !self.has_range()
+    }
+
+    /// Whether the count of the items is constrained.
+    pub fn has_items_limits(&self) -> bool {
+        self.non_empty || self.max_items.is_some()
+    }
+
+    /// Whether the value is constrained.
+    pub fn has_range(&self) -> bool {
+        self.min_value.is_some() || self.max_value.is_some()
+    }
+}
+
+impl Attribute {
+    /// The value of the attribute, if it's declared as `name = "value"`.
+    pub fn value(&self) -> Option<&str> {
+        self.value.as_ref().map(AsRef::as_ref)
+    }
+
+    /// Finds the argument with the name.
+    pub fn arg(&self, name: &str) -> Option<&Attribute> {
+        self.args.iter().find(|arg| arg.name == name)
+    }
+
+    /// Finds the attribute with the name in a list of attributes.
+    pub fn find<'a>(attributes: &'a [Attribute], name: &str) -> Option<&'a Attribute> {
+        attributes.iter().find(|attr| attr.name == name)
+    }
+
+    /// Where the attribute is written, or `None` if the AST is recovered from the intermediate
+    /// data.
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+}
+
+// The locations are ignored, so the same attributes in different schemas are equal.
+impl PartialEq for Attribute {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.value == other.value && self.args == other.args
+    }
+}
+
+impl Eq for Attribute {}
+
+impl TypeRef {
+    fn resolved(decl: Rc<TopDecl>) -> Self {
+        Self::Resolved(decl)
+    }
+
+    fn unresolved(name: &str) -> Self {
+        Self::Recursive(name.into(), Rc::new(OnceCell::new()))
+    }
+
+    fn resolve(&self, decl: &Rc<TopDecl>) {
+        if let Self::Recursive(_, cell) = self {
+            if cell.set(Rc::downgrade(decl)).is_ok() {
+                return;
+            }
+        }
+        panic!("the type `{}` is resolved more than once", decl.name());
+    }
+
+    fn name(&self) -> &str {
+        match self {
+            Self::Resolved(decl) => decl.name(),
+            Self::Recursive(name, _) => name,
+        }
+    }
+
+    fn get(&self) -> Rc<TopDecl> {
+        self.try_get()
+            .expect("the type should be resolved, and the `Ast` should be alive")
+    }
+
+    fn try_get(&self) -> Option<Rc<TopDecl>> {
+        match self {
+            Self::Resolved(decl) => Some(Rc::clone(decl)),
+            Self::Recursive(_, cell) => cell.get().and_then(Weak::upgrade),
+        }
+    }
+
+    // Only the types which aren't fixed size could be unresolved.
+    fn total_size(&self) -> Option<usize> {
+        self.try_get().and_then(|decl| decl.total_size())
+    }
+}
+
+// Only the name is printed, since the types could be recursive.
+impl fmt::Debug for TypeRef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TypeRef({})", self.name())
+    }
+}
 
 impl ItemDecl {
-    fn new(top_decl: &Rc<TopDecl>) -> Self {
+    fn new(typ: &TypeRef) -> Self {
+        Self { typ: typ.clone() }
+    }
+
+    /// The type of the item.
+    ///
+    /// The types could be recursive, so the type is shared with the `Ast` and it's returned by
+    /// value, instead of a reference to an owned type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the type is recursive and the `Ast` is already dropped, use [`try_typ`] when the
+    /// declarations could outlive the `Ast`.
+    ///
+    /// [`try_typ`]: #method.try_typ
+    pub fn typ(&self) -> Rc<TopDecl> {
+        self.typ.get()
+    }
+
+    /// The type of the item, or `None` if the type is recursive and the `Ast` is already
+    /// dropped.
+    pub fn try_typ(&self) -> Option<Rc<TopDecl>> {
+        self.typ.try_get()
+    }
+}
+
+impl UnionItemDecl {
+    fn new(
+        name: Option<&str>,
+        docs: &[String],
+        attributes: Vec<Attribute>,
+        typ: &TypeRef,
+        id: usize,
+    ) -> Self {
         Self {
-            typ: Rc::clone(top_decl),
+            name: name.map(ToOwned::to_owned),
+            docs: docs.to_owned(),
+            attributes,
+            typ: typ.clone(),
+            id,
         }
     }
+
+    /// The type of the item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the type is recursive and the `Ast` is already dropped, same as
+    /// [`ItemDecl::typ`].
+    ///
+    /// [`ItemDecl::typ`]: struct.ItemDecl.html#method.typ
+    pub fn typ(&self) -> Rc<TopDecl> {
+        self.typ.get()
+    }
+
+    /// The type of the item, or `None` if the type is recursive and the `Ast` is already
+    /// dropped.
+    pub fn try_typ(&self) -> Option<Rc<TopDecl>> {
+        self.typ.try_get()
+    }
+
+    /// The name of the item, if it's declared as `name: Type`.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_ref().map(AsRef::as_ref)
+    }
+
+    /// The name of the item if it has, otherwise, the name of its type.
+    pub fn variant_name(&self) -> &str {
+        self.name().unwrap_or_else(|| self.typ.name())
+    }
+}
+
+impl Enum {
+    /// Finds the item by its value.
+    pub fn item_by_value(&self, value: u64) -> Option<&EnumItemDecl> {
+        self.items.iter().find(|item| item.value == value)
+    }
 }
 
 impl FieldDecl {
-    fn new(name: &str, top_decl: &Rc<TopDecl>) -> Self {
+    fn new(name: &str, docs: &[String], attributes: Vec<Attribute>, typ: &TypeRef) -> Self {
         Self {
             name: name.to_owned(),
-            typ: Rc::clone(top_decl),
+            docs: docs.to_owned(),
+            attributes,
+            typ: typ.clone(),
+            default: None,
         }
     }
+
+    fn with_default(mut self, default: Option<Vec<u8>>) -> Self {
+        self.default = default.map(OnceCell::from);
+        self
+    }
+
+    // The default value is the default value of the type.
+    fn with_default_of_type(mut self) -> Self {
+        self.default = Some(OnceCell::new());
+        self
+    }
+
+    /// The type of the field.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the type is recursive and the `Ast` is already dropped, same as
+    /// [`ItemDecl::typ`].
+    ///
+    /// [`ItemDecl::typ`]: struct.ItemDecl.html#method.typ
+    pub fn typ(&self) -> Rc<TopDecl> {
+        self.typ.get()
+    }
+
+    /// The type of the field, or `None` if the type is recursive and the `Ast` is already
+    /// dropped.
+    pub fn try_typ(&self) -> Option<Rc<TopDecl>> {
+        self.typ.try_get()
+    }
+
+    /// The default value of a table field, which is used when an older encoding doesn't have
+    /// the field.
+    pub fn default(&self) -> Option<&[u8]> {
+        self.default
+            .as_ref()
+            .map(|value| &value.get_or_init(|| self.typ().default_content())[..])
+    }
+}
+
+// Decodes a hex string without the `0x` prefix.
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            if pair.len() == 2 {
+                std::str::from_utf8(pair)
+                    .ok()
+                    .and_then(|s| u8::from_str_radix(s, 16).ok())
+            } else {
+                None
+            }
+        })
+        .collect()
 }
@@ -3,78 +3,113 @@ use std::{
     rc::Rc,
 };
 
-use crate::ir;
+use crate::{
+    error::{Error, Result},
+    ir,
+};
 
+// The declarations are `None` if their dependencies aren't recovered yet.
 trait RecoverFromIr {
-    fn recover(&self, deps: &super::Deps) -> Option<super::TopDecl>;
+    fn recover(&self, deps: &super::Deps) -> Result<Option<super::TopDecl>>;
 }
 
 impl RecoverFromIr for ir::Option_ {
-    fn recover(&self, deps: &super::Deps) -> Option<super::TopDecl> {
-        deps.get(self.item().typ()).map(|dep| {
+    fn recover(&self, deps: &super::Deps) -> Result<Option<super::TopDecl>> {
+        let decl = deps.get(self.item().typ()).map(|dep| {
             let name = self.name().to_owned();
             let item = super::ItemDecl::new(dep);
             super::Option_ {
                 name,
+                docs: self.docs().to_owned(),
+                attributes: recover_attributes(self.attributes()),
                 item,
                 imported_depth: self.imported_depth(),
             }
             .into()
-        })
+        });
+        Ok(decl)
     }
 }
 
 impl RecoverFromIr for ir::Union {
-    fn recover(&self, deps: &super::Deps) -> Option<super::TopDecl> {
+    fn recover(&self, deps: &super::Deps) -> Result<Option<super::TopDecl>> {
         if self.items().is_empty() {
-            panic!("the union ({}) is empty", self.name());
+            return Err(invalid(format!("the union ({}) is empty", self.name())));
         }
-        self.items()
-            .iter()
-            .map(|ir_item| deps.get(ir_item.typ()).map(super::ItemDecl::new))
-            .collect::<Option<Vec<_>>>()
-            .map(|items| {
-                let name = self.name().to_owned();
-                super::Union {
-                    name,
-                    items,
-                    imported_depth: self.imported_depth(),
-                }
-                .into()
-            })
+        let mut ids = HashSet::new();
+        let mut next_id = 0;
+        let mut items = Vec::with_capacity(self.items().len());
+        for ir_item in self.items() {
+            let id = ir_item.id().unwrap_or(next_id);
+            if !ids.insert(id) {
+                return Err(invalid(format!(
+                    "the id ({}) is used more than once in union ({})",
+                    id,
+                    self.name()
+                )));
+            }
+            next_id = id.saturating_add(1);
+            if let Some(dep) = deps.get(ir_item.typ()) {
+                let attributes = recover_attributes(ir_item.attributes());
+                let item =
+                    super::UnionItemDecl::new(ir_item.name(), ir_item.docs(), attributes, dep, id);
+                items.push(item);
+            } else {
+                return Ok(None);
+            }
+        }
+        let name = self.name().to_owned();
+        let decl = super::Union {
+            name,
+            docs: self.docs().to_owned(),
+            attributes: recover_attributes(self.attributes()),
+            items,
+            imported_depth: self.imported_depth(),
+        };
+        Ok(Some(decl.into()))
     }
 }
 
 impl RecoverFromIr for ir::Array {
-    fn recover(&self, deps: &super::Deps) -> Option<super::TopDecl> {
-        deps.get(self.item().typ()).map(|dep| {
-            let item_size = dep.total_size().unwrap_or_else(|| {
-                panic!(
-                    "the item type ({}) of array ({}) doesn't have fixed size",
-                    self.item().typ(),
-                    self.name(),
-                );
-            });
-            if item_size == 0 {
-                panic!("the array ({}) has no size", self.name());
-            }
-            let name = self.name().to_owned();
-            let item = super::ItemDecl::new(dep);
-            let item_count = self.item_count();
-            super::Array {
-                name,
-                item,
-                item_count,
-                imported_depth: self.imported_depth(),
-                item_size,
-            }
-            .into()
-        })
+    fn recover(&self, deps: &super::Deps) -> Result<Option<super::TopDecl>> {
+        let dep = if let Some(dep) = deps.get(self.item().typ()) {
+            dep
+        } else {
+            return Ok(None);
+        };
+        let item_size = dep.total_size().ok_or_else(|| {
+            invalid(format!(
+                "the item type ({}) of array ({}) doesn't have fixed size",
+                self.item().typ(),
+                self.name(),
+            ))
+        })?;
+        if item_size == 0 {
+            return Err(invalid(format!("the array ({}) has no size", self.name())));
+        }
+        let name = self.name().to_owned();
+        let item = super::ItemDecl::new(dep);
+        let item_count = self.item_count();
+        let decl = super::Array {
+            name,
+            docs: self.docs().to_owned(),
+            attributes: recover_attributes(self.attributes()),
+            item,
+            item_count,
+            imported_depth: self.imported_depth(),
+            item_size,
+            builtin: self
+                .builtin()
+                .map(|name| recover_builtin(name))
+                .transpose()?,
+            constraints: recover_constraints(self.constraints()),
+        };
+        Ok(Some(decl.into()))
     }
 }
 
 impl RecoverFromIr for ir::Struct {
-    fn recover(&self, deps: &super::Deps) -> Option<super::TopDecl> {
+    fn recover(&self, deps: &super::Deps) -> Result<Option<super::TopDecl>> {
         let mut fields = Vec::with_capacity(self.fields().len());
         let mut field_sizes = Vec::with_capacity(self.fields().len());
         for ir_field in self.fields() {
@@ -83,130 +118,249 @@ impl RecoverFromIr for ir::Struct {
                 if let Some(field_size) = dep.total_size() {
                     field_sizes.push(field_size);
                 } else {
-                    panic!(
-                        "the filed type ({}) in struct ({}) doesn't have fixed size",
+                    return Err(invalid(format!(
+                        "the type ({}) of field ({}) in struct ({}) doesn't have fixed size",
+                        ir_field.typ(),
                         field_name,
                         self.name(),
-                    );
+                    )));
                 }
-                let field = super::FieldDecl::new(field_name, dep);
+                let field = {
+                    let attributes = recover_attributes(ir_field.attributes());
+                    super::FieldDecl::new(field_name, ir_field.docs(), attributes, dep)
+                };
                 fields.push(field);
             } else {
                 break;
             }
         }
         if fields.len() != self.fields().len() {
-            return None;
+            return Ok(None);
         }
         if field_sizes.iter().sum::<usize>() == 0 {
-            panic!("the struct ({}) has no size", self.name());
+            return Err(invalid(format!("the struct ({}) has no size", self.name())));
         }
         let name = self.name().to_owned();
-        Some(
-            super::Struct {
-                name,
-                fields,
-                imported_depth: self.imported_depth(),
-                field_sizes,
-            }
-            .into(),
-        )
+        let decl = super::Struct {
+            name,
+            docs: self.docs().to_owned(),
+            attributes: recover_attributes(self.attributes()),
+            fields,
+            imported_depth: self.imported_depth(),
+            field_sizes,
+        };
+        Ok(Some(decl.into()))
     }
 }
 
 impl RecoverFromIr for ir::FixVec {
-    fn recover(&self, deps: &super::Deps) -> Option<super::TopDecl> {
-        deps.get(self.item().typ()).map(|dep| {
-            let name = self.name().to_owned();
-            let item = super::ItemDecl::new(dep);
-            let item_size = dep.total_size().unwrap();
-            super::FixVec {
-                name,
-                item,
-                imported_depth: self.imported_depth(),
-                item_size,
-            }
-            .into()
-        })
+    fn recover(&self, deps: &super::Deps) -> Result<Option<super::TopDecl>> {
+        let dep = if let Some(dep) = deps.get(self.item().typ()) {
+            dep
+        } else {
+            return Ok(None);
+        };
+        let item_size = dep.total_size().ok_or_else(|| {
+            invalid(format!(
+                "the item type ({}) of fixvec ({}) doesn't have fixed size",
+                self.item().typ(),
+                self.name(),
+            ))
+        })?;
+        let name = self.name().to_owned();
+        let item = super::ItemDecl::new(dep);
+        let decl = super::FixVec {
+            name,
+            docs: self.docs().to_owned(),
+            attributes: recover_attributes(self.attributes()),
+            item,
+            imported_depth: self.imported_depth(),
+            item_size,
+            builtin: self
+                .builtin()
+                .map(|name| recover_builtin(name))
+                .transpose()?,
+            is_map: self.map(),
+            constraints: recover_constraints(self.constraints()),
+        };
+        check_map_entry(decl.name(), decl.is_map(), decl.map_entry().is_some())?;
+        Ok(Some(decl.into()))
     }
 }
 
 impl RecoverFromIr for ir::DynVec {
-    fn recover(&self, deps: &super::Deps) -> Option<super::TopDecl> {
-        deps.get(self.item().typ()).map(|dep| {
-            let name = self.name().to_owned();
-            let item = super::ItemDecl::new(dep);
-            super::DynVec {
-                name,
-                item,
-                imported_depth: self.imported_depth(),
-            }
-            .into()
-        })
+    fn recover(&self, deps: &super::Deps) -> Result<Option<super::TopDecl>> {
+        let dep = if let Some(dep) = deps.get(self.item().typ()) {
+            dep
+        } else {
+            return Ok(None);
+        };
+        let name = self.name().to_owned();
+        let item = super::ItemDecl::new(dep);
+        let decl = super::DynVec {
+            name,
+            docs: self.docs().to_owned(),
+            attributes: recover_attributes(self.attributes()),
+            item,
+            imported_depth: self.imported_depth(),
+            is_map: self.map(),
+            constraints: recover_constraints(self.constraints()),
+        };
+        check_map_entry(decl.name(), decl.is_map(), decl.map_entry().is_some())?;
+        Ok(Some(decl.into()))
+    }
+}
+
+fn recover_constraints(ir: &ir::Constraints) -> super::Constraints {
+    super::Constraints {
+        max_items: ir.max_items(),
+        non_empty: ir.non_empty(),
+        min_value: ir.min_value(),
+        max_value: ir.max_value(),
+    }
+}
+
+fn check_map_entry(name: &str, is_map: bool, has_entry: bool) -> Result<()> {
+    if is_map && !has_entry {
+        return Err(invalid(format!(
+            "the items of the map ({}) should have two fields, `key` and `value`",
+            name
+        )));
     }
+    Ok(())
 }
 
 impl RecoverFromIr for ir::Table {
-    fn recover(&self, deps: &super::Deps) -> Option<super::TopDecl> {
-        self.fields()
+    fn recover(&self, deps: &super::Deps) -> Result<Option<super::TopDecl>> {
+        let mut fields = Vec::with_capacity(self.fields().len());
+        for ir_field in self.fields() {
+            let field_name = ir_field.name();
+            let dep = if let Some(dep) = deps.get(ir_field.typ()) {
+                dep
+            } else {
+                return Ok(None);
+            };
+            let attributes = recover_attributes(ir_field.attributes());
+            let default = ir_field
+                .default()
+                .map(|value| {
+                    decode_hex(value).ok_or_else(|| {
+                        invalid(format!(
+                            "the default value of field ({}) in table ({}) is not hex",
+                            field_name,
+                            self.name()
+                        ))
+                    })
+                })
+                .transpose()?;
+            let field = super::FieldDecl::new(field_name, ir_field.docs(), attributes, dep)
+                .with_default(default);
+            fields.push(field);
+        }
+        let name = self.name().to_owned();
+        let min_field_count = fields
             .iter()
-            .map(|ir_field| {
-                let field_name = ir_field.name();
-                deps.get(ir_field.typ())
-                    .map(|dep| super::FieldDecl::new(field_name, dep))
-            })
-            .collect::<Option<Vec<_>>>()
-            .map(|fields| {
-                let name = self.name().to_owned();
-                super::Table {
-                    name,
-                    fields,
-                    imported_depth: self.imported_depth(),
-                }
-                .into()
+            .take_while(|field| field.default().is_none())
+            .count();
+        if fields[min_field_count..]
+            .iter()
+            .any(|field| field.default().is_none())
+        {
+            return Err(invalid(format!(
+                "the fields which have default values should be the trailing fields of table ({})",
+                name
+            )));
+        }
+        let decl = super::Table {
+            name,
+            docs: self.docs().to_owned(),
+            attributes: recover_attributes(self.attributes()),
+            fields,
+            imported_depth: self.imported_depth(),
+        };
+        Ok(Some(decl.into()))
+    }
+}
+
+impl RecoverFromIr for ir::Enum {
+    fn recover(&self, _deps: &super::Deps) -> Result<Option<super::TopDecl>> {
+        if self.size() == 0 || self.size() > 8 {
+            let reason = format!("the size of enum ({}) should be 1 to 8 bytes", self.name());
+            return Err(invalid(reason));
+        }
+        if self.items().is_empty() {
+            let reason = format!("the enum ({}) doesn't have any items", self.name());
+            return Err(invalid(reason));
+        }
+        let items = self
+            .items()
+            .iter()
+            .map(|ir_item| super::EnumItemDecl {
+                name: ir_item.name().to_owned(),
+                docs: ir_item.docs().to_owned(),
+                attributes: recover_attributes(ir_item.attributes()),
+                value: ir_item.value(),
             })
+            .collect();
+        let decl = super::Enum {
+            name: self.name().to_owned(),
+            docs: self.docs().to_owned(),
+            attributes: recover_attributes(self.attributes()),
+            items,
+            imported_depth: self.imported_depth(),
+            total_size: self.size(),
+        };
+        Ok(Some(decl.into()))
     }
 }
 
 impl super::Ast {
-    pub(crate) fn recover(ir: ir::Ir) -> Self {
+    pub(crate) fn recover(ir: ir::Ir) -> Result<Self> {
         let mut decls_idx = HashMap::new();
         let mut decls_keys = HashSet::new();
         for decl in ir.decls() {
             let name = decl.name();
             if super::TopDecl::new_primitive(name.to_lowercase().as_str()).is_some() {
-                panic!("the name `{}` is reserved", name);
+                return Err(invalid(format!("the name ({}) is reserved", name)));
             }
             if decls_idx.insert(name, decl).is_some() || !decls_keys.insert(name) {
-                panic!("the name `{}` is used more than once", name);
+                return Err(invalid(format!(
+                    "the name ({}) is used more than once",
+                    name
+                )));
             };
         }
         let mut decls_result = HashMap::new();
         decls_result.insert(
             "byte",
-            Rc::new(super::TopDecl::new_primitive("byte").unwrap()),
+            super::TypeRef::resolved(Rc::new(super::TopDecl::new_primitive("byte").unwrap())),
         );
         loop {
             if decls_keys.is_empty() {
                 break;
             }
             let unrecovered = decls_keys.len();
-            decls_keys.retain(|&name| {
+            let mut recovered = Vec::new();
+            for &name in &decls_keys {
                 let decl_ir = decls_idx.get(name).unwrap();
-                if let Some(decl) = super::TopDecl::recover(decl_ir, &decls_result) {
-                    decls_result.insert(name, Rc::new(decl));
-                    false
-                } else {
-                    true
+                if let Some(decl) = super::TopDecl::recover(decl_ir, &decls_result)? {
+                    recovered.push((name, decl));
                 }
-            });
+            }
+            for (name, decl) in recovered {
+                decls_keys.remove(name);
+                decls_result.insert(name, super::TypeRef::resolved(Rc::new(decl)));
+            }
             if decls_keys.len() == unrecovered {
-                panic!(
-                    "there are {} types which are unable to be recovered: {:?}",
-                    unrecovered, decls_keys
-                );
+                recover_recursive_decls(&decls_keys, &decls_idx, &mut decls_result)?;
+                decls_keys.clear();
             }
         }
+        let consts = ir
+            .consts()
+            .iter()
+            .map(|ir_const| super::Const::recover(ir_const, &decls_result))
+            .collect::<Result<Vec<_>>>()?;
         let namespace = ir.namespace().to_owned();
         let imports = ir
             .imports()
@@ -216,14 +370,90 @@ impl super::Ast {
         // remove the primitive types and keep the order
         let mut decls = Vec::with_capacity(ir.decls().len());
         for decl in ir.decls() {
-            let result = decls_result.get(decl.name()).unwrap();
-            decls.push(Rc::clone(result));
+            let result = decls_result.get(decl.name()).unwrap().get();
+            // the layout is computed by the compiler, it should be same as the recovered one
+            if let Some(layout) = decl.layout() {
+                if *layout != ir::layout_of(&result) {
+                    let reason = format!("the layout of type ({}) doesn't match", decl.name());
+                    return Err(invalid(reason));
+                }
+            }
+            decls.push(result);
         }
-        Self {
+        Ok(Self {
             namespace,
             imports,
             decls,
+            consts,
+        })
+    }
+}
+
+// The remaining declarations depend on each other, or depend on the declarations which depend on
+// each other, the recursion should be through the types which aren't fixed size.
+fn recover_recursive_decls<'a>(
+    names: &HashSet<&'a str>,
+    decls_idx: &HashMap<&'a str, &ir::TopDecl>,
+    deps: &mut super::Deps<'a>,
+) -> Result<()> {
+    for &name in names {
+        deps.insert(name, super::TypeRef::unresolved(name));
+    }
+    let mut recovered = Vec::with_capacity(names.len());
+    for &name in names {
+        let decl_ir = decls_idx.get(name).unwrap();
+        let decl = super::TopDecl::recover(decl_ir, deps)?
+            .map(Rc::new)
+            .ok_or_else(|| {
+                invalid(format!(
+                    "the type ({}) depends on the types which are not declared",
+                    name
+                ))
+            })?;
+        recovered.push((name, decl));
+    }
+    for (name, decl) in &recovered {
+        deps.get(name).unwrap().resolve(decl);
+        // the declarations refer to each other weakly, so the dependencies own them
+        deps.insert(name, super::TypeRef::resolved(Rc::clone(decl)));
+    }
+    for (name, decl) in &recovered {
+        if let Some(cycle) = super::default_content::find_infinite_default(decl) {
+            return Err(invalid(format!(
+                "the default value of type ({}) is infinite, since it contains {}",
+                name,
+                cycle.join(" -> ")
+            )));
+        }
+    }
+    Ok(())
+}
+
+impl super::Const {
+    fn recover(ir: &ir::Const, deps: &super::Deps) -> Result<Self> {
+        let typ = deps.get(ir.typ()).ok_or_else(|| {
+            invalid(format!(
+                "the type ({}) of constant ({}) is not declared",
+                ir.typ(),
+                ir.name()
+            ))
+        })?;
+        let value = decode_hex(ir.value())
+            .ok_or_else(|| invalid(format!("the value of constant ({}) is not hex", ir.name())))?;
+        if typ.total_size() != Some(value.len()) {
+            return Err(invalid(format!(
+                "the value of constant ({}) doesn't match its type",
+                ir.name()
+            )));
         }
+        Ok(Self {
+            name: ir.name().to_owned(),
+            docs: ir.docs().to_owned(),
+            attributes: recover_attributes(ir.attributes()),
+            typ: typ.get(),
+            value,
+            imported_depth: ir.imported_depth(),
+        })
     }
 }
 
@@ -233,12 +463,13 @@ impl super::ImportStmt {
             name: ir.name().to_owned(),
             paths: ir.paths().to_owned(),
             path_supers: ir.path_supers(),
+            resolved_path: ir.resolved_path().cloned(),
         }
     }
 }
 
 impl super::TopDecl {
-    fn recover(ir: &ir::TopDecl, deps: &super::Deps) -> Option<Self> {
+    fn recover(ir: &ir::TopDecl, deps: &super::Deps) -> Result<Option<Self>> {
         match ir {
             ir::TopDecl::Option_(inner) => inner.recover(deps),
             ir::TopDecl::Union(inner) => inner.recover(deps),
@@ -247,6 +478,36 @@ impl super::TopDecl {
             ir::TopDecl::FixVec(inner) => inner.recover(deps),
             ir::TopDecl::DynVec(inner) => inner.recover(deps),
             ir::TopDecl::Table(inner) => inner.recover(deps),
+            ir::TopDecl::Enum(inner) => inner.recover(deps),
         }
     }
 }
+
+fn recover_attributes(ir: &[ir::Attribute]) -> Vec<super::Attribute> {
+    ir.iter()
+        .map(|attr| super::Attribute {
+            name: attr.name().to_owned(),
+            value: attr.value().cloned(),
+            args: recover_attributes(attr.args()),
+            location: None,
+        })
+        .collect()
+}
+
+fn recover_builtin(name: &str) -> Result<super::BuiltIn> {
+    super::BuiltIn::from_name(name)
+        .ok_or_else(|| invalid(format!("the built-in type ({}) is unknown", name)))
+}
+
+// Decodes a hex string with the `0x` prefix.
+fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    let mut parts = value.splitn(2, "0x");
+    match (parts.next(), parts.next()) {
+        (Some(""), Some(hex)) => super::decode_hex(hex),
+        _ => None,
+    }
+}
+
+fn invalid(reason: String) -> Error {
+    Error::Intermediate(reason)
+}
@@ -0,0 +1,376 @@
+//! Checks whether the data of an older schema are still valid for a newer schema.
+
+use std::{cmp, collections::HashMap, fmt};
+
+use property::Property;
+
+use crate::ast::{self, HasName as _};
+
+/// How a declaration is changed between two versions of a schema.
+///
+/// The variants are ordered by severity, so the compatibility of several changes is the maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Compatibility {
+    /// The encoding isn't changed, only the docs or the attributes could be changed.
+    Identical,
+    /// The older data are still valid, for example, some fields with default values are appended
+    /// to a table.
+    Compatible,
+    /// Some older data are invalid, or they are decoded as different values.
+    Breaking,
+}
+
+/// The change of a declaration.
+#[derive(Debug, Clone, PartialEq, Eq, Property)]
+#[property(get(public))]
+pub struct Change {
+    name: String,
+    compatibility: Compatibility,
+    /// What are changed, it's empty if the declaration is identical.
+    reasons: Vec<String>,
+}
+
+/// The changes of all declarations, in the order of the newer schema, then the removed ones.
+#[derive(Debug, Clone, PartialEq, Eq, Property)]
+#[property(get(public))]
+pub struct CompatibilityReport {
+    changes: Vec<Change>,
+}
+
+/// Compares two versions of a schema declaration by declaration.
+///
+/// The declarations are matched by their names, the types of the fields and the items are
+/// compared by their names, too, and each of them is reported as another declaration.
+pub fn check_compatibility(old: &ast::Ast, new: &ast::Ast) -> CompatibilityReport {
+    let old_decls = old
+        .decls()
+        .iter()
+        .map(|decl| (decl.name(), decl.as_ref()))
+        .collect::<HashMap<_, _>>();
+    let mut changes = new
+        .decls()
+        .iter()
+        .map(|decl| {
+            let name = decl.name();
+            let mut checker = Checker::default();
+            if let Some(old_decl) = old_decls.get(name) {
+                checker.compare(old_decl, decl);
+            } else {
+                checker.compatible("it's added".to_owned());
+            }
+            checker.into_change(name)
+        })
+        .collect::<Vec<_>>();
+    for decl in old.decls() {
+        if !new
+            .decls()
+            .iter()
+            .any(|new_decl| new_decl.name() == decl.name())
+        {
+            let mut checker = Checker::default();
+            checker.breaking("it's removed".to_owned());
+            changes.push(checker.into_change(decl.name()));
+        }
+    }
+    CompatibilityReport { changes }
+}
+
+impl Change {
+    pub fn is_identical(&self) -> bool {
+        self.compatibility == Compatibility::Identical
+    }
+}
+
+impl CompatibilityReport {
+    /// The compatibility of the whole schema, which is the worst of all changes.
+    pub fn compatibility(&self) -> Compatibility {
+        self.changes
+            .iter()
+            .map(|change| *change.compatibility())
+            .max()
+            .unwrap_or(Compatibility::Identical)
+    }
+
+    pub fn is_breaking(&self) -> bool {
+        self.compatibility() == Compatibility::Breaking
+    }
+}
+
+impl fmt::Display for Compatibility {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Self::Identical => "identical",
+            Self::Compatible => "compatible",
+            Self::Breaking => "breaking",
+        };
+        f.pad(name)
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:10} {}", self.compatibility, self.name)?;
+        for reason in &self.reasons {
+            write!(f, "\n{:10} - {}", "", reason)?;
+        }
+        Ok(())
+    }
+}
+
+/// Only the changed declarations are displayed, then the compatibility of the whole schema.
+impl fmt::Display for CompatibilityReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for change in self.changes.iter().filter(|change| !change.is_identical()) {
+            writeln!(f, "{}", change)?;
+        }
+        write!(f, "the new schema is {}", self.compatibility())
+    }
+}
+
+#[derive(Default)]
+struct Checker {
+    compatibility: Option<Compatibility>,
+    reasons: Vec<String>,
+}
+
+impl Checker {
+    fn into_change(self, name: &str) -> Change {
+        Change {
+            name: name.to_owned(),
+            compatibility: self.compatibility.unwrap_or(Compatibility::Identical),
+            reasons: self.reasons,
+        }
+    }
+
+    fn record(&mut self, compatibility: Compatibility, reason: String) {
+        self.compatibility = Some(cmp::max(
+            self.compatibility.unwrap_or(compatibility),
+            compatibility,
+        ));
+        self.reasons.push(reason);
+    }
+
+    fn compatible(&mut self, reason: String) {
+        self.record(Compatibility::Compatible, reason);
+    }
+
+    fn breaking(&mut self, reason: String) {
+        self.record(Compatibility::Breaking, reason);
+    }
+
+    fn compare_type(&mut self, what: &str, old: &ast::TopDecl, new: &ast::TopDecl) {
+        if old.name() != new.name() {
+            self.breaking(format!(
+                "the type of {} is changed from `{}` to `{}`",
+                what,
+                old.name(),
+                new.name()
+            ));
+        }
+    }
+
+    fn compare(&mut self, old: &ast::TopDecl, new: &ast::TopDecl) {
+        match (old, new) {
+            (ast::TopDecl::Primitive(_), ast::TopDecl::Primitive(_)) => {}
+            (ast::TopDecl::Option_(old), ast::TopDecl::Option_(new)) => {
+                self.compare_type("the item", &old.item().typ(), &new.item().typ());
+            }
+            (ast::TopDecl::Union(old), ast::TopDecl::Union(new)) => self.compare_union(old, new),
+            (ast::TopDecl::Array(old), ast::TopDecl::Array(new)) => {
+                self.compare_type("the items", &old.item().typ(), &new.item().typ());
+                if old.item_count() != new.item_count() {
+                    self.breaking(format!(
+                        "the array is resized from {} to {} items",
+                        old.item_count(),
+                        new.item_count()
+                    ));
+                }
+                self.compare_constraints(old.constraints(), new.constraints());
+            }
+            (ast::TopDecl::Struct(old), ast::TopDecl::Struct(new)) => self.compare_struct(old, new),
+            (ast::TopDecl::FixVec(old), ast::TopDecl::FixVec(new)) => {
+                self.compare_type("the items", &old.item().typ(), &new.item().typ());
+                self.compare_map(old.is_map(), new.is_map());
+                self.compare_constraints(old.constraints(), new.constraints());
+            }
+            (ast::TopDecl::DynVec(old), ast::TopDecl::DynVec(new)) => {
+                self.compare_type("the items", &old.item().typ(), &new.item().typ());
+                self.compare_map(old.is_map(), new.is_map());
+                self.compare_constraints(old.constraints(), new.constraints());
+            }
+            (ast::TopDecl::Table(old), ast::TopDecl::Table(new)) => self.compare_table(old, new),
+            (ast::TopDecl::Enum(old), ast::TopDecl::Enum(new)) => self.compare_enum(old, new),
+            _ => self.breaking(format!("it's changed from {} to {}", kind(old), kind(new))),
+        }
+    }
+
+    fn compare_union(&mut self, old: &ast::Union, new: &ast::Union) {
+        for old_item in old.items() {
+            let what = format!("the item `{}`", old_item.variant_name());
+            match new.items().iter().find(|item| item.id() == old_item.id()) {
+                Some(new_item) => {
+                    self.compare_type(&what, &old_item.typ(), &new_item.typ());
+                    if old_item.variant_name() != new_item.variant_name() {
+                        self.compatible(format!(
+                            "{} is renamed to `{}`",
+                            what,
+                            new_item.variant_name()
+                        ));
+                    }
+                }
+                None => self.breaking(format!("{} (id {}) is removed", what, old_item.id())),
+            }
+        }
+        for new_item in new.items() {
+            if !old.items().iter().any(|item| item.id() == new_item.id()) {
+                self.compatible(format!(
+                    "the item `{}` (id {}) is added",
+                    new_item.variant_name(),
+                    new_item.id()
+                ));
+            }
+        }
+    }
+
+    fn compare_struct(&mut self, old: &ast::Struct, new: &ast::Struct) {
+        if old.fields().len() != new.fields().len() {
+            self.breaking(format!(
+                "the number of the fields is changed from {} to {}",
+                old.fields().len(),
+                new.fields().len()
+            ));
+            return;
+        }
+        self.compare_fields(old.fields(), new.fields());
+    }
+
+    fn compare_table(&mut self, old: &ast::Table, new: &ast::Table) {
+        if old.fields().len() > new.fields().len() {
+            let removed = &old.fields()[new.fields().len()..];
+            for field in removed {
+                self.breaking(format!("the field `{}` is removed", field.name()));
+            }
+        }
+        let len = cmp::min(old.fields().len(), new.fields().len());
+        self.compare_fields(&old.fields()[..len], &new.fields()[..len]);
+        for (old_field, new_field) in old.fields().iter().zip(new.fields()) {
+            // the older data which miss the field are decoded as different values
+            if old_field.default() != new_field.default() {
+                self.breaking(format!(
+                    "the default value of the field `{}` is changed",
+                    new_field.name()
+                ));
+            }
+        }
+        for field in &new.fields()[len..] {
+            if field.default().is_some() {
+                self.compatible(format!("the field `{}` is appended", field.name()));
+            } else {
+                self.breaking(format!(
+                    "the field `{}` is appended without a default value, so the older data \
+                    can't be decoded by the newer schema",
+                    field.name()
+                ));
+            }
+        }
+    }
+
+    // The fields are compared by their positions.
+    fn compare_fields(&mut self, old: &[ast::FieldDecl], new: &[ast::FieldDecl]) {
+        for (old_field, new_field) in old.iter().zip(new) {
+            let what = format!("the field `{}`", old_field.name());
+            self.compare_type(&what, &old_field.typ(), &new_field.typ());
+            if old_field.name() != new_field.name() {
+                self.compatible(format!("{} is renamed to `{}`", what, new_field.name()));
+            }
+        }
+    }
+
+    fn compare_enum(&mut self, old: &ast::Enum, new: &ast::Enum) {
+        if old.total_size() != new.total_size() {
+            self.breaking(format!(
+                "the size is changed from {} to {} bytes",
+                old.total_size(),
+                new.total_size()
+            ));
+            return;
+        }
+        for old_item in old.items() {
+            match new.item_by_value(old_item.value()) {
+                Some(new_item) if new_item.name() != old_item.name() => {
+                    self.compatible(format!(
+                        "the item `{}` is renamed to `{}`",
+                        old_item.name(),
+                        new_item.name()
+                    ));
+                }
+                Some(_) => {}
+                None => self.breaking(format!(
+                    "the item `{}` (value {}) is removed",
+                    old_item.name(),
+                    old_item.value()
+                )),
+            }
+        }
+        for new_item in new.items() {
+            if old.item_by_value(new_item.value()).is_none() {
+                self.compatible(format!(
+                    "the item `{}` (value {}) is added",
+                    new_item.name(),
+                    new_item.value()
+                ));
+            }
+        }
+        if old.items()[0].value() != new.items()[0].value() {
+            self.compatible("the default value is changed".to_owned());
+        }
+    }
+
+    fn compare_map(&mut self, old: bool, new: bool) {
+        match (old, new) {
+            (false, true) => self.breaking(
+                "it's changed to a map, the keys of the older data could be unsorted".to_owned(),
+            ),
+            (true, false) => self.compatible("it's changed from a map to a vector".to_owned()),
+            _ => {}
+        }
+    }
+
+    // The older data are still valid if the newer constraints are looser.
+    fn compare_constraints(&mut self, old: &ast::Constraints, new: &ast::Constraints) {
+        if old == new {
+            return;
+        }
+        let looser = |old: Option<u64>, new: Option<u64>, is_looser: fn(u64, u64) -> bool| match (
+            old, new,
+        ) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(old), Some(new)) => is_looser(old, new),
+        };
+        let max_items = |constraints: &ast::Constraints| constraints.max_items().map(|n| n as u64);
+        let is_looser = looser(max_items(old), max_items(new), |old, new| new >= old)
+            && (old.non_empty() || !new.non_empty())
+            && looser(old.min_value(), new.min_value(), |old, new| new <= old)
+            && looser(old.max_value(), new.max_value(), |old, new| new >= old);
+        if is_looser {
+            self.compatible("the constraints are looser".to_owned());
+        } else {
+            self.breaking("the constraints are stricter".to_owned());
+        }
+    }
+}
+
+fn kind(decl: &ast::TopDecl) -> &'static str {
+    match decl {
+        ast::TopDecl::Primitive(_) => "a primitive type",
+        ast::TopDecl::Option_(_) => "an option",
+        ast::TopDecl::Union(_) => "a union",
+        ast::TopDecl::Array(_) => "an array",
+        ast::TopDecl::Struct(_) => "a struct",
+        ast::TopDecl::FixVec(_) => "a fixvec",
+        ast::TopDecl::DynVec(_) => "a dynvec",
+        ast::TopDecl::Table(_) => "a table",
+        ast::TopDecl::Enum(_) => "an enum",
+    }
+}
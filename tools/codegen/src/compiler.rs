@@ -1,17 +1,27 @@
 use std::{env, ffi, fs, io, io::Write as _, path};
 
 #[cfg(feature = "compiler-plugin")]
-use std::process;
+use std::{
+    io::{BufRead as _, Read as _},
+    process, thread,
+};
 
-use crate::{generator, parser};
+use crate::{
+    error::{Error, Result},
+    generator, parser,
+};
 
 #[cfg(feature = "compiler-plugin")]
-use crate::ir;
+use crate::{ir, plugin};
 
 pub struct Compiler {
     target: Option<generator::Target>,
+    #[cfg(feature = "compiler-plugin")]
+    intermediate_contents: ir::Contents,
     input: Option<Input>,
     output: Option<Output>,
+    resolver: parser::FileResolver,
+    parser: parser::Parser,
 }
 
 pub(crate) enum Input {
@@ -24,7 +34,9 @@ pub(crate) enum Output {
     Directory(path::PathBuf),
     Stdout,
     #[cfg(feature = "compiler-plugin")]
-    PluginProcess(process::Child),
+    /// The plugin process, and the directory to write the files in the manifest, which is output
+    /// by the plugin, or `None` if the plugin outputs the code to stdout directly.
+    PluginProcess(process::Child, Option<path::PathBuf>),
 }
 
 impl Default for Compiler {
@@ -37,8 +49,12 @@ impl Compiler {
     pub fn new() -> Self {
         Self {
             target: None,
+            #[cfg(feature = "compiler-plugin")]
+            intermediate_contents: ir::Contents::default(),
             input: None,
             output: Some(Output::Stdout),
+            resolver: parser::FileResolver::default(),
+            parser: parser::Parser::default(),
         }
     }
 
@@ -49,7 +65,36 @@ impl Compiler {
 
     #[cfg(feature = "compiler-plugin")]
     pub fn generate_intermediate(&mut self, format: ir::Format) -> &mut Self {
-        self.target.replace(generator::Target::Intermediate(format));
+        let target = generator::Target::Intermediate(format, self.intermediate_contents);
+        self.target.replace(target);
+        self
+    }
+
+    /// Includes the layout of the declarations in the intermediate data: whether they are fixed
+    /// size, the total sizes, the offsets of the fields of structs and the default values.
+    #[cfg(feature = "compiler-plugin")]
+    pub fn intermediate_layout(&mut self, include: bool) -> &mut Self {
+        self.intermediate_contents.layout = include;
+        self.update_intermediate_contents()
+    }
+
+    /// Removes the doc comments and the attributes from the intermediate data, unless the
+    /// features of the plugin include them.
+    #[cfg(feature = "compiler-plugin")]
+    pub fn intermediate_features(&mut self, features: &[plugin::Feature]) -> &mut Self {
+        self.intermediate_contents.docs = features.contains(&plugin::Feature::DocComments);
+        self.intermediate_contents.attributes = features.contains(&plugin::Feature::Attributes);
+        self.update_intermediate_contents()
+    }
+
+    #[cfg(feature = "compiler-plugin")]
+    fn update_intermediate_contents(&mut self) -> &mut Self {
+        if let Some(generator::Target::Intermediate(format, _)) = self.target {
+            self.target.replace(generator::Target::Intermediate(
+                format,
+                self.intermediate_contents,
+            ));
+        }
         self
     }
 
@@ -59,6 +104,19 @@ impl Compiler {
         self
     }
 
+    /// Appends a directory to search for imported schemas, when they are not
+    /// found relative to the importing schema.
+    pub fn import_path<P: AsRef<path::Path>>(&mut self, path: P) -> &mut Self {
+        self.resolver.import_path(path);
+        self
+    }
+
+    /// Allows identical declarations of a name in different schemas.
+    pub fn allow_identical_redeclarations(&mut self, allow: bool) -> &mut Self {
+        self.parser.allow_identical_redeclarations(allow);
+        self
+    }
+
     #[cfg(feature = "compiler-plugin")]
     pub fn input_intermediate(&mut self, format: ir::Format, data: Vec<u8>) -> &mut Self {
         self.input.replace(Input::Intermediate(format, data));
@@ -78,28 +136,52 @@ impl Compiler {
 
     #[cfg(feature = "compiler-plugin")]
     pub fn output_plugin_process(&mut self, child: process::Child) -> &mut Self {
-        self.output.replace(Output::PluginProcess(child));
+        self.output.replace(Output::PluginProcess(child, None));
+        self
+    }
+
+    /// The plugin outputs a manifest of files, the files are written into the directory.
+    ///
+    /// The stdout of the plugin process should be piped.
+    #[cfg(feature = "compiler-plugin")]
+    pub fn output_plugin_manifest<P: AsRef<path::Path>>(
+        &mut self,
+        child: process::Child,
+        out_dir: P,
+    ) -> &mut Self {
+        let out_dir = Some(out_dir.as_ref().to_path_buf());
+        self.output.replace(Output::PluginProcess(child, out_dir));
         self
     }
 
-    pub fn run(&mut self) -> Result<(), String> {
+    pub fn run(&mut self) -> Result<()> {
         let Self {
             target,
+            #[cfg(feature = "compiler-plugin")]
+                intermediate_contents: _,
             ref input,
             #[cfg(not(feature = "compiler-plugin"))]
             ref output,
             #[cfg(feature = "compiler-plugin")]
             ref mut output,
+            ref resolver,
+            parser: ref schema_parser,
         } = self;
-        let target = target.ok_or("target is not set: generate code or intermediate data")?;
+        let config_error = |reason: &str| Error::Config(reason.to_owned());
+        let target = target
+            .ok_or_else(|| config_error("target is not set: generate code or intermediate data"))?;
         let input = input
             .as_ref()
-            .ok_or("input is not set: schema file or intermediate data")?;
+            .ok_or_else(|| config_error("input is not set: schema file or intermediate data"))?;
 
         #[cfg(not(feature = "compiler-plugin"))]
-        let output = output.as_ref().ok_or("output is not set")?;
+        let output = output
+            .as_ref()
+            .ok_or_else(|| config_error("output is not set"))?;
         #[cfg(feature = "compiler-plugin")]
-        let output = output.as_mut().ok_or("output is not set")?;
+        let output = output
+            .as_mut()
+            .ok_or_else(|| config_error("output is not set"))?;
 
         #[cfg(not(feature = "compiler-plugin"))]
         let file_name;
@@ -113,17 +195,18 @@ impl Compiler {
                     .file_name()
                     .and_then(ffi::OsStr::to_str)
                     .to_owned();
-                parser::Parser::parse(file_path)
+                schema_parser.parse_schema_file(file_path, resolver)?
             }
             #[cfg(feature = "compiler-plugin")]
             Input::Intermediate(format, ref data) => format.recover(data)?,
         };
         let generator = generator::Generator::new(ast);
+        generator.check(target)?;
 
         let mut output_data = Vec::<u8>::new();
         generator
             .generate(target, &mut output_data)
-            .map_err(|err| format!("failed to write data by generator: {}", err))?;
+            .map_err(|err| Error::Io(None, err))?;
 
         match output {
             Output::Directory(ref out_dir) => {
@@ -131,35 +214,78 @@ impl Compiler {
                 let mut out_file = out_dir.to_owned();
                 out_file.push(file_name);
                 out_file.set_extension(target.extension());
-                let mut file_out = fs::OpenOptions::new()
+                fs::OpenOptions::new()
                     .create(true)
                     .write(true)
                     .truncate(true)
                     .open(&out_file)
-                    .unwrap();
-                file_out.write_all(&output_data).unwrap();
-                file_out.flush().unwrap();
+                    .and_then(|mut file_out| {
+                        file_out.write_all(&output_data)?;
+                        file_out.flush()
+                    })
+                    .map_err(|err| Error::Io(Some(out_file), err))?;
             }
             Output::Stdout => {
                 let stdout = io::stdout();
                 let mut stdout_handle = stdout.lock();
-                stdout_handle.write_all(&output_data).unwrap();
-                stdout_handle.flush().unwrap();
+                stdout_handle
+                    .write_all(&output_data)
+                    .and_then(|_| stdout_handle.flush())
+                    .map_err(|err| Error::Io(None, err))?;
             }
             #[cfg(feature = "compiler-plugin")]
-            Output::PluginProcess(ref mut process) => {
-                {
-                    let child_stdin = process.stdin.as_mut().unwrap();
-                    child_stdin.write_all(&output_data).unwrap();
-                    child_stdin.flush().unwrap();
-                }
-                if let Ok(status) = process.wait() {
-                    if !status.success() {
-                        process::exit(1)
+            Output::PluginProcess(ref mut process, ref manifest_dir) => {
+                let mut child_stdin = process
+                    .stdin
+                    .take()
+                    .ok_or_else(|| config_error("the stdin of the plugin is not piped"))?;
+                // The intermediate data is written in another thread, and the manifest is read in
+                // another thread, since the plugin could write to stdout and stderr before it
+                // reads all the data, then neither of them would block the other.
+                let data_writer = thread::spawn(move || {
+                    child_stdin
+                        .write_all(&output_data)
+                        .and_then(|_| child_stdin.flush())
+                });
+                let manifest_reader = process.stdout.take().map(|mut child_stdout| {
+                    thread::spawn(move || {
+                        let mut data = Vec::new();
+                        child_stdout.read_to_end(&mut data).map(|_| data)
+                    })
+                });
+                // The stderr is only captured if the plugin reports diagnostics.
+                let mut has_errors = false;
+                if let Some(child_stderr) = process.stderr.take() {
+                    for line in io::BufReader::new(child_stderr).lines() {
+                        let line = match line {
+                            Ok(line) => line,
+                            Err(_) => break,
+                        };
+                        if let Some(diagnostic) = plugin::Diagnostic::from_json(&line) {
+                            has_errors |= *diagnostic.level() == plugin::Level::Error;
+                            eprintln!("{}", diagnostic);
+                        } else {
+                            eprintln!("{}", line);
+                        }
                     }
-                } else {
-                    eprintln!("Error: failed to execute the plugin");
-                    process::exit(1)
+                }
+                let status = process.wait().map_err(|err| Error::Io(None, err))?;
+                let written = data_writer
+                    .join()
+                    .map_err(|_| Error::Plugin("failed to write the input".to_owned()))?;
+                if !status.success() {
+                    return Err(Error::Plugin(format!("the process exited with {}", status)));
+                } else if has_errors {
+                    return Err(Error::Plugin("it reported errors".to_owned()));
+                }
+                written.map_err(|err| Error::Io(None, err))?;
+                if let Some(ref out_dir) = manifest_dir {
+                    let data = manifest_reader
+                        .ok_or_else(|| config_error("the stdout of the plugin is not piped"))?
+                        .join()
+                        .map_err(|_| Error::Plugin("failed to read the output".to_owned()))?
+                        .map_err(|err| Error::Io(None, err))?;
+                    write_manifest(out_dir, &data)?;
                 }
             }
         }
@@ -167,3 +293,33 @@ impl Compiler {
         Ok(())
     }
 }
+
+// Writes the files in the manifest into the directory, if all paths are inside the directory.
+//
+// `matches!` isn't available in the minimum supported version.
+#[cfg(feature = "compiler-plugin")]
+#[allow(clippy::match_like_matches_macro)]
+fn write_manifest(out_dir: &path::Path, data: &[u8]) -> Result<()> {
+    let manifest = plugin::Manifest::from_json(data).map_err(Error::PluginOutput)?;
+    for file in manifest.files() {
+        let is_inside = !file.path().is_empty()
+            && path::Path::new(file.path())
+                .components()
+                .all(|component| match component {
+                    path::Component::Normal(_) | path::Component::CurDir => true,
+                    _ => false,
+                });
+        if !is_inside {
+            let reason = format!("the path `{}` is out of the output directory", file.path());
+            return Err(Error::PluginOutput(reason));
+        }
+    }
+    for file in manifest.files() {
+        let out_file = out_dir.join(file.path());
+        if let Some(parent) = out_file.parent() {
+            fs::create_dir_all(parent).map_err(|err| Error::Io(Some(parent.to_owned()), err))?;
+        }
+        fs::write(&out_file, file.content()).map_err(|err| Error::Io(Some(out_file), err))?;
+    }
+    Ok(())
+}
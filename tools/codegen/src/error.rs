@@ -0,0 +1,276 @@
+use std::{error, fmt, io, path};
+
+use property::Property;
+
+/// A location in a schema file, with the source line for rendering snippets.
+#[derive(Debug, Clone, Property)]
+#[property(get(public))]
+pub struct Location {
+    path: path::PathBuf,
+    line: usize,
+    column: usize,
+    length: usize,
+    source_line: String,
+}
+
+/// Errors when parsing schemas or running the compiler.
+#[derive(Debug)]
+pub enum Error {
+    /// Failed to access a file (the path, the I/O error).
+    Io(Option<path::PathBuf>, io::Error),
+    /// The schema has syntax errors (the location, the reason).
+    Syntax(Location, String),
+    /// A type is used but never declared (the location, the type name).
+    UnknownType(Location, String),
+    /// An imported schema file can't be resolved (the location, the imported path, the I/O error).
+    UnresolvedImport(Location, path::PathBuf, io::Error),
+    /// Schema files import each other (the location, the import chain).
+    CyclicImport(Location, Vec<path::PathBuf>),
+    /// Declarations depend on each other without an end, for example, structs which contain each
+    /// other, or tables whose default values are infinite (the location, the names of declarations).
+    CyclicDependency(Location, Vec<String>),
+    /// A field or an item is required to be fixed size but it isn't
+    /// (the location, the declaration name, the type name).
+    NotFixedSize(Location, String, String),
+    /// A fixed size declaration has no size (the location, the declaration name).
+    ZeroSize(Location, String),
+    /// A union doesn't have any items (the location, the union name).
+    EmptyUnion(Location, String),
+    /// A name is declared more than once
+    /// (the location, the location of the previous declaration, the name).
+    DuplicateName(Location, Box<Location>, String),
+    /// A name is reserved by a primitive type (the location, the name).
+    ReservedName(Location, String),
+    /// An id is used by more than one item in a union
+    /// (the location, the location of the previous item, the union name, the id).
+    DuplicateItemId(Location, Box<Location>, String, usize),
+    /// An id of a union item can't be packed as a number
+    /// (the location, the union name, the id).
+    ItemIdOutOfRange(Location, String, usize),
+    /// The value of a constant doesn't match its type (the location, the constant name, the reason).
+    InvalidConstant(Location, String, String),
+    /// An enum is declared incorrectly (the location, the enum name, the reason).
+    InvalidEnum(Location, String, String),
+    /// The constraints of a declaration are invalid (the location, the declaration name, the reason).
+    InvalidConstraint(Location, String, String),
+    /// The default value of a field is invalid (the location, the field name as
+    /// `Table.field`, the reason).
+    InvalidDefault(Location, String, String),
+    /// An attribute can't be used by the generator (the location, which is unknown if the AST is
+    /// recovered from the intermediate data, the declaration name, the reason).
+    InvalidAttribute(Option<Location>, String, String),
+    /// Failed to recover the intermediate data (the reason).
+    Intermediate(String),
+    /// The compiler is not configured completely (the reason).
+    Config(String),
+    /// The output of a plugin is invalid (the reason).
+    PluginOutput(String),
+    /// The plugin process failed (the reason).
+    Plugin(String),
+}
+
+pub type Result<T> = ::std::result::Result<T, Error>;
+
+impl Location {
+    pub(crate) fn new<P: AsRef<path::Path>>(path: &P, span: &pest::Span) -> Self {
+        let start = span.start_pos();
+        let (line, column) = start.line_col();
+        let source_line = start
+            .line_of()
+            .trim_end_matches(&['\r', '\n'][..])
+            .to_owned();
+        let length = span
+            .as_str()
+            .lines()
+            .next()
+            .map(|s| s.chars().count())
+            .unwrap_or(0)
+            .max(1);
+        Self {
+            path: path.as_ref().to_path_buf(),
+            line,
+            column,
+            length,
+            source_line,
+        }
+    }
+
+    pub(crate) fn from_line_col<P: AsRef<path::Path>>(
+        path: &P,
+        source: &str,
+        line: usize,
+        column: usize,
+        length: usize,
+    ) -> Self {
+        let source_line = source
+            .lines()
+            .nth(line.saturating_sub(1))
+            .unwrap_or("")
+            .to_owned();
+        Self {
+            path: path.as_ref().to_path_buf(),
+            line,
+            column,
+            length,
+            source_line,
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let line_number = self.line.to_string();
+        let gutter = " ".repeat(line_number.len());
+        let padding = self
+            .source_line
+            .chars()
+            .take(self.column.saturating_sub(1))
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+        writeln!(
+            f,
+            "{}--> {}:{}:{}",
+            gutter,
+            self.path.display(),
+            self.line,
+            self.column
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", line_number, self.source_line)?;
+        write!(f, "{} | {}{}", gutter, padding, "^".repeat(self.length))
+    }
+}
+
+impl Error {
+    /// The location in the schema file where the error occurred.
+    pub fn location(&self) -> Option<&Location> {
+        match self {
+            Self::Syntax(location, _)
+            | Self::UnknownType(location, _)
+            | Self::UnresolvedImport(location, _, _)
+            | Self::CyclicImport(location, _)
+            | Self::CyclicDependency(location, _)
+            | Self::NotFixedSize(location, _, _)
+            | Self::ZeroSize(location, _)
+            | Self::EmptyUnion(location, _)
+            | Self::DuplicateName(location, _, _)
+            | Self::ReservedName(location, _)
+            | Self::DuplicateItemId(location, _, _, _)
+            | Self::ItemIdOutOfRange(location, _, _)
+            | Self::InvalidConstant(location, _, _)
+            | Self::InvalidEnum(location, _, _)
+            | Self::InvalidConstraint(location, _, _)
+            | Self::InvalidDefault(location, _, _) => Some(location),
+            Self::InvalidAttribute(location, _, _) => location.as_ref(),
+            Self::Io(_, _)
+            | Self::Intermediate(_)
+            | Self::Config(_)
+            | Self::PluginOutput(_)
+            | Self::Plugin(_) => None,
+        }
+    }
+
+    /// The message of the error, without the location.
+    pub fn message(&self) -> String {
+        match self {
+            Self::Io(Some(path), err) => format!("failed to access `{}`: {}", path.display(), err),
+            Self::Io(None, err) => format!("I/O failure: {}", err),
+            Self::Syntax(_, reason) => format!("syntax error: {}", reason),
+            Self::UnknownType(_, name) => format!("the type `{}` is not declared", name),
+            Self::UnresolvedImport(_, path, err) => {
+                format!("failed to import `{}`: {}", path.display(), err)
+            }
+            Self::CyclicImport(_, chain) => {
+                let chain = chain
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+                format!("found cyclic import: {}", chain)
+            }
+            Self::CyclicDependency(_, names) => format!(
+                "there are {} types which depend on each other: {}",
+                names.len(),
+                names.join(", ")
+            ),
+            Self::NotFixedSize(_, name, typ) => {
+                format!("the type `{}` in `{}` doesn't have fixed size", typ, name)
+            }
+            Self::ZeroSize(_, name) => format!("the `{}` has no size", name),
+            Self::EmptyUnion(_, name) => format!("the union `{}` is empty", name),
+            Self::DuplicateName(location, previous, name) => {
+                if location.path() == previous.path() {
+                    format!(
+                        "the name `{}` is declared more than once in `{}`",
+                        name,
+                        location.path().display()
+                    )
+                } else {
+                    format!(
+                        "the name `{}` is declared in both `{}` and `{}`",
+                        name,
+                        previous.path().display(),
+                        location.path().display()
+                    )
+                }
+            }
+            Self::ReservedName(_, name) => {
+                format!("the name `{}` is reserved by a primitive type", name)
+            }
+            Self::DuplicateItemId(_, _, name, id) => {
+                format!("the id {} is used by more than one item in `{}`", id, name)
+            }
+            Self::ItemIdOutOfRange(_, name, id) => format!(
+                "the id {} in `{}` is out of range, it should fit in 32 bits",
+                id, name
+            ),
+            Self::InvalidConstant(_, name, reason) => {
+                format!("the value of constant `{}` is invalid: {}", name, reason)
+            }
+            Self::InvalidEnum(_, name, reason) => {
+                format!("the enum `{}` is invalid: {}", name, reason)
+            }
+            Self::InvalidConstraint(_, name, reason) => {
+                format!("the constraint of `{}` is invalid: {}", name, reason)
+            }
+            Self::InvalidDefault(_, name, reason) => {
+                format!("the default value of `{}` is invalid: {}", name, reason)
+            }
+            Self::InvalidAttribute(_, name, reason) => {
+                format!("the attribute of `{}` is invalid: {}", name, reason)
+            }
+            Self::Intermediate(reason) => format!("invalid intermediate data: {}", reason),
+            Self::Config(reason) => reason.to_owned(),
+            Self::PluginOutput(reason) => format!("invalid output of the plugin: {}", reason),
+            Self::Plugin(reason) => format!("the plugin failed: {}", reason),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "error: {}", self.message())?;
+        if let Some(location) = self.location() {
+            write!(f, "\n{}", location)?;
+        }
+        match self {
+            Self::DuplicateName(_, previous, _) => {
+                write!(f, "\nnote: previously declared here\n{}", previous)?;
+            }
+            Self::DuplicateItemId(_, previous, _, _) => {
+                write!(f, "\nnote: previously used here\n{}", previous)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(_, err) | Self::UnresolvedImport(_, _, err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use std::io;
 
-use super::utilities::IdentPrefix;
-use crate::ast::{self as ast, DefaultContent, HasName};
+use super::utilities::{write_bytes_array, write_docs, IdentPrefix};
+use crate::ast::{self as ast, DefaultContent};
 
 pub(super) trait GenBuilder: IdentPrefix + DefaultContent {
     fn gen_builder_interfaces_internal<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
@@ -43,38 +43,11 @@ pub(super) trait GenBuilder: IdentPrefix + DefaultContent {
             self.default_constant(),
             default_content.len()
         );
-        write!(writer, "{:64} =  {{", constant_name)?;
-        if default_content.len() > 4 {
-            for (index, byte) in default_content.into_iter().enumerate() {
-                if index % 12 == 0 {
-                    writeln!(writer)?;
-                    write!(writer, "{:4}", "")?;
-                } else {
-                    write!(writer, " ")?;
-                }
-                if byte == 0 {
-                    write!(writer, "____,")?;
-                } else {
-                    write!(writer, "0x{:02x},", byte)?;
-                }
-            }
-            writeln!(writer)?;
-        } else {
-            let mut not_first = false;
-            for byte in default_content.into_iter() {
-                if not_first {
-                    write!(writer, ", ")?;
-                } else {
-                    not_first = true;
-                }
-                if byte == 0 {
-                    write!(writer, "____")?;
-                } else {
-                    write!(writer, "0x{:02x}", byte)?;
-                }
-            }
-        }
-        writeln!(writer, "}};")?;
+        write_bytes_array(writer, &constant_name, &default_content)?;
+        self.gen_field_defaults(writer)
+    }
+
+    fn gen_field_defaults<W: io::Write>(&self, _writer: &mut W) -> io::Result<()> {
         Ok(())
     }
 }
@@ -103,8 +76,8 @@ impl GenBuilder for ast::Option_ {
 impl GenBuilder for ast::Union {
     fn gen_builder_interfaces_internal<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
         {
-            let id = 0;
-            let default = &self.items()[id].typ();
+            let id = self.items()[0].id();
+            let default = &self.items()[0].typ();
             let len = default.default_content().len();
             let name = if default.is_byte() {
                 "NULL".to_owned()
@@ -119,15 +92,17 @@ impl GenBuilder for ast::Union {
             );
             self.define_builder_macro(writer, "_init(b)", &macro_content)?;
         }
-        for (item_id, item) in self.items().iter().enumerate() {
+        for item in self.items() {
+            write_docs(writer, item.docs(), item.attributes())?;
+            let item_id = item.id();
             let (macro_sig_tail, macro_content) = if item.typ().is_byte() {
                 (
-                    format!("_set_{}(b, p)", item.typ().name()),
+                    format!("_set_{}(b, p)", item.variant_name()),
                     format!("mol_union_builder_set_byte(b, {}, p)", item_id),
                 )
             } else {
                 (
-                    format!("_set_{}(b, p, l)", item.typ().name()),
+                    format!("_set_{}(b, p, l)", item.variant_name()),
                     format!("mol_union_builder_set(b, {}, p, l)", item_id),
                 )
             };
@@ -238,6 +213,23 @@ impl GenBuilder for ast::DynVec {
 }
 
 impl GenBuilder for ast::Table {
+    // The default values of the fields which could be missing in an older encoding.
+    fn gen_field_defaults<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        for field in self.fields() {
+            if let Some(default) = field.default() {
+                let constant_name = format!(
+                    "{} const uint8_t {}_{}[{}]",
+                    self.api_decorator(),
+                    self.default_constant(),
+                    field.name(),
+                    default.len()
+                );
+                write_bytes_array(writer, &constant_name, default)?;
+            }
+        }
+        Ok(())
+    }
+
     fn gen_builder_interfaces_internal<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
         {
             let data_capacity = calculate_capacity(self.default_content().len() * 4);
@@ -330,6 +322,25 @@ impl GenBuilder for ast::Table {
     }
 }
 
+impl GenBuilder for ast::Enum {
+    fn gen_builder_interfaces_internal<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        {
+            let macro_content = format!(
+                "mol_builder_initialize_fixed_size(b, {})",
+                self.total_size()
+            );
+            self.define_builder_macro(writer, "_init(b)", &macro_content)?;
+        }
+        {
+            let macro_content =
+                format!("mol_builder_set_by_offset(b, 0, p, {})", self.total_size());
+            self.define_builder_macro(writer, "_set(b, p)", &macro_content)?;
+        }
+        self.gen_builder_interface_build(writer, Some("mol_builder_finalize_simple"))?;
+        Ok(())
+    }
+}
+
 impl GenBuilder for ast::TopDecl {
     fn gen_builder_interfaces_internal<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
         match self {
@@ -340,6 +351,7 @@ impl GenBuilder for ast::TopDecl {
             ast::TopDecl::FixVec(ref i) => i.gen_builder_interfaces_internal(writer),
             ast::TopDecl::DynVec(ref i) => i.gen_builder_interfaces_internal(writer),
             ast::TopDecl::Table(ref i) => i.gen_builder_interfaces_internal(writer),
+            ast::TopDecl::Enum(ref i) => i.gen_builder_interfaces_internal(writer),
             ast::TopDecl::Primitive(_) => unreachable!(),
         }
     }
@@ -353,7 +365,15 @@ impl GenBuilder for ast::TopDecl {
             ast::TopDecl::FixVec(ref i) => i.gen_builder_function_build(writer),
             ast::TopDecl::DynVec(ref i) => i.gen_builder_function_build(writer),
             ast::TopDecl::Table(ref i) => i.gen_builder_function_build(writer),
+            ast::TopDecl::Enum(ref i) => i.gen_builder_function_build(writer),
             ast::TopDecl::Primitive(_) => unreachable!(),
         }
     }
+
+    fn gen_field_defaults<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            ast::TopDecl::Table(ref i) => i.gen_field_defaults(writer),
+            _ => Ok(()),
+        }
+    }
 }
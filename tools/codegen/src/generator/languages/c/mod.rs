@@ -91,6 +91,7 @@ impl super::LanguageGenerator for Generator {
         writeln!(writer)?;
         Self::title(writer, "Reader APIs")?;
         for decl in ast.major_decls() {
+            utilities::write_docs(writer, decl.docs(), decl.attributes())?;
             decl.gen_reader_interfaces(writer)?;
         }
         writeln!(writer)?;
@@ -105,6 +106,20 @@ impl super::LanguageGenerator for Generator {
         for decl in ast.major_decls() {
             decl.gen_default(writer)?;
         }
+        let consts = ast.major_consts();
+        if !consts.is_empty() {
+            writeln!(writer)?;
+            Self::title(writer, "Constants")?;
+            for constant in consts {
+                utilities::write_docs(writer, constant.docs(), constant.attributes())?;
+                let definition = format!(
+                    "static const uint8_t MolConst_{}[{}]",
+                    utilities::ident_name(constant.name(), constant.attributes()),
+                    constant.value().len()
+                );
+                utilities::write_bytes_array(writer, &definition, constant.value())?;
+            }
+        }
         writeln!(writer)?;
         writeln!(writer, r#"#undef ____"#)?;
         writeln!(writer)?;
@@ -1,6 +1,6 @@
 use std::io;
 
-use super::utilities::IdentPrefix;
+use super::utilities::{native_type, unpack_function, write_docs, IdentPrefix};
 use crate::ast;
 
 pub(super) trait GenReader: IdentPrefix {
@@ -13,12 +13,17 @@ pub(super) trait GenReader: IdentPrefix {
 
     fn gen_reader_functions<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
         self.gen_reader_function_verify(writer)?;
+        self.gen_reader_function_unpack(writer)?;
         Ok(())
     }
 
     fn gen_reader_function_verify<W: io::Write>(&self, _writer: &mut W) -> io::Result<()> {
         Ok(())
     }
+
+    fn gen_reader_function_unpack<W: io::Write>(&self, _writer: &mut W) -> io::Result<()> {
+        Ok(())
+    }
 }
 
 impl GenReader for ast::Option_ {
@@ -31,6 +36,14 @@ impl GenReader for ast::Option_ {
                 "mol_errno",
             )?;
         }
+        if self.is_recursive() {
+            self.define_reader_function(
+                writer,
+                "_verify_nested",
+                "(const mol_seg_t*, bool, mol_num_t)",
+                "mol_errno",
+            )?;
+        }
         {
             self.define_reader_macro(writer, "_is_none(s)", "mol_option_is_none(s)")?;
         }
@@ -38,21 +51,16 @@ impl GenReader for ast::Option_ {
     }
 
     fn gen_reader_function_verify<W: io::Write>(&self, o: &mut W) -> io::Result<()> {
-        let func_name = format!("{}_verify", self.reader_prefix());
+        let is_recursive = self.is_recursive();
         let api_decorator = self.api_decorator();
-        w!(
-            o,
-            "{} mol_errno {} (const mol_seg_t *input, bool compatible) {{",
-            api_decorator,
-            func_name
-        );
+        write_verify_header(o, &self.reader_prefix(), api_decorator, is_recursive)?;
         if self.item().typ().is_byte() {
             w!(o, "    if (input->size > 1) {{                            ");
             w!(o, "        return MOL_ERR;                                ");
         } else {
-            let f = format!("{}_verify", self.item().typ().reader_prefix());
+            let f = verify_inner(is_recursive, &self.item().typ(), "input");
             w!(o, "    if (input->size != 0) {{                           ");
-            w!(o, "        return {}(input, compatible);               ", f);
+            w!(o, "        return {};                                  ", f);
         }
         w!(o, "    }} else {{                                         ");
         w!(o, "        return MOL_OK;                                 ");
@@ -72,6 +80,14 @@ impl GenReader for ast::Union {
                 "mol_errno",
             )?;
         }
+        if self.is_recursive() {
+            self.define_reader_function(
+                writer,
+                "_verify_nested",
+                "(const mol_seg_t*, bool, mol_num_t)",
+                "mol_errno",
+            )?;
+        }
         {
             self.define_reader_macro(writer, "_unpack(s)", "mol_union_unpack(s)")?;
         }
@@ -79,14 +95,9 @@ impl GenReader for ast::Union {
     }
 
     fn gen_reader_function_verify<W: io::Write>(&self, o: &mut W) -> io::Result<()> {
-        let func_name = format!("{}_verify", self.reader_prefix());
+        let is_recursive = self.is_recursive();
         let api_decorator = self.api_decorator();
-        w!(
-            o,
-            "{} mol_errno {} (const mol_seg_t *input, bool compatible) {{",
-            api_decorator,
-            func_name
-        );
+        write_verify_header(o, &self.reader_prefix(), api_decorator, is_recursive)?;
         w!(o, "    if (input->size < MOL_NUM_T_SIZE) {{               ");
         w!(o, "        return MOL_ERR_HEADER;                         ");
         w!(o, "    }}                                                 ");
@@ -95,13 +106,17 @@ impl GenReader for ast::Union {
         w!(o, "    inner.ptr = input->ptr + MOL_NUM_T_SIZE;           ");
         w!(o, "    inner.size = input->size - MOL_NUM_T_SIZE;         ");
         w!(o, "    switch(item_id) {{                                 ");
-        for (item_id, item) in self.items().iter().enumerate() {
-            w!(o, "        case {}:                              ", item_id);
+        for item in self.items() {
+            w!(
+                o,
+                "        case {}:                              ",
+                item.id()
+            );
             if item.typ().is_byte() {
                 w!(o, "            return inner.size == 1 ? MOL_OK : MOL_ERR; ");
             } else {
-                let f = format!("{}_verify", item.typ().reader_prefix());
-                w!(o, "            return {}(&inner, compatible);          ", f);
+                let f = verify_inner(is_recursive, &item.typ(), "&inner");
+                w!(o, "            return {};                              ", f);
             }
         }
         w!(o, "        default:                                       ");
@@ -114,7 +129,14 @@ impl GenReader for ast::Union {
 
 impl GenReader for ast::Array {
     fn gen_reader_interfaces_internal<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
-        {
+        if self.has_restricted_values() {
+            self.define_reader_function(
+                writer,
+                "_verify",
+                "(const mol_seg_t*, bool)",
+                "mol_errno",
+            )?;
+        } else {
             let macro_content = format!("mol_verify_fixed_size(s, {})", self.total_size());
             self.define_reader_macro(writer, "_verify(s, c)", &macro_content)?;
         }
@@ -128,18 +150,109 @@ impl GenReader for ast::Array {
             );
             self.define_reader_macro(writer, &macro_sig_tail, &macro_content)?;
         }
+        if let Some(builtin) = self.builtin() {
+            let macro_content = format!("{}((s)->ptr)", unpack_function(builtin));
+            self.define_reader_macro(writer, "_unpack(s)", &macro_content)?;
+        }
+        Ok(())
+    }
+
+    fn gen_reader_function_verify<W: io::Write>(&self, o: &mut W) -> io::Result<()> {
+        if !self.has_restricted_values() {
+            return Ok(());
+        }
+        let func_name = format!("{}_verify", self.reader_prefix());
+        let api_decorator = self.api_decorator();
+        w!(
+            o,
+            "{} mol_errno {} (const mol_seg_t *input, bool compatible) {{",
+            api_decorator,
+            func_name
+        );
+        w!(
+            o,
+            "    if (input->size != {}) {{                         ",
+            self.total_size()
+        );
+        w!(o, "        return MOL_ERR_TOTAL_SIZE;                     ");
+        w!(o, "    }}                                                 ");
+        if let Some(ast::BuiltIn::Bool) = self.builtin() {
+            w!(o, "    if (input->ptr[0] > 1) {{                          ");
+            w!(o, "        return MOL_ERR_DATA;                           ");
+            w!(o, "    }}                                                 ");
+        } else if self.item().typ().has_restricted_values() {
+            let f = format!("{}_verify", self.item().typ().reader_prefix());
+            w!(o, "    mol_seg_t inner;                                   ");
+            w!(
+                o,
+                "    inner.size = {};                                   ",
+                self.item_size()
+            );
+            w!(
+                o,
+                "    for (mol_num_t i = 0; i < {}; i++) {{            ",
+                self.item_count()
+            );
+            w!(
+                o,
+                "        inner.ptr = input->ptr + {} * i;               ",
+                self.item_size()
+            );
+            w!(o, "        if ({}(&inner, compatible) != MOL_OK) {{   ", f);
+            w!(o, "            return MOL_ERR_DATA;                       ");
+            w!(o, "        }}                                             ");
+            w!(o, "    }}                                                 ");
+        }
+        write_range_verify(o, self.constraints(), self.total_size())?;
+        w!(o, "    return MOL_OK;                                     ");
+        w!(o, "}}                                                     ");
         Ok(())
     }
+
+    fn gen_reader_function_unpack<W: io::Write>(&self, o: &mut W) -> io::Result<()> {
+        let builtin = if let Some(builtin) = self.builtin() {
+            builtin
+        } else {
+            return Ok(());
+        };
+        let func_name = unpack_function(builtin);
+        let guard = func_name.to_uppercase();
+        let native = native_type(builtin);
+        if let ast::BuiltIn::Bool = builtin {
+            w!(o, "#ifndef {}                                      ", guard);
+            w!(o, "#define {}                                      ", guard);
+            w!(
+                o,
+                "{} {} {} (const uint8_t *src) {{",
+                self.api_decorator(),
+                native,
+                func_name
+            );
+            w!(o, "    return src[0] != 0;                                ");
+            w!(o, "}}                                                     ");
+            w!(o, "#endif /* {} */                                 ", guard);
+            return Ok(());
+        }
+        write_unpack_integer_function(o, self.api_decorator(), builtin)
+    }
 }
 
 impl GenReader for ast::Struct {
     fn gen_reader_interfaces_internal<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
-        {
+        if self.has_restricted_values() {
+            self.define_reader_function(
+                writer,
+                "_verify",
+                "(const mol_seg_t*, bool)",
+                "mol_errno",
+            )?;
+        } else {
             let macro_content = format!("mol_verify_fixed_size(s, {})", self.total_size());
             self.define_reader_macro(writer, "_verify(s, c)", &macro_content)?;
         }
         let mut field_offset = 0;
         for (f, field_size) in self.fields().iter().zip(self.field_sizes().iter()) {
+            write_docs(writer, f.docs(), f.attributes())?;
             let macro_sig_tail = format!("_get_{}(s)", f.name());
             let macro_content = format!("mol_slice_by_offset(s, {}, {})", field_offset, field_size);
             self.define_reader_macro(writer, &macro_sig_tail, &macro_content)?;
@@ -147,11 +260,67 @@ impl GenReader for ast::Struct {
         }
         Ok(())
     }
+
+    fn gen_reader_function_verify<W: io::Write>(&self, o: &mut W) -> io::Result<()> {
+        if !self.has_restricted_values() {
+            return Ok(());
+        }
+        let func_name = format!("{}_verify", self.reader_prefix());
+        let api_decorator = self.api_decorator();
+        w!(
+            o,
+            "{} mol_errno {} (const mol_seg_t *input, bool compatible) {{",
+            api_decorator,
+            func_name
+        );
+        w!(
+            o,
+            "    if (input->size != {}) {{                         ",
+            self.total_size()
+        );
+        w!(o, "        return MOL_ERR_TOTAL_SIZE;                     ");
+        w!(o, "    }}                                                 ");
+        w!(o, "    mol_seg_t inner;                                   ");
+        let mut field_offset = 0;
+        for (f, field_size) in self.fields().iter().zip(self.field_sizes().iter()) {
+            if f.typ().has_restricted_values() {
+                let func = format!("{}_verify", f.typ().reader_prefix());
+                w!(
+                    o,
+                    "    inner.ptr = input->ptr + {};                    ",
+                    field_offset
+                );
+                w!(
+                    o,
+                    "    inner.size = {};                                ",
+                    field_size
+                );
+                w!(
+                    o,
+                    "    if ({}(&inner, compatible) != MOL_OK) {{       ",
+                    func
+                );
+                w!(o, "        return MOL_ERR_DATA;                           ");
+                w!(o, "    }}                                                 ");
+            }
+            field_offset += field_size;
+        }
+        w!(o, "    return MOL_OK;                                     ");
+        w!(o, "}}                                                     ");
+        Ok(())
+    }
 }
 
 impl GenReader for ast::FixVec {
     fn gen_reader_interfaces_internal<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
-        {
+        if self.has_restricted_values() || self.is_map() || self.constraints().has_items_limits() {
+            self.define_reader_function(
+                writer,
+                "_verify",
+                "(const mol_seg_t*, bool)",
+                "mol_errno",
+            )?;
+        } else {
             let macro_content = format!("mol_fixvec_verify(s, {})", self.item_size());
             self.define_reader_macro(writer, "_verify(s, c)", &macro_content)?;
         }
@@ -167,6 +336,89 @@ impl GenReader for ast::FixVec {
         }
         Ok(())
     }
+
+    fn gen_reader_function_verify<W: io::Write>(&self, o: &mut W) -> io::Result<()> {
+        if !(self.has_restricted_values() || self.is_map() || self.constraints().has_items_limits())
+        {
+            return Ok(());
+        }
+        let func_name = format!("{}_verify", self.reader_prefix());
+        let api_decorator = self.api_decorator();
+        if let Some(ast::BuiltIn::String) = self.builtin() {
+            write_utf8_verify_function(o, api_decorator)?;
+            w!(
+                o,
+                "{} mol_errno {} (const mol_seg_t *input, bool compatible) {{",
+                api_decorator,
+                func_name
+            );
+            w!(o, "    mol_errno errno = mol_fixvec_verify(input, 1);    ");
+            w!(o, "    if (errno != MOL_OK) {{                            ");
+            w!(o, "        return errno;                                  ");
+            w!(o, "    }}                                                 ");
+            if self.constraints().has_items_limits() {
+                w!(
+                    o,
+                    "    mol_num_t item_count = mol_unpack_number(input->ptr); "
+                );
+                write_items_count_verify(o, self.constraints())?;
+            }
+            w!(
+                o,
+                "    mol_seg_t raw_bytes = mol_fixvec_slice_raw_bytes(input); "
+            );
+            w!(o, "    return mol_utf8_verify(&raw_bytes);                ");
+            w!(o, "}}                                                     ");
+            return Ok(());
+        }
+        let f = format!("{}_verify", self.item().typ().reader_prefix());
+        w!(
+            o,
+            "{} mol_errno {} (const mol_seg_t *input, bool compatible) {{",
+            api_decorator,
+            func_name
+        );
+        w!(
+            o,
+            "    mol_errno errno = mol_fixvec_verify(input, {});  ",
+            self.item_size()
+        );
+        w!(o, "    if (errno != MOL_OK) {{                            ");
+        w!(o, "        return errno;                                  ");
+        w!(o, "    }}                                                 ");
+        if self.has_restricted_values() || self.constraints().has_items_limits() {
+            w!(
+                o,
+                "    mol_num_t item_count = mol_unpack_number(input->ptr); "
+            );
+            write_items_count_verify(o, self.constraints())?;
+        }
+        if self.has_restricted_values() {
+            w!(o, "    mol_seg_t inner;                                   ");
+            w!(
+                o,
+                "    inner.size = {};                                   ",
+                self.item_size()
+            );
+            w!(o, "    for (mol_num_t i = 0; i < item_count; i++) {{      ");
+            w!(
+                o,
+                "        inner.ptr = input->ptr + MOL_NUM_T_SIZE + {} * i; ",
+                self.item_size()
+            );
+            w!(o, "        if ({}(&inner, compatible) != MOL_OK) {{   ", f);
+            w!(o, "            return MOL_ERR_DATA;                       ");
+            w!(o, "        }}                                             ");
+            w!(o, "    }}                                                 ");
+        }
+        if self.is_map() {
+            let entry_prefix = self.item().typ().reader_prefix();
+            write_map_keys_verify(o, &self.reader_prefix(), &entry_prefix)?;
+        }
+        w!(o, "    return MOL_OK;                                     ");
+        w!(o, "}}                                                     ");
+        Ok(())
+    }
 }
 
 impl GenReader for ast::DynVec {
@@ -179,6 +431,14 @@ impl GenReader for ast::DynVec {
                 "mol_errno",
             )?;
         }
+        if self.is_recursive() {
+            self.define_reader_function(
+                writer,
+                "_verify_nested",
+                "(const mol_seg_t*, bool, mol_num_t)",
+                "mol_errno",
+            )?;
+        }
         {
             self.define_reader_macro(writer, "_length(s)", "mol_dynvec_length(s)")?;
         }
@@ -189,15 +449,10 @@ impl GenReader for ast::DynVec {
     }
 
     fn gen_reader_function_verify<W: io::Write>(&self, o: &mut W) -> io::Result<()> {
-        let func_name = format!("{}_verify", self.reader_prefix());
+        let is_recursive = self.is_recursive();
         let api_decorator = self.api_decorator();
-        let f = format!("{}_verify", self.item().typ().reader_prefix());
-        w!(
-            o,
-            "{} mol_errno {} (const mol_seg_t *input, bool compatible) {{",
-            api_decorator,
-            func_name
-        );
+        let f = verify_inner(is_recursive, &self.item().typ(), "&inner");
+        write_verify_header(o, &self.reader_prefix(), api_decorator, is_recursive)?;
         w!(o, "    if (input->size < MOL_NUM_T_SIZE) {{               ");
         w!(o, "        return MOL_ERR_HEADER;                         ");
         w!(o, "    }}                                                 ");
@@ -207,7 +462,11 @@ impl GenReader for ast::DynVec {
         w!(o, "        return MOL_ERR_TOTAL_SIZE;                     ");
         w!(o, "    }}                                                 ");
         w!(o, "    if (input->size == MOL_NUM_T_SIZE) {{              ");
-        w!(o, "        return MOL_OK;                                 ");
+        if self.constraints().non_empty() {
+            w!(o, "        return MOL_ERR_CONSTRAINT;                     ");
+        } else {
+            w!(o, "        return MOL_OK;                                 ");
+        }
         w!(o, "    }}                                                 ");
         w!(o, "    if (input->size < MOL_NUM_T_SIZE * 2) {{           ");
         w!(o, "        return MOL_ERR_HEADER;                         ");
@@ -218,6 +477,7 @@ impl GenReader for ast::DynVec {
         w!(o, "        return MOL_ERR_OFFSET;                         ");
         w!(o, "    }}                                                 ");
         w!(o, "    mol_num_t item_count = offset / 4 - 1;             ");
+        write_max_items_verify(o, self.constraints())?;
         w!(o, "    if (input->size < MOL_NUM_T_SIZE*(item_count+1)) {{");
         w!(o, "        return MOL_ERR_HEADER;                         ");
         w!(o, "    }}                                                 ");
@@ -231,7 +491,7 @@ impl GenReader for ast::DynVec {
         w!(o, "        mol_seg_t inner;                               ");
         w!(o, "        inner.ptr = input->ptr + offset;               ");
         w!(o, "        inner.size = end - offset;                     ");
-        w!(o, "        mol_errno errno = {}(&inner, compatible);   ", f);
+        w!(o, "        mol_errno errno = {};                       ", f);
         w!(o, "        if (errno != MOL_OK) {{                        ");
         w!(o, "            return MOL_ERR_DATA;                       ");
         w!(o, "        }}                                             ");
@@ -243,7 +503,17 @@ impl GenReader for ast::DynVec {
         w!(o, "    mol_seg_t inner;                                   ");
         w!(o, "    inner.ptr = input->ptr + offset;                   ");
         w!(o, "    inner.size = total_size - offset;                  ");
-        w!(o, "    return {}(&inner, compatible);                  ", f);
+        if self.is_map() {
+            w!(o, "    mol_errno errno = {};                           ", f);
+            w!(o, "    if (errno != MOL_OK) {{                            ");
+            w!(o, "        return MOL_ERR_DATA;                           ");
+            w!(o, "    }}                                                 ");
+            let entry_prefix = self.item().typ().reader_prefix();
+            write_map_keys_verify(o, &self.reader_prefix(), &entry_prefix)?;
+            w!(o, "    return MOL_OK;                                     ");
+        } else {
+            w!(o, "    return {};                                      ", f);
+        }
         w!(o, "}}                                                     ");
         Ok(())
     }
@@ -259,6 +529,14 @@ impl GenReader for ast::Table {
                 "mol_errno",
             )?;
         }
+        if self.is_recursive() {
+            self.define_reader_function(
+                writer,
+                "_verify_nested",
+                "(const mol_seg_t*, bool, mol_num_t)",
+                "mol_errno",
+            )?;
+        }
         {
             self.define_reader_macro(
                 writer,
@@ -271,23 +549,29 @@ impl GenReader for ast::Table {
             self.define_reader_macro(writer, "_has_extra_fields(s)", &macro_content)?;
         }
         for (i, f) in self.fields().iter().enumerate() {
+            write_docs(writer, f.docs(), f.attributes())?;
             let macro_sig_tail = format!("_get_{}(s)", f.name());
-            let macro_content = format!("mol_table_slice_by_index(s, {})", i);
+            let macro_content = if let Some(default) = f.default() {
+                format!(
+                    "mol_table_slice_by_index_or_default(s, {}, {}_{}, {})",
+                    i,
+                    self.default_constant(),
+                    f.name(),
+                    default.len()
+                )
+            } else {
+                format!("mol_table_slice_by_index(s, {})", i)
+            };
             self.define_reader_macro(writer, &macro_sig_tail, &macro_content)?;
         }
         Ok(())
     }
 
     fn gen_reader_function_verify<W: io::Write>(&self, o: &mut W) -> io::Result<()> {
-        let func_name = format!("{}_verify", self.reader_prefix());
+        let is_recursive = self.is_recursive();
         let api_decorator = self.api_decorator();
         let fc = self.fields().len();
-        w!(
-            o,
-            "{} mol_errno {} (const mol_seg_t *input, bool compatible) {{",
-            api_decorator,
-            func_name
-        );
+        write_verify_header(o, &self.reader_prefix(), api_decorator, is_recursive)?;
         w!(o, "    if (input->size < MOL_NUM_T_SIZE) {{               ");
         w!(o, "        return MOL_ERR_HEADER;                         ");
         w!(o, "    }}                                                 ");
@@ -300,6 +584,10 @@ impl GenReader for ast::Table {
             w!(o, "    if (input->size == MOL_NUM_T_SIZE) {{              ");
             w!(o, "        return MOL_OK;                                 ");
             w!(o, "    }}                                                 ");
+        } else if self.min_field_count() == 0 {
+            w!(o, "    if (input->size == MOL_NUM_T_SIZE && compatible) {{");
+            w!(o, "        return MOL_OK;                                 ");
+            w!(o, "    }}                                                 ");
         }
         w!(o, "    if (input->size < MOL_NUM_T_SIZE * 2) {{           ");
         w!(o, "        return MOL_ERR_HEADER;                         ");
@@ -310,7 +598,17 @@ impl GenReader for ast::Table {
         w!(o, "        return MOL_ERR_OFFSET;                         ");
         w!(o, "    }}                                                 ");
         w!(o, "    mol_num_t field_count = offset / 4 - 1;            ");
-        w!(o, "    if (field_count < {}) {{                       ", fc);
+        let min_fc = self.min_field_count();
+        if min_fc < fc {
+            w!(
+                o,
+                "    if (field_count < (compatible ? {} : {})) {{   ",
+                min_fc,
+                fc
+            );
+        } else {
+            w!(o, "    if (field_count < {}) {{                       ", fc);
+        }
         w!(o, "        return MOL_ERR_FIELD_COUNT;                    ");
         w!(o, "    }} else if (!compatible && field_count > {}) {{", fc);
         w!(o, "        return MOL_ERR_FIELD_COUNT;                    ");
@@ -338,19 +636,27 @@ impl GenReader for ast::Table {
             }
             for (i, field) in self.fields().iter().enumerate() {
                 let j = i + 1;
+                // the field could be missing in an older encoding
+                let optional = field.default().is_some();
+                if optional {
+                    w!(o, "    if (field_count > {}) {{                       ", i);
+                }
                 if field.typ().is_byte() {
                     w!(o, "        if (offsets[{}] - offsets[{}] != 1) {{   ", j, i);
                     w!(o, "            return MOL_ERR_DATA;                       ");
                     w!(o, "        }}                                             ");
                 } else {
-                    let f = format!("{}_verify", field.typ().reader_prefix());
+                    let f = verify_inner(is_recursive, &field.typ(), "&inner");
                     w!(o, "        inner.ptr = input->ptr + offsets[{}];       ", i);
                     w!(o, "        inner.size = offsets[{}] - offsets[{}];  ", j, i);
-                    w!(o, "        errno = {}(&inner, compatible);             ", f);
+                    w!(o, "        errno = {};                                 ", f);
                     w!(o, "        if (errno != MOL_OK) {{                        ");
                     w!(o, "            return MOL_ERR_DATA;                       ");
                     w!(o, "        }}                                             ");
                 }
+                if optional {
+                    w!(o, "    }}                                                 ");
+                }
             }
         }
         w!(o, "    return MOL_OK;                                     ");
@@ -359,6 +665,66 @@ impl GenReader for ast::Table {
     }
 }
 
+impl GenReader for ast::Enum {
+    fn gen_reader_interfaces_internal<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        for item in self.items() {
+            write_docs(writer, item.docs(), item.attributes())?;
+            let name = format!("MolEnum_{}_{}", self.ident_name(), item.name());
+            let value = unsigned_literal(item.value());
+            writeln!(writer, "{:39} {:47} {}", "#define", name, value)?;
+        }
+        {
+            self.define_reader_function(
+                writer,
+                "_verify",
+                "(const mol_seg_t*, bool)",
+                "mol_errno",
+            )?;
+        }
+        {
+            let unpack = unpack_function(enum_builtin(self));
+            let macro_content = format!("{}((s)->ptr)", unpack);
+            self.define_reader_macro(writer, "_unpack(s)", &macro_content)?;
+        }
+        Ok(())
+    }
+
+    fn gen_reader_function_verify<W: io::Write>(&self, o: &mut W) -> io::Result<()> {
+        let func_name = format!("{}_verify", self.reader_prefix());
+        let api_decorator = self.api_decorator();
+        let builtin = enum_builtin(self);
+        write_unpack_integer_function(o, api_decorator, builtin)?;
+        w!(
+            o,
+            "{} mol_errno {} (const mol_seg_t *input, bool compatible) {{",
+            api_decorator,
+            func_name
+        );
+        w!(
+            o,
+            "    if (input->size != {}) {{                         ",
+            self.total_size()
+        );
+        w!(o, "        return MOL_ERR_TOTAL_SIZE;                     ");
+        w!(o, "    }}                                                 ");
+        w!(
+            o,
+            "    switch ({}(input->ptr)) {{                        ",
+            unpack_function(builtin)
+        );
+        for item in self.items() {
+            let name = format!("MolEnum_{}_{}", self.ident_name(), item.name());
+            w!(o, "        case {}:                              ", name);
+        }
+        w!(o, "            return MOL_OK;                             ");
+        w!(o, "        default:                                       ");
+        w!(o, "            return MOL_ERR_DATA;                       ");
+        w!(o, "    }}                                                 ");
+        w!(o, "}}                                                     ");
+        Ok(())
+    }
+}
+
 impl GenReader for ast::TopDecl {
     fn gen_reader_interfaces_internal<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
         match self {
@@ -369,6 +735,7 @@ impl GenReader for ast::TopDecl {
             ast::TopDecl::FixVec(ref i) => i.gen_reader_interfaces_internal(writer),
             ast::TopDecl::DynVec(ref i) => i.gen_reader_interfaces_internal(writer),
             ast::TopDecl::Table(ref i) => i.gen_reader_interfaces_internal(writer),
+            ast::TopDecl::Enum(ref i) => i.gen_reader_interfaces_internal(writer),
             ast::TopDecl::Primitive(_) => unreachable!(),
         }
     }
@@ -382,7 +749,295 @@ impl GenReader for ast::TopDecl {
             ast::TopDecl::FixVec(ref i) => i.gen_reader_function_verify(writer),
             ast::TopDecl::DynVec(ref i) => i.gen_reader_function_verify(writer),
             ast::TopDecl::Table(ref i) => i.gen_reader_function_verify(writer),
+            ast::TopDecl::Enum(ref i) => i.gen_reader_function_verify(writer),
             ast::TopDecl::Primitive(_) => unreachable!(),
         }
     }
+
+    fn gen_reader_function_unpack<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            ast::TopDecl::Array(ref i) => i.gen_reader_function_unpack(writer),
+            _ => Ok(()),
+        }
+    }
+}
+
+// The unsigned integer which has the same size as an enum.
+fn enum_builtin(decl: &ast::Enum) -> ast::BuiltIn {
+    ast::BuiltIn::Integer {
+        signed: false,
+        size: decl.total_size(),
+    }
+}
+
+fn write_unpack_integer_function<W: io::Write>(
+    o: &mut W,
+    api_decorator: &str,
+    builtin: ast::BuiltIn,
+) -> io::Result<()> {
+    let size = builtin.size().expect("an integer has a fixed size");
+    let func_name = unpack_function(builtin);
+    let guard = func_name.to_uppercase();
+    let native = native_type(builtin);
+    let is_128_bits = size == 16;
+    // the helpers are shared by all headers, and 128-bit integers are an extension of C
+    if is_128_bits {
+        w!(o, "#ifdef __SIZEOF_INT128__                              ");
+    }
+    w!(o, "#ifndef {}                                      ", guard);
+    w!(o, "#define {}                                      ", guard);
+    w!(
+        o,
+        "{} {} {} (const uint8_t *src) {{",
+        api_decorator,
+        native,
+        func_name
+    );
+    let unsigned = native_type(ast::BuiltIn::Integer {
+        signed: false,
+        size,
+    });
+    w!(
+        o,
+        "    {} output = 0;                                 ",
+        unsigned
+    );
+    w!(o, "    for (int i = {}; i >= 0; i--) {{         ", size - 1);
+    w!(o, "        output = (output << 8) | src[i];               ");
+    w!(o, "    }}                                                 ");
+    w!(
+        o,
+        "    return ({}) output;                            ",
+        native
+    );
+    w!(o, "}}                                                     ");
+    w!(o, "#endif /* {} */                                 ", guard);
+    if is_128_bits {
+        w!(o, "#endif /* __SIZEOF_INT128__ */                        ");
+    }
+    Ok(())
+}
+
+// The helper is shared by all headers, it returns `MOL_ERR_DATA` if the bytes aren't valid UTF-8.
+fn write_utf8_verify_function<W: io::Write>(o: &mut W, api_decorator: &str) -> io::Result<()> {
+    w!(o, "#ifndef MOL_UTF8_VERIFY                                ");
+    w!(o, "#define MOL_UTF8_VERIFY                                ");
+    w!(
+        o,
+        "{} mol_errno mol_utf8_verify (const mol_seg_t *input) {{",
+        api_decorator
+    );
+    w!(o, "    const uint8_t *ptr = input->ptr;                   ");
+    w!(o, "    mol_num_t size = input->size;                      ");
+    w!(o, "    mol_num_t i = 0;                                   ");
+    w!(o, "    while (i < size) {{                                ");
+    w!(o, "        uint8_t c = ptr[i];                            ");
+    w!(o, "        mol_num_t n;                                   ");
+    w!(o, "        uint8_t lower = 0x80, upper = 0xbf;            ");
+    w!(o, "        if (c < 0x80) {{                               ");
+    w!(o, "            i++;                                       ");
+    w!(o, "            continue;                                  ");
+    w!(o, "        }} else if (c >= 0xc2 && c <= 0xdf) {{         ");
+    w!(o, "            n = 1;                                     ");
+    w!(o, "        }} else if (c >= 0xe0 && c <= 0xef) {{         ");
+    w!(o, "            n = 2;                                     ");
+    w!(o, "            if (c == 0xe0) {{                          ");
+    w!(o, "                lower = 0xa0;                          ");
+    w!(o, "            }} else if (c == 0xed) {{                  ");
+    w!(o, "                upper = 0x9f;                          ");
+    w!(o, "            }}                                         ");
+    w!(o, "        }} else if (c >= 0xf0 && c <= 0xf4) {{         ");
+    w!(o, "            n = 3;                                     ");
+    w!(o, "            if (c == 0xf0) {{                          ");
+    w!(o, "                lower = 0x90;                          ");
+    w!(o, "            }} else if (c == 0xf4) {{                  ");
+    w!(o, "                upper = 0x8f;                          ");
+    w!(o, "            }}                                         ");
+    w!(o, "        }} else {{                                     ");
+    w!(o, "            return MOL_ERR_DATA;                       ");
+    w!(o, "        }}                                             ");
+    w!(o, "        if (size - i <= n) {{                          ");
+    w!(o, "            return MOL_ERR_DATA;                       ");
+    w!(o, "        }}                                             ");
+    w!(o, "        if (ptr[i+1] < lower || ptr[i+1] > upper) {{   ");
+    w!(o, "            return MOL_ERR_DATA;                       ");
+    w!(o, "        }}                                             ");
+    w!(o, "        for (mol_num_t j = 2; j <= n; j++) {{          ");
+    w!(o, "            if ((ptr[i+j] & 0xc0) != 0x80) {{          ");
+    w!(o, "                return MOL_ERR_DATA;                   ");
+    w!(o, "            }}                                         ");
+    w!(o, "        }}                                             ");
+    w!(o, "        i += n + 1;                                    ");
+    w!(o, "    }}                                                 ");
+    w!(o, "    return MOL_OK;                                     ");
+    w!(o, "}}                                                     ");
+    w!(o, "#endif /* MOL_UTF8_VERIFY */                           ");
+    Ok(())
+}
+
+// The keys of a map should be strictly ascending, they are compared byte-wise.
+fn write_map_keys_verify<W: io::Write>(
+    o: &mut W,
+    reader_prefix: &str,
+    entry_reader_prefix: &str,
+) -> io::Result<()> {
+    w!(
+        o,
+        "    mol_num_t entry_count = {}_length(input);          ",
+        reader_prefix
+    );
+    w!(o, "    for (mol_num_t i = 1; i < entry_count; i++) {{     ");
+    w!(
+        o,
+        "        mol_seg_t prev = {}_get(input, i - 1).seg;    ",
+        reader_prefix
+    );
+    w!(
+        o,
+        "        mol_seg_t curr = {}_get(input, i).seg;        ",
+        reader_prefix
+    );
+    w!(
+        o,
+        "        mol_seg_t prev_key = {}_get_key(&prev);       ",
+        entry_reader_prefix
+    );
+    w!(
+        o,
+        "        mol_seg_t curr_key = {}_get_key(&curr);       ",
+        entry_reader_prefix
+    );
+    w!(o, "        if (mol_seg_cmp(&prev_key, &curr_key) >= 0) {{ ");
+    w!(o, "            return MOL_ERR_DATA;                       ");
+    w!(o, "        }}                                             ");
+    w!(o, "    }}                                                 ");
+    Ok(())
+}
+
+// The recursive types are verified with the depth of the nested data, so the data which is nested
+// too deeply is rejected, instead of overflowing the stack.
+fn write_verify_header<W: io::Write>(
+    o: &mut W,
+    reader_prefix: &str,
+    api_decorator: &str,
+    is_recursive: bool,
+) -> io::Result<()> {
+    if is_recursive {
+        w!(
+            o,
+            "{} mol_errno {}_verify (const mol_seg_t *input, bool compatible) {{",
+            api_decorator,
+            reader_prefix
+        );
+        w!(
+            o,
+            "    return {}_verify_nested(input, compatible, 0);",
+            reader_prefix
+        );
+        w!(o, "}}                                                     ");
+        w!(
+            o,
+            "{} mol_errno {}_verify_nested (const mol_seg_t *input, bool compatible, mol_num_t depth) {{",
+            api_decorator,
+            reader_prefix
+        );
+        w!(o, "    if (depth >= MOL_MAX_NESTING_DEPTH) {{             ");
+        w!(o, "        return MOL_ERR_NESTING_DEPTH;                  ");
+        w!(o, "    }}                                                 ");
+    } else {
+        w!(
+            o,
+            "{} mol_errno {}_verify (const mol_seg_t *input, bool compatible) {{",
+            api_decorator,
+            reader_prefix
+        );
+    }
+    Ok(())
+}
+
+// The call which verifies an inner item, the depth is increased if both types are recursive.
+fn verify_inner(is_recursive: bool, inner: &ast::TopDecl, input: &str) -> String {
+    if is_recursive && inner.is_recursive() {
+        format!(
+            "{}_verify_nested({}, compatible, depth + 1)",
+            inner.reader_prefix(),
+            input
+        )
+    } else {
+        format!("{}_verify({}, compatible)", inner.reader_prefix(), input)
+    }
+}
+
+// The violated constraints are reported as `MOL_ERR_CONSTRAINT`, `item_count` should be declared.
+fn write_items_count_verify<W: io::Write>(
+    o: &mut W,
+    constraints: &ast::Constraints,
+) -> io::Result<()> {
+    if constraints.non_empty() {
+        w!(o, "    if (item_count == 0) {{                            ");
+        w!(o, "        return MOL_ERR_CONSTRAINT;                     ");
+        w!(o, "    }}                                                 ");
+    }
+    write_max_items_verify(o, constraints)
+}
+
+fn write_max_items_verify<W: io::Write>(
+    o: &mut W,
+    constraints: &ast::Constraints,
+) -> io::Result<()> {
+    if let Some(max_items) = constraints.max_items() {
+        w!(
+            o,
+            "    if (item_count > {}) {{                           ",
+            max_items
+        );
+        w!(o, "        return MOL_ERR_CONSTRAINT;                     ");
+        w!(o, "    }}                                                 ");
+    }
+    Ok(())
+}
+
+// The value is an unsigned integer in little-endian, the checks which always pass are omitted.
+fn write_range_verify<W: io::Write>(
+    o: &mut W,
+    constraints: &ast::Constraints,
+    size: usize,
+) -> io::Result<()> {
+    let upper_limit = if size < 8 { (1 << (size * 8)) - 1 } else { !0 };
+    let min_value = constraints.min_value().filter(|min| *min > 0);
+    let max_value = constraints.max_value().filter(|max| *max < upper_limit);
+    if min_value.is_none() && max_value.is_none() {
+        return Ok(());
+    }
+    w!(o, "    uint64_t value = 0;                                ");
+    w!(
+        o,
+        "    for (mol_num_t i = {}; i > 0; i--) {{              ",
+        size
+    );
+    w!(o, "        value = (value << 8) | input->ptr[i - 1];      ");
+    w!(o, "    }}                                                 ");
+    for (op, limit) in [("<", min_value), (">", max_value)].iter() {
+        if let Some(limit) = limit {
+            let limit = unsigned_literal(*limit);
+            w!(
+                o,
+                "    if (value {} {}) {{                            ",
+                op,
+                limit
+            );
+            w!(o, "        return MOL_ERR_CONSTRAINT;                     ");
+            w!(o, "    }}                                                 ");
+        }
+    }
+    Ok(())
+}
+
+// The literals without suffixes are signed.
+fn unsigned_literal(value: u64) -> String {
+    if value >> 63 != 0 {
+        format!("{}u", value)
+    } else {
+        value.to_string()
+    }
 }
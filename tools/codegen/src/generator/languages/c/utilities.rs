@@ -1,5 +1,6 @@
 use std::io;
 
+use super::super::deprecation_note;
 use crate::ast::{self as ast, HasName};
 
 pub(super) const API_DECORATOR: &str = "MOLECULE_API_DECORATOR";
@@ -10,17 +11,122 @@ macro_rules! w {
     }
 }
 
+/// Writes doc comments as a block comment, nothing is written if there are no docs.
+pub(super) fn write_docs<W: io::Write>(
+    writer: &mut W,
+    docs: &[String],
+    attributes: &[ast::Attribute],
+) -> io::Result<()> {
+    let deprecation = deprecation_note(attributes);
+    if docs.is_empty() && deprecation.is_none() {
+        return Ok(());
+    }
+    writeln!(writer, "/*")?;
+    for line in docs {
+        // the doc comments should not close the block comment
+        let line = line.replace("*/", "* /");
+        w!(writer, " * {}", line);
+    }
+    if let Some(note) = deprecation {
+        if !docs.is_empty() {
+            writeln!(writer, " *")?;
+        }
+        if note.is_empty() {
+            writeln!(writer, " * Deprecated.")?;
+        } else {
+            w!(writer, " * Deprecated: {}", note.replace("*/", "* /"));
+        }
+    }
+    writeln!(writer, " */")
+}
+
+/// The native type of a built-in type, for example, `uint32_t` for `uint32`.
+pub(super) fn native_type(builtin: ast::BuiltIn) -> String {
+    match builtin {
+        ast::BuiltIn::Integer { signed, size: 16 } => {
+            if signed {
+                "__int128".to_owned()
+            } else {
+                "unsigned __int128".to_owned()
+            }
+        }
+        ast::BuiltIn::Integer { signed, size } => {
+            let prefix = if signed { "int" } else { "uint" };
+            format!("{}{}_t", prefix, size * 8)
+        }
+        ast::BuiltIn::Bool => "bool".to_owned(),
+        ast::BuiltIn::String => unreachable!("a string is read as a segment in C"),
+    }
+}
+
+/// The name of the helper which unpacks a built-in type, for example, `mol_unpack_uint32`.
+pub(super) fn unpack_function(builtin: ast::BuiltIn) -> String {
+    format!("mol_unpack_{}", builtin.name())
+}
+
+/// Writes a byte array definition, the zero bytes are written as `____`.
+pub(super) fn write_bytes_array<W: io::Write>(
+    writer: &mut W,
+    definition: &str,
+    bytes: &[u8],
+) -> io::Result<()> {
+    write!(writer, "{:64} =  {{", definition)?;
+    if bytes.len() > 4 {
+        for (index, byte) in bytes.iter().enumerate() {
+            if index % 12 == 0 {
+                writeln!(writer)?;
+                write!(writer, "{:4}", "")?;
+            } else {
+                write!(writer, " ")?;
+            }
+            if *byte == 0 {
+                write!(writer, "____,")?;
+            } else {
+                write!(writer, "0x{:02x},", byte)?;
+            }
+        }
+        writeln!(writer)?;
+    } else {
+        let mut not_first = false;
+        for byte in bytes {
+            if not_first {
+                write!(writer, ", ")?;
+            } else {
+                not_first = true;
+            }
+            if *byte == 0 {
+                write!(writer, "____")?;
+            } else {
+                write!(writer, "0x{:02x}", byte)?;
+            }
+        }
+    }
+    writeln!(writer, "}};")?;
+    Ok(())
+}
+
+/// The name in the C API, with the prefix from the `#[c(prefix = "...")]` attribute.
+pub(super) fn ident_name(name: &str, attributes: &[ast::Attribute]) -> String {
+    let prefix = ast::Attribute::find(attributes, "c")
+        .and_then(|attr| attr.arg("prefix"))
+        .and_then(ast::Attribute::value)
+        .unwrap_or("");
+    format!("{}{}", prefix, name)
+}
+
 pub(super) trait IdentPrefix: HasName {
+    fn ident_name(&self) -> String;
+
     fn reader_prefix(&self) -> String {
-        format!("MolReader_{}", self.name())
+        format!("MolReader_{}", self.ident_name())
     }
 
     fn builder_prefix(&self) -> String {
-        format!("MolBuilder_{}", self.name())
+        format!("MolBuilder_{}", self.ident_name())
     }
 
     fn default_constant(&self) -> String {
-        format!("MolDefault_{}", self.name())
+        format!("MolDefault_{}", self.ident_name())
     }
 
     fn api_decorator(&self) -> &str {
@@ -110,18 +216,22 @@ pub(super) trait IdentPrefix: HasName {
     }
 }
 
-impl IdentPrefix for ast::Option_ {}
-
-impl IdentPrefix for ast::Union {}
-
-impl IdentPrefix for ast::Array {}
-
-impl IdentPrefix for ast::Struct {}
-
-impl IdentPrefix for ast::FixVec {}
-
-impl IdentPrefix for ast::DynVec {}
-
-impl IdentPrefix for ast::Table {}
+macro_rules! impl_ident_prefix_for {
+    ($decl:ident) => {
+        impl IdentPrefix for ast::$decl {
+            fn ident_name(&self) -> String {
+                ident_name(self.name(), self.attributes())
+            }
+        }
+    };
+}
 
-impl IdentPrefix for ast::TopDecl {}
+impl_ident_prefix_for!(Option_);
+impl_ident_prefix_for!(Union);
+impl_ident_prefix_for!(Array);
+impl_ident_prefix_for!(Struct);
+impl_ident_prefix_for!(FixVec);
+impl_ident_prefix_for!(DynVec);
+impl_ident_prefix_for!(Table);
+impl_ident_prefix_for!(Enum);
+impl_ident_prefix_for!(TopDecl);
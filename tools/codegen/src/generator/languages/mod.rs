@@ -1,6 +1,6 @@
 use std::{convert::TryFrom, fmt, io};
 
-use crate::ast;
+use crate::{ast, error::Error};
 
 mod c;
 mod rust;
@@ -15,6 +15,15 @@ pub(super) trait LanguageGenerator {
     fn generate<W: io::Write>(writer: &mut W, ast: &ast::Ast) -> io::Result<()>;
 }
 
+/// Returns the note of the `#[deprecated]` attribute, the note is empty if it isn't given.
+pub(super) fn deprecation_note(attributes: &[ast::Attribute]) -> Option<&str> {
+    ast::Attribute::find(attributes, "deprecated").map(|attr| {
+        attr.value()
+            .or_else(|| attr.arg("note").and_then(ast::Attribute::value))
+            .unwrap_or("")
+    })
+}
+
 impl fmt::Display for Language {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
@@ -43,6 +52,14 @@ impl Language {
         }
     }
 
+    /// Checks whether the code could be generated, for example, the attributes are valid.
+    pub(crate) fn check(self, ast: &ast::Ast) -> Result<(), Error> {
+        match self {
+            Self::C => Ok(()),
+            Self::Rust => rust::check(ast),
+        }
+    }
+
     pub(crate) fn generate<W: io::Write>(self, writer: &mut W, ast: &ast::Ast) -> io::Result<()> {
         match self {
             Self::C => c::Generator::generate(writer, ast),
@@ -0,0 +1,109 @@
+use proc_macro2 as m4;
+use quote::quote;
+
+use super::utilities::{allow_deprecated_fields, doc_attrs_with_deprecation, ident_new};
+use crate::{ast, error::Location};
+
+pub(super) trait DefAnnotations {
+    fn def_docs(&self) -> m4::TokenStream;
+    fn def_extra_derives(&self) -> m4::TokenStream;
+    fn allow_deprecated(&self) -> m4::TokenStream;
+}
+
+/// Checks the paths in `#[rust(derive = "...")]`, they are separated by commas.
+///
+/// The error has the location of the `derive` argument.
+pub(super) fn check_extra_derives(
+    attributes: &[ast::Attribute],
+) -> Result<(), (Option<&Location>, String)> {
+    let derive = ast::Attribute::find(attributes, "rust").and_then(|attr| attr.arg("derive"));
+    derive_paths(attributes).map(|_| ()).map_err(|reason| {
+        let location = derive.and_then(ast::Attribute::location);
+        (location, reason)
+    })
+}
+
+fn extra_derives(attributes: &[ast::Attribute]) -> m4::TokenStream {
+    // the paths are checked before generating the code
+    let derives = derive_paths(attributes).unwrap_or_default();
+    quote!( #( , #derives )* )
+}
+
+fn derive_paths(attributes: &[ast::Attribute]) -> Result<Vec<m4::TokenStream>, String> {
+    let value = if let Some(value) = ast::Attribute::find(attributes, "rust")
+        .and_then(|attr| attr.arg("derive"))
+        .and_then(ast::Attribute::value)
+    {
+        value
+    } else {
+        return Ok(Vec::new());
+    };
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|path| {
+            let mut segments = path.split("::").collect::<Vec<_>>();
+            let leading = if segments.len() > 1 && segments[0].is_empty() {
+                segments.remove(0);
+                quote!(::)
+            } else {
+                quote!()
+            };
+            let segments = segments
+                .into_iter()
+                .map(|segment| {
+                    if is_identifier(segment) {
+                        Ok(ident_new(segment))
+                    } else {
+                        Err(format!("`{}` in `derive` is not a path", path))
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(quote!(#leading #( #segments )::*))
+        })
+        .collect()
+}
+
+fn is_identifier(segment: &str) -> bool {
+    let mut chars = segment.chars();
+    match chars.next() {
+        Some(ch) if ch == '_' || ch.is_ascii_alphabetic() => {
+            segment != "_" && chars.all(|ch| ch == '_' || ch.is_ascii_alphanumeric())
+        }
+        _ => false,
+    }
+}
+
+macro_rules! impl_def_annotations_for {
+    ($decl:ident) => {
+        impl_def_annotations_for!($decl, |_| quote!());
+    };
+    ($decl:ident, fields) => {
+        impl_def_annotations_for!($decl, |decl: &ast::$decl| allow_deprecated_fields(
+            decl.fields()
+        ));
+    };
+    ($decl:ident, $allow_deprecated:expr) => {
+        impl DefAnnotations for ast::$decl {
+            fn def_docs(&self) -> m4::TokenStream {
+                doc_attrs_with_deprecation(self.docs(), self.attributes())
+            }
+            fn def_extra_derives(&self) -> m4::TokenStream {
+                extra_derives(self.attributes())
+            }
+            fn allow_deprecated(&self) -> m4::TokenStream {
+                ($allow_deprecated)(self)
+            }
+        }
+    };
+}
+
+impl_def_annotations_for!(Option_);
+impl_def_annotations_for!(Union);
+impl_def_annotations_for!(Array);
+impl_def_annotations_for!(Struct, fields);
+impl_def_annotations_for!(FixVec);
+impl_def_annotations_for!(DynVec);
+impl_def_annotations_for!(Table, fields);
+impl_def_annotations_for!(Enum);
@@ -2,7 +2,7 @@ use proc_macro2 as m4;
 use quote::quote;
 
 use super::super::utilities::{
-    builder_name, entity_name, entity_union_name, field_name, usize_lit,
+    builder_name, entity_enum_name, entity_name, entity_union_name, field_name, usize_lit,
 };
 use crate::ast::{self as ast, HasName};
 
@@ -82,6 +82,17 @@ impl DefBuilder for ast::Table {
     }
 }
 
+impl DefBuilder for ast::Enum {
+    fn def_builder(&self) -> m4::TokenStream {
+        let builder = builder_name(self.name());
+        let entity_enum = entity_enum_name(self.name());
+        quote!(
+            #[derive(Debug, Default)]
+            pub struct #builder (pub(crate) #entity_enum);
+        )
+    }
+}
+
 fn def_builder_for_struct_or_table(self_name: &str, inner: &[ast::FieldDecl]) -> m4::TokenStream {
     let builder = builder_name(self_name);
     let fields = inner.iter().map(|f| {
@@ -1,7 +1,7 @@
 use proc_macro2 as m4;
 use quote::quote;
 
-use super::super::utilities::{builder_name, entity_name, field_name, usize_lit};
+use super::super::utilities::{builder_name, entity_name, enum_repr_type, field_name, usize_lit};
 use crate::ast::{self as ast, HasName};
 
 pub(in super::super) trait ImplBuilder: HasName {
@@ -83,6 +83,20 @@ impl ImplBuilder for ast::Array {
     }
 }
 
+impl ImplBuilder for ast::Enum {
+    fn impl_builder_internal(&self) -> m4::TokenStream {
+        let repr = enum_repr_type(self);
+        quote!(
+            fn expected_length(&self) -> usize {
+                Self::TOTAL_SIZE
+            }
+            fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+                writer.write_all(&#repr::from(self.0).to_le_bytes())
+            }
+        )
+    }
+}
+
 impl ImplBuilder for ast::Struct {
     fn impl_builder_internal(&self) -> m4::TokenStream {
         let fields = self.fields().iter().map(|f| {
@@ -105,15 +119,18 @@ impl ImplBuilder for ast::Struct {
 
 impl ImplBuilder for ast::FixVec {
     fn impl_builder_internal(&self) -> m4::TokenStream {
-        let write_inners = quote!(for inner in &self.0[..] {
+        let (prepare, items) = vector_items(self.is_map());
+        let write_inners = quote!(for inner in &#items[..] {
             writer.write_all(inner.as_slice())?;
         });
         quote!(
             fn expected_length(&self) -> usize {
-                molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+                #prepare
+                molecule::NUMBER_SIZE + Self::ITEM_SIZE * #items.len()
             }
             fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-                writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+                #prepare
+                writer.write_all(&molecule::pack_number(#items.len() as molecule::Number))?;
                 #write_inners
                 Ok(())
             }
@@ -123,23 +140,25 @@ impl ImplBuilder for ast::FixVec {
 
 impl ImplBuilder for ast::DynVec {
     fn impl_builder_internal(&self) -> m4::TokenStream {
+        let (prepare, items) = vector_items(self.is_map());
         quote!(
             fn expected_length(&self) -> usize {
-                molecule::NUMBER_SIZE * (self.0.len() + 1)
-                    + self
-                        .0
+                #prepare
+                molecule::NUMBER_SIZE * (#items.len() + 1)
+                    + #items
                         .iter()
                         .map(|inner| inner.as_slice().len())
                         .sum::<usize>()
             }
             fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-                let item_count = self.0.len();
+                #prepare
+                let item_count = #items.len();
                 if item_count == 0 {
                     writer.write_all(&molecule::pack_number(
                         molecule::NUMBER_SIZE as molecule::Number,
                     ))?;
                 } else {
-                    let (total_size, offsets) = self.0.iter().fold(
+                    let (total_size, offsets) = #items.iter().fold(
                         (
                             molecule::NUMBER_SIZE * (item_count + 1),
                             Vec::with_capacity(item_count),
@@ -153,7 +172,7 @@ impl ImplBuilder for ast::DynVec {
                     for offset in offsets.into_iter() {
                         writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
                     }
-                    for inner in self.0.iter() {
+                    for inner in #items.iter() {
                         writer.write_all(inner.as_slice())?;
                     }
                 }
@@ -163,6 +182,18 @@ impl ImplBuilder for ast::DynVec {
     }
 }
 
+// The items of a map are written as the sorted entries.
+fn vector_items(is_map: bool) -> (m4::TokenStream, m4::TokenStream) {
+    if is_map {
+        (
+            quote!(let entries = self.sorted_entries();),
+            quote!(entries),
+        )
+    } else {
+        (quote!(), quote!(self.0))
+    }
+}
+
 impl ImplBuilder for ast::Table {
     fn impl_builder_internal(&self) -> m4::TokenStream {
         if self.fields().is_empty() {
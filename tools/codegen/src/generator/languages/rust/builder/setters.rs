@@ -1,7 +1,10 @@
 use proc_macro2 as m4;
 use quote::quote;
 
-use super::super::utilities::{entity_name, entity_union_name, field_name, func_name, usize_lit};
+use super::super::utilities::{
+    deprecated_attr, entity_enum_name, entity_name, entity_union_name, field_name, func_name,
+    native_type, usize_lit,
+};
 use crate::ast::{self as ast, HasName};
 
 pub(in super::super) trait ImplSetters {
@@ -57,9 +60,29 @@ impl ImplSetters for ast::Array {
                 )
             })
             .collect::<Vec<_>>();
+        let native_setter = self.builtin().map(|builtin| {
+            let native = native_type(builtin);
+            let func = func_name(&format!("set_{}", native));
+            let native_setter_stmt = if let ast::BuiltIn::Bool = builtin {
+                quote!(self.0 = [Byte::new(u8::from(v))];)
+            } else {
+                quote!(
+                    for (item, byte) in self.0.iter_mut().zip(v.to_le_bytes().iter()) {
+                        *item = Byte::new(*byte);
+                    }
+                )
+            };
+            quote!(
+                pub fn #func(mut self, v: #native) -> Self {
+                    #native_setter_stmt
+                    self
+                }
+            )
+        });
         quote!(
             #entire_setter
             #( #each_setter )*
+            #native_setter
         )
     }
 }
@@ -72,13 +95,39 @@ impl ImplSetters for ast::Struct {
 
 impl ImplSetters for ast::FixVec {
     fn impl_setters(&self) -> m4::TokenStream {
-        impl_setters_for_vector(self.item().typ().name())
+        let setters = impl_setters_for_vector(self.item().typ().name());
+        if let Some(ast::BuiltIn::String) = self.builtin() {
+            quote!(
+                #setters
+                pub fn set_str(mut self, v: &str) -> Self {
+                    self.0 = v.bytes().map(Byte::new).collect();
+                    self
+                }
+            )
+        } else if let Some(entry) = self.map_entry() {
+            let map_setters = impl_setters_for_map(self.item().typ().name(), entry);
+            quote!(
+                #setters
+                #map_setters
+            )
+        } else {
+            setters
+        }
     }
 }
 
 impl ImplSetters for ast::DynVec {
     fn impl_setters(&self) -> m4::TokenStream {
-        impl_setters_for_vector(self.item().typ().name())
+        let setters = impl_setters_for_vector(self.item().typ().name());
+        if let Some(entry) = self.map_entry() {
+            let map_setters = impl_setters_for_map(self.item().typ().name(), entry);
+            quote!(
+                #setters
+                #map_setters
+            )
+        } else {
+            setters
+        }
     }
 }
 
@@ -88,13 +137,27 @@ impl ImplSetters for ast::Table {
     }
 }
 
+impl ImplSetters for ast::Enum {
+    fn impl_setters(&self) -> m4::TokenStream {
+        let entity_enum = entity_enum_name(self.name());
+        quote!(
+            pub fn set(mut self, v: #entity_enum) -> Self {
+                self.0 = v;
+                self
+            }
+        )
+    }
+}
+
 fn impl_setters_for_struct_or_table(inner: &[ast::FieldDecl]) -> m4::TokenStream {
     let each_setter = inner
         .iter()
         .map(|f| {
             let field_name = field_name(f.name());
             let field_type = entity_name(f.typ().name());
+            let deprecated = deprecated_attr(f.attributes());
             quote!(
+                #deprecated
                 pub fn #field_name(mut self, v: #field_type) -> Self {
                     self.#field_name = v;
                     self
@@ -126,3 +189,26 @@ fn impl_setters_for_vector(inner_name: &str) -> m4::TokenStream {
         }
     )
 }
+
+// The entries are sorted by the keys when they are written, if a key is set more than once,
+// the last one is kept.
+fn impl_setters_for_map(
+    entry_name: &str,
+    (key, value): (ast::FieldDecl, ast::FieldDecl),
+) -> m4::TokenStream {
+    let entry = entity_name(entry_name);
+    let key_type = entity_name(key.typ().name());
+    let value_type = entity_name(value.typ().name());
+    quote!(
+        pub fn insert(mut self, key: #key_type, value: #value_type) -> Self {
+            self.0.push(#entry::new_builder().key(key).value(value).build());
+            self
+        }
+        fn sorted_entries(&self) -> Vec<&#entry> {
+            let mut entries = self.0.iter().rev().collect::<Vec<_>>();
+            entries.sort_by(|a, b| a.key().as_slice().cmp(b.key().as_slice()));
+            entries.dedup_by(|a, b| a.key().as_slice() == b.key().as_slice());
+            entries
+        }
+    )
+}
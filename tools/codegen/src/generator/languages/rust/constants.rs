@@ -64,11 +64,29 @@ impl DefConstants for ast::DynVec {
     }
 }
 
+impl DefConstants for ast::Enum {
+    fn def_constants(&self) -> m4::TokenStream {
+        let total_size = usize_lit(self.total_size());
+        let items_count = usize_lit(self.items().len());
+        quote!(
+            pub const TOTAL_SIZE: usize = #total_size;
+            pub const ITEMS_COUNT: usize = #items_count;
+        )
+    }
+}
+
 impl DefConstants for ast::Table {
     fn def_constants(&self) -> m4::TokenStream {
         let field_count = usize_lit(self.fields().len());
+        let min_field_count = if self.min_field_count() < self.fields().len() {
+            let min_field_count = usize_lit(self.min_field_count());
+            quote!(pub const MIN_FIELD_COUNT: usize = #min_field_count;)
+        } else {
+            quote!()
+        };
         quote!(
             pub const FIELD_COUNT: usize = #field_count;
+            #min_field_count
         )
     }
 }
@@ -1,7 +1,7 @@
 use proc_macro2 as m4;
 use quote::quote;
 
-use super::utilities::func_name;
+use super::utilities::{func_name, native_type};
 use crate::ast;
 
 pub(super) trait ImplDisplay {
@@ -30,7 +30,10 @@ impl ImplDisplay for ast::Union {
 
 impl ImplDisplay for ast::Array {
     fn impl_display(&self) -> m4::TokenStream {
-        if self.item().typ().is_byte() {
+        if let Some(builtin) = self.builtin() {
+            let func = func_name(&format!("get_{}", native_type(builtin)));
+            quote!(write!(f, "{}({})", Self::NAME, self.#func()))
+        } else if self.item().typ().is_byte() {
             quote!(
                 use molecule::hex_string;
                 let raw_data = hex_string(&self.raw_data());
@@ -75,7 +78,24 @@ impl ImplDisplay for ast::Struct {
 
 impl ImplDisplay for ast::FixVec {
     fn impl_display(&self) -> m4::TokenStream {
-        if self.item().typ().is_byte() {
+        if let Some(ast::BuiltIn::String) = self.builtin() {
+            // same as the native strings, so `to_string()` returns the text, the invalid bytes
+            // are replaced as `String::from_utf8_lossy()` does, since the data could be unchecked
+            quote!(
+                let mut slice = &self.as_slice()[molecule::NUMBER_SIZE..];
+                loop {
+                    match ::core::str::from_utf8(slice) {
+                        Ok(valid) => return write!(f, "{}", valid),
+                        Err(err) => {
+                            let (valid, rest) = slice.split_at(err.valid_up_to());
+                            let valid = ::core::str::from_utf8(valid).unwrap_or_default();
+                            write!(f, "{}\u{FFFD}", valid)?;
+                            slice = &rest[err.error_len().unwrap_or_else(|| rest.len())..];
+                        }
+                    }
+                }
+            )
+        } else if self.item().typ().is_byte() {
             quote!(
                 use molecule::hex_string;
                 let raw_data = hex_string(&self.raw_data());
@@ -113,6 +133,12 @@ impl ImplDisplay for ast::DynVec {
     }
 }
 
+impl ImplDisplay for ast::Enum {
+    fn impl_display(&self) -> m4::TokenStream {
+        quote!(write!(f, "{}({})", Self::NAME, self.to_enum()))
+    }
+}
+
 impl ImplDisplay for ast::Table {
     fn impl_display(&self) -> m4::TokenStream {
         let display_fields = self.fields().iter().enumerate().map(|(i, f)| {
@@ -1,7 +1,9 @@
 use proc_macro2 as m4;
 use quote::quote;
 
-use super::super::utilities::{builder_name, entity_name, field_name, func_name, reader_name};
+use super::super::utilities::{
+    allow_deprecated_fields, builder_name, entity_name, field_name, func_name, reader_name,
+};
 use crate::ast::{self as ast, HasName};
 
 pub(in super::super) trait ImplEntity: HasName {
@@ -78,7 +80,9 @@ impl ImplEntity for ast::Struct {
     fn impl_entity_internal(&self) -> m4::TokenStream {
         let fields = self.fields().iter().map(|f| field_name(f.name()));
         let fields_func = fields.clone();
+        let allow_deprecated = allow_deprecated_fields(self.fields());
         quote!(
+            #allow_deprecated
             fn as_builder(self) -> Self::Builder {
                 Self::new_builder()
                     #( .#fields(self.#fields_func()) )*
@@ -107,11 +111,23 @@ impl ImplEntity for ast::DynVec {
     }
 }
 
+impl ImplEntity for ast::Enum {
+    fn impl_entity_internal(&self) -> m4::TokenStream {
+        quote!(
+            fn as_builder(self) -> Self::Builder {
+                Self::new_builder().set(self.to_enum())
+            }
+        )
+    }
+}
+
 impl ImplEntity for ast::Table {
     fn impl_entity_internal(&self) -> m4::TokenStream {
         let fields = self.fields().iter().map(|f| field_name(f.name()));
         let fields_func = fields.clone();
+        let allow_deprecated = allow_deprecated_fields(self.fields());
         quote!(
+            #allow_deprecated
             fn as_builder(self) -> Self::Builder {
                 Self::new_builder()
                     #( .#fields(self.#fields_func()) )*
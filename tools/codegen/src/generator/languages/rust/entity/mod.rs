@@ -18,6 +18,7 @@ where
         + super::constants::DefConstants
         + super::properties::DefProperties
         + super::getters::ImplGetters
+        + super::annotations::DefAnnotations
         + implementation::ImplEntity,
 {
     fn gen_entity(&self) -> m4::TokenStream {
@@ -27,13 +28,17 @@ where
             .default_content()
             .into_iter()
             .map(|b| usize_lit(b as usize));
+        let docs = self.def_docs();
+        let extra_derives = self.def_extra_derives();
+        let allow_deprecated = self.allow_deprecated();
         let display_stmts = self.impl_display();
         let constants = self.def_constants();
         let properties = self.def_properties();
         let getters = self.impl_getters_for_entity();
         let implementation = self.impl_entity();
         quote!(
-            #[derive(Clone)]
+            #docs
+            #[derive(Clone #extra_derives)]
             pub struct #entity(molecule::bytes::Bytes);
 
             impl ::core::fmt::LowerHex for #entity {
@@ -53,6 +58,7 @@ where
             }
 
             impl ::core::fmt::Display for #entity {
+                #allow_deprecated
                 fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                     #display_stmts
                 }
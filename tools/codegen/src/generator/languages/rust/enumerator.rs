@@ -2,7 +2,8 @@ use proc_macro2 as m4;
 use quote::quote;
 
 use super::utilities::{
-    entity_name, entity_union_name, reader_name, reader_union_name, union_item_name, usize_lit,
+    doc_attrs_with_deprecation, entity_name, entity_union_name, reader_name, reader_union_name,
+    union_item_name, usize_lit,
 };
 use crate::ast::{self as ast, HasName};
 
@@ -26,7 +27,7 @@ impl GenEnumerator for ast::Union {
             ref entity_union_item_paths,
             ref reader_union_item_paths,
         ) = {
-            self.items().iter().enumerate().fold(
+            self.items().iter().fold(
                 (
                     Vec::with_capacity(inner_len),
                     Vec::with_capacity(inner_len),
@@ -43,12 +44,12 @@ impl GenEnumerator for ast::Union {
                     mut entity_union_item_paths,
                     mut reader_union_item_paths,
                 ),
-                 (index, inner)| {
-                    let inner_name = inner.typ().name();
-                    let entity_name = entity_name(inner_name);
-                    let reader_name = reader_name(inner_name);
-                    let item_name = union_item_name(inner_name);
-                    let item_id = usize_lit(index);
+                 inner| {
+                    let inner_type = inner.typ();
+                    let entity_name = entity_name(inner_type.name());
+                    let reader_name = reader_name(inner_type.name());
+                    let item_name = union_item_name(inner.variant_name());
+                    let item_id = usize_lit(inner.id());
                     let entity_union_item_path = quote!(#entity_union::#item_name);
                     let reader_union_item_path = quote!(#reader_union::#item_name);
                     entity_inners.push(entity_name);
@@ -72,19 +73,37 @@ impl GenEnumerator for ast::Union {
             .iter()
             .map(|x| x.to_string())
             .collect::<Vec<_>>();
+        // for named items, display the item names instead of the type names
+        let display_names = &self
+            .items()
+            .iter()
+            .zip(entity_inners.iter())
+            .map(|(inner, entity_name)| {
+                if let Some(name) = inner.name() {
+                    quote!(#name)
+                } else {
+                    quote!(#entity_name::NAME)
+                }
+            })
+            .collect::<Vec<_>>();
+        let item_docs = &self
+            .items()
+            .iter()
+            .map(|inner| doc_attrs_with_deprecation(inner.docs(), inner.attributes()))
+            .collect::<Vec<_>>();
         let entity_default = {
             let inner = &self.items()[0];
-            let item_name = union_item_name(inner.typ().name());
+            let item_name = union_item_name(inner.variant_name());
             quote!(#item_name(::core::default::Default::default()))
         };
         let code_union_definitions_and_impl_traits = quote!(
             #[derive(Debug, Clone)]
             pub enum #entity_union {
-                #( #union_items(#entity_inners), )*
+                #( #item_docs #union_items(#entity_inners), )*
             }
             #[derive(Debug, Clone, Copy)]
             pub enum #reader_union<'r> {
-                #( #union_items(#reader_inners<'r>), )*
+                #( #item_docs #union_items(#reader_inners<'r>), )*
             }
 
             impl ::core::default::Default for #entity_union {
@@ -98,7 +117,7 @@ impl GenEnumerator for ast::Union {
                     match self {
                         #(
                             #entity_union_item_paths(ref item) => {
-                                write!(f, "{}::{}({})", Self::NAME, #union_items::NAME, item)
+                                write!(f, "{}::{}({})", Self::NAME, #display_names, item)
                             }
                         )*
                     }
@@ -109,7 +128,7 @@ impl GenEnumerator for ast::Union {
                     match self {
                         #(
                             #reader_union_item_paths(ref item) => {
-                                write!(f, "{}::{}({})", Self::NAME, #union_items::NAME, item)
+                                write!(f, "{}::{}({})", Self::NAME, #display_names, item)
                             }
                         )*
                     }
@@ -131,10 +150,21 @@ impl GenEnumerator for ast::Union {
                 }
             }
         );
+        // a type could be used by several named items, then the conversion is ambiguous
+        let is_unique = |index: usize| {
+            let typ = self.items()[index].typ();
+            self.items()
+                .iter()
+                .filter(|inner| inner.typ().name() == typ.name())
+                .count()
+                == 1
+        };
         let code_entity_item_into_union = union_items
             .iter()
             .zip(entity_inners.iter())
-            .map(|(item_name, entity_name)| {
+            .enumerate()
+            .filter(|(index, _)| is_unique(*index))
+            .map(|(_, (item_name, entity_name))| {
                 quote!(
                     impl ::core::convert::From<#entity_name> for #entity_union {
                         fn from(item: #entity_name) -> Self {
@@ -147,7 +177,9 @@ impl GenEnumerator for ast::Union {
         let code_reader_item_into_union = union_items
             .iter()
             .zip(reader_inners.iter())
-            .map(|(item_name, reader_name)| {
+            .enumerate()
+            .filter(|(index, _)| is_unique(*index))
+            .map(|(_, (item_name, reader_name))| {
                 quote!(
                     impl<'r> ::core::convert::From<#reader_name<'r>> for #reader_union<'r> {
                         fn from(item: #reader_name<'r>) -> Self {
@@ -183,7 +215,7 @@ impl GenEnumerator for ast::Union {
                     }
                     pub fn as_reader<'r>(&'r self) -> #reader_union<'r> {
                         match self {
-                            #( #entity_union_item_paths(item) => item.as_reader().into(), )*
+                            #( #entity_union_item_paths(item) => #reader_union_item_paths(item.as_reader()), )*
                         }
                     }
                 }
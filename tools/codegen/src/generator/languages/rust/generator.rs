@@ -1,10 +1,20 @@
 use std::io;
 
+use proc_macro2 as m4;
+use quote::quote;
+
 use super::{
-    builder::GenBuilder, entity::GenEntity, enumerator::GenEnumerator, iterator::GenIterator,
+    builder::GenBuilder,
+    entity::GenEntity,
+    enumerator::GenEnumerator,
+    iterator::GenIterator,
     reader::GenReader,
+    utilities::{
+        deprecated_attr, doc_attrs, doc_attrs_with_deprecation, entity_enum_name, entity_name,
+        enum_item_name, enum_repr_type, func_name, ident_new, native_type, usize_lit,
+    },
 };
-use crate::ast;
+use crate::ast::{self, HasName as _};
 
 pub(super) trait Generator {
     fn generate<W: io::Write>(&self, writer: &mut W) -> io::Result<()>;
@@ -34,6 +44,26 @@ impl Generator for ast::Array {
         writeln!(writer, "{}", self.gen_entity())?;
         writeln!(writer, "{}", self.gen_reader())?;
         writeln!(writer, "{}", self.gen_builder())?;
+        if let Some(builtin) = self.builtin() {
+            let entity = entity_name(self.name());
+            let native = native_type(builtin);
+            let getter = func_name(&format!("get_{}", native));
+            let setter = func_name(&format!("set_{}", native));
+            let code = quote!(
+                impl ::core::convert::From<#native> for #entity {
+                    fn from(value: #native) -> Self {
+                        Self::new_builder().#setter(value).build()
+                    }
+                }
+
+                impl ::core::convert::From<#entity> for #native {
+                    fn from(value: #entity) -> Self {
+                        value.#getter()
+                    }
+                }
+            );
+            writeln!(writer, "{}", code)?;
+        }
         Ok(())
     }
 }
@@ -53,6 +83,17 @@ impl Generator for ast::FixVec {
         writeln!(writer, "{}", self.gen_reader())?;
         writeln!(writer, "{}", self.gen_builder())?;
         writeln!(writer, "{}", self.gen_iterator())?;
+        if let Some(ast::BuiltIn::String) = self.builtin() {
+            let entity = entity_name(self.name());
+            let code = quote!(
+                impl<'a> ::core::convert::From<&'a str> for #entity {
+                    fn from(value: &'a str) -> Self {
+                        Self::new_builder().set_str(value).build()
+                    }
+                }
+            );
+            writeln!(writer, "{}", code)?;
+        }
         Ok(())
     }
 }
@@ -75,3 +116,119 @@ impl Generator for ast::Table {
         Ok(())
     }
 }
+
+impl Generator for ast::Enum {
+    fn generate<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writeln!(writer, "{}", self.gen_entity())?;
+        writeln!(writer, "{}", self.gen_reader())?;
+        writeln!(writer, "{}", self.gen_builder())?;
+        let entity = entity_name(self.name());
+        let entity_enum = entity_enum_name(self.name());
+        let entity_string = entity.to_string();
+        let repr = enum_repr_type(self);
+        let docs = doc_attrs(&[format!("The items of `{}`.", entity_string)]);
+        let first_item = enum_item_name(self.items()[0].name());
+        let variants = self.items().iter().map(|item| {
+            let docs = doc_attrs_with_deprecation(item.docs(), item.attributes());
+            let item_name = enum_item_name(item.name());
+            let value = m4::Literal::u64_unsuffixed(item.value());
+            quote!(
+                #docs
+                #item_name = #value,
+            )
+        });
+        let names = self.items().iter().map(|item| {
+            let item_name = enum_item_name(item.name());
+            let item_string = item_name.to_string();
+            quote!(#entity_enum::#item_name => #item_string,)
+        });
+        let from_values = self.items().iter().map(|item| {
+            let item_name = enum_item_name(item.name());
+            let value = m4::Literal::u64_unsuffixed(item.value());
+            quote!(#value => Ok(#entity_enum::#item_name),)
+        });
+        let code = quote!(
+            #docs
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            #[repr(#repr)]
+            pub enum #entity_enum {
+                #( #variants )*
+            }
+
+            impl ::core::default::Default for #entity_enum {
+                fn default() -> Self {
+                    #entity_enum::#first_item
+                }
+            }
+
+            impl ::core::fmt::Display for #entity_enum {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    write!(f, "{}", self.item_name())
+                }
+            }
+
+            impl #entity_enum {
+                pub fn item_name(&self) -> &'static str {
+                    match self {
+                        #( #names )*
+                    }
+                }
+            }
+
+            impl ::core::convert::From<#entity_enum> for #repr {
+                fn from(value: #entity_enum) -> Self {
+                    value as #repr
+                }
+            }
+
+            impl ::core::convert::TryFrom<#repr> for #entity_enum {
+                type Error = molecule::error::VerificationError;
+                fn try_from(value: #repr) -> ::core::result::Result<Self, Self::Error> {
+                    match value {
+                        #( #from_values )*
+                        _ => Err(molecule::error::VerificationError::InvalidEnumValue(
+                            #entity_string.to_owned(),
+                            u64::from(value),
+                        )),
+                    }
+                }
+            }
+
+            impl ::core::convert::From<#entity_enum> for #entity {
+                fn from(value: #entity_enum) -> Self {
+                    Self::new_builder().set(value).build()
+                }
+            }
+
+            impl ::core::convert::From<#entity> for #entity_enum {
+                fn from(value: #entity) -> Self {
+                    value.to_enum()
+                }
+            }
+        );
+        writeln!(writer, "{}", code)?;
+        Ok(())
+    }
+}
+
+impl Generator for ast::Const {
+    fn generate<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        let name = ident_new(self.name());
+        let docs = if self.docs().is_empty() {
+            let doc = format!(" A constant of `{}`.", self.typ().name());
+            quote!(#[doc = #doc])
+        } else {
+            doc_attrs(self.docs())
+        };
+        let deprecated = deprecated_attr(self.attributes());
+        let size = usize_lit(self.value().len());
+        let bytes = self.value().iter().map(|b| m4::Literal::u8_unsuffixed(*b));
+        let code = quote!(
+            #docs
+            #deprecated
+            pub const #name: [u8; #size] = [ #( #bytes, )* ];
+        );
+        writeln!(writer, "{}", code)?;
+        Ok(())
+    }
+}
@@ -2,7 +2,8 @@ use proc_macro2 as m4;
 use quote::quote;
 
 use super::utilities::{
-    entity_name, entity_union_name, func_name, reader_name, reader_union_name, usize_lit,
+    deprecated_attr, doc_attrs, entity_enum_name, entity_name, entity_union_name, enum_item_name,
+    func_name, native_type, reader_name, reader_union_name, union_item_name, usize_lit,
 };
 use crate::ast::{self as ast, HasName};
 
@@ -52,25 +53,26 @@ impl ImplGetters for ast::Option_ {
 
 impl ImplGetters for ast::Union {
     fn impl_getters_internal(&self, is_entity: bool) -> m4::TokenStream {
-        let (getter_ret, getter_stmt) = if is_entity {
+        let (union, getter_ret, getter_stmt) = if is_entity {
             let union = entity_union_name(self.name());
             let getter_ret = quote!(#union);
             let getter_stmt = quote!(self.0.slice(molecule::NUMBER_SIZE..));
-            (getter_ret, getter_stmt)
+            (union, getter_ret, getter_stmt)
         } else {
             let union = reader_union_name(self.name());
             let getter_ret = quote!(#union<'r>);
             let getter_stmt = quote!(&self.as_slice()[molecule::NUMBER_SIZE..]);
-            (getter_ret, getter_stmt)
+            (union, getter_ret, getter_stmt)
         };
-        let match_stmts = self.items().iter().enumerate().map(|(index, inner)| {
-            let item_id = usize_lit(index);
+        let match_stmts = self.items().iter().map(|inner| {
+            let item_id = usize_lit(inner.id());
+            let item_name = union_item_name(inner.variant_name());
             let inner = if is_entity {
                 entity_name(inner.typ().name())
             } else {
                 reader_name(inner.typ().name())
             };
-            quote!(#item_id => #inner::new_unchecked(inner).into(),)
+            quote!(#item_id => #union::#item_name(#inner::new_unchecked(inner)),)
         });
         quote!(
             pub fn to_enum(&self) -> #getter_ret {
@@ -116,7 +118,29 @@ impl ImplGetters for ast::Array {
                 )
             })
             .collect::<Vec<_>>();
-        if self.item().typ().is_byte() {
+        if let Some(builtin) = self.builtin() {
+            let native = native_type(builtin);
+            let func = func_name(&format!("get_{}", native));
+            let native_getter_stmt = if let ast::BuiltIn::Bool = builtin {
+                quote!(self.as_slice()[0] != 0)
+            } else {
+                let size = usize_lit(self.total_size());
+                quote!(
+                    let mut bytes = [0u8; #size];
+                    bytes.copy_from_slice(self.as_slice());
+                    #native::from_le_bytes(bytes)
+                )
+            };
+            quote!(
+                #( #each_getter )*
+                pub fn raw_data(&self) -> #getter_ret_byte {
+                    #getter_stmt_byte
+                }
+                pub fn #func(&self) -> #native {
+                    #native_getter_stmt
+                }
+            )
+        } else if self.item().typ().is_byte() {
             quote!(
                 #( #each_getter )*
                 pub fn raw_data(&self) -> #getter_ret_byte {
@@ -154,7 +178,11 @@ impl ImplGetters for ast::Struct {
                 } else {
                     quote!(&self.as_slice()[#start..#end])
                 };
+                let docs = doc_attrs(f.docs());
+                let deprecated = deprecated_attr(f.attributes());
                 let getter = quote!(
+                    #docs
+                    #deprecated
                     pub fn #func(&self) -> #getter_ret {
                         #inner::new_unchecked(#getter_stmt)
                     }
@@ -198,8 +226,9 @@ impl ImplGetters for ast::FixVec {
                 getter_stmt_byte,
             )
         };
+        let (index_getter, map_getter) = impl_map_getter(self.map_entry(), is_entity);
         let common_part = quote!(
-            pub fn get(&self, idx: usize) -> Option<#getter_ret> {
+            pub fn #index_getter(&self, idx: usize) -> Option<#getter_ret> {
                 if idx >= self.len() {
                     None
                 } else {
@@ -211,8 +240,25 @@ impl ImplGetters for ast::FixVec {
                 let end = start + Self::ITEM_SIZE;
                 #inner::new_unchecked(#getter_stmt)
             }
+            #map_getter
         );
-        if self.item().typ().is_byte() {
+        if let Some(ast::BuiltIn::String) = self.builtin() {
+            let getter_ret_str = if is_entity {
+                quote!(&str)
+            } else {
+                quote!(&'r str)
+            };
+            quote!(
+                #common_part
+                pub fn raw_data(&self) -> #getter_ret_byte {
+                    #getter_stmt_byte
+                }
+                pub fn as_str(&self) -> Result<#getter_ret_str, ::core::str::Utf8Error> {
+                    let slice = &self.as_slice()[molecule::NUMBER_SIZE..];
+                    ::core::str::from_utf8(slice)
+                }
+            )
+        } else if self.item().typ().is_byte() {
             quote!(
                 #common_part
                 pub fn raw_data(&self) -> #getter_ret_byte {
@@ -240,8 +286,9 @@ impl ImplGetters for ast::DynVec {
             let getter_stmt = quote!(&self.as_slice()[start..end]);
             (inner, getter_ret, getter_stmt_last, getter_stmt)
         };
+        let (index_getter, map_getter) = impl_map_getter(self.map_entry(), is_entity);
         quote!(
-            pub fn get(&self, idx: usize) -> Option<#getter_ret> {
+            pub fn #index_getter(&self, idx: usize) -> Option<#getter_ret> {
                 if idx >= self.len() {
                     None
                 } else {
@@ -260,10 +307,84 @@ impl ImplGetters for ast::DynVec {
                     #inner::new_unchecked(#getter_stmt)
                 }
             }
+            #map_getter
         )
     }
 }
 
+// The entries of a map are got by `get_entry(idx)`, since `get(&key)` finds the value of a key.
+fn impl_map_getter(
+    entry: Option<(ast::FieldDecl, ast::FieldDecl)>,
+    is_entity: bool,
+) -> (m4::Ident, m4::TokenStream) {
+    let (key, value) = if let Some(entry) = entry {
+        entry
+    } else {
+        return (func_name("get"), quote!());
+    };
+    let (key_type, value_ret) = if is_entity {
+        let key_type = entity_name(key.typ().name());
+        let value_ret = entity_name(value.typ().name());
+        (quote!(#key_type), quote!(#value_ret))
+    } else {
+        let key_type = reader_name(key.typ().name());
+        let value_ret = reader_name(value.typ().name());
+        (quote!(#key_type<'_>), quote!(#value_ret<'r>))
+    };
+    let map_getter = quote!(
+        pub fn get(&self, key: &#key_type) -> Option<#value_ret> {
+            let key = key.as_slice();
+            let (mut low, mut high) = (0, self.len());
+            while low < high {
+                let mid = low + (high - low) / 2;
+                let entry = self.get_unchecked(mid);
+                match entry.key().as_slice().cmp(key) {
+                    ::core::cmp::Ordering::Less => low = mid + 1,
+                    ::core::cmp::Ordering::Greater => high = mid,
+                    ::core::cmp::Ordering::Equal => return Some(entry.value()),
+                }
+            }
+            None
+        }
+    );
+    (func_name("get_entry"), map_getter)
+}
+
+impl ImplGetters for ast::Enum {
+    fn impl_getters_internal(&self, _is_entity: bool) -> m4::TokenStream {
+        let entity_enum = entity_enum_name(self.name());
+        let match_stmts = self.items().iter().map(|item| {
+            let value = m4::Literal::u64_unsuffixed(item.value());
+            let item_name = enum_item_name(item.name());
+            quote!(#value => #entity_enum::#item_name,)
+        });
+        quote!(
+            pub fn to_enum(&self) -> #entity_enum {
+                match self.raw_value() {
+                    #( #match_stmts )*
+                    _ => panic!("{}: invalid data", Self::NAME),
+                }
+            }
+        )
+    }
+}
+
+// The default value of a table field, which is returned when the field is missing.
+fn table_field_default(inner: &m4::Ident, default: &[u8], is_entity: bool) -> m4::TokenStream {
+    let default = default.iter().map(|b| usize_lit(*b as usize));
+    if is_entity {
+        quote!({
+            let v: Vec<u8> = vec![#( #default, )*];
+            #inner::new_unchecked(v.into())
+        })
+    } else {
+        quote!({
+            let v: &'static [u8] = &[#( #default, )*];
+            #inner::new_unchecked(v)
+        })
+    }
+}
+
 impl ImplGetters for ast::Table {
     fn impl_getters_internal(&self, is_entity: bool) -> m4::TokenStream {
         let (getter_stmt_last, getter_stmt) = if is_entity {
@@ -281,6 +402,8 @@ impl ImplGetters for ast::Table {
             .enumerate()
             .map(|(i, f)| {
                 let func = func_name(f.name());
+                let docs = doc_attrs(f.docs());
+                let deprecated = deprecated_attr(f.attributes());
                 let (inner, getter_ret) = if is_entity {
                     let inner = entity_name(f.typ().name());
                     let getter_ret = quote!(#inner);
@@ -292,8 +415,50 @@ impl ImplGetters for ast::Table {
                 };
                 let start = usize_lit((i + 1) * molecule::NUMBER_SIZE);
                 let end = usize_lit((i + 2) * molecule::NUMBER_SIZE);
-                if i == self.fields().len() - 1 {
+                if let Some(default) = f.default() {
+                    // the field is missing in an older encoding, and it could be the last one
+                    let index = usize_lit(i);
+                    let next = usize_lit(i + 1);
+                    let default_stmt = table_field_default(&inner, default, is_entity);
+                    quote!(
+                        #docs
+                        #deprecated
+                        pub fn #func(&self) -> #getter_ret {
+                            let field_count = self.field_count();
+                            if field_count <= #index {
+                                return #default_stmt;
+                            }
+                            let slice = self.as_slice();
+                            let start = molecule::unpack_number(&slice[#start..]) as usize;
+                            if field_count > #next {
+                                let end = molecule::unpack_number(&slice[#end..]) as usize;
+                                #inner::new_unchecked(#getter_stmt)
+                            } else {
+                                #inner::new_unchecked(#getter_stmt_last)
+                            }
+                        }
+                    )
+                } else if i + 1 == self.min_field_count() && i + 1 < self.fields().len() {
+                    // the next field could be missing
+                    let next = usize_lit(i + 1);
+                    quote!(
+                        #docs
+                        #deprecated
+                        pub fn #func(&self) -> #getter_ret {
+                            let slice = self.as_slice();
+                            let start = molecule::unpack_number(&slice[#start..]) as usize;
+                            if self.field_count() > #next {
+                                let end = molecule::unpack_number(&slice[#end..]) as usize;
+                                #inner::new_unchecked(#getter_stmt)
+                            } else {
+                                #inner::new_unchecked(#getter_stmt_last)
+                            }
+                        }
+                    )
+                } else if i == self.fields().len() - 1 {
                     quote!(
+                        #docs
+                        #deprecated
                         pub fn #func(&self) -> #getter_ret {
                             let slice = self.as_slice();
                             let start = molecule::unpack_number(&slice[#start..]) as usize;
@@ -307,6 +472,8 @@ impl ImplGetters for ast::Table {
                     )
                 } else {
                     quote!(
+                        #docs
+                        #deprecated
                         pub fn #func(&self) -> #getter_ret {
                             let slice = self.as_slice();
                             let start = molecule::unpack_number(&slice[#start..]) as usize;
@@ -9,8 +9,11 @@ pub(super) trait GenImport {
 }
 
 impl GenImport for ast::ImportStmt {
+    // The imported types are re-exported, since the types which are declared in several files, such
+    // as the built-in types, are only generated in the most deeply imported file, which may be
+    // imported indirectly.
     fn import_crate(&self) -> m4::TokenStream {
-        let mut stmt = quote!(use super::);
+        let mut stmt = quote!(pub use super::);
         for _ in 0..self.path_supers() {
             stmt = quote!(#stmt super::);
         }
@@ -1,8 +1,13 @@
 use std::io;
 
+use proc_macro2 as m4;
 use quote::quote;
 
-use crate::{ast, VERSION};
+use crate::{
+    ast::{self, HasName as _},
+    error::Error,
+    RUST_API_VERSION_MIN, VERSION,
+};
 
 pub(self) mod utilities;
 
@@ -22,6 +27,9 @@ pub(self) mod properties;
 /// Constants for `{ Entity, Reader }`
 pub(self) mod getters;
 
+/// Doc comments and attributes for `{ Entity, Reader }`
+mod annotations;
+
 /// Iterator for `{ Union } x { Entity, Reader }`
 pub(self) mod iterator;
 
@@ -36,6 +44,35 @@ use generator::Generator as _;
 
 pub(crate) struct Generator;
 
+impl Generator {
+    // The code fails to compile if the version of the API is older than the minimum version.
+    fn check_api_version<W: io::Write>(writer: &mut W) -> io::Result<()> {
+        let api_ver_min = semver::Version::parse(RUST_API_VERSION_MIN)
+            .map(|v| (v.major * 1000 + v.minor) * 1000 + v.patch)
+            .unwrap();
+        writeln!(
+            writer,
+            "// The code requires the API version {} of molecule, or newer.",
+            RUST_API_VERSION_MIN
+        )?;
+        let api_ver_min = m4::Literal::u32_unsuffixed(api_ver_min as u32);
+        let code = quote!(
+            const _: [(); 0] = [(); (molecule::API_VERSION < #api_ver_min) as usize];
+        );
+        write!(writer, "{}", code)
+    }
+}
+
+/// Checks the attributes which are used by the generator.
+pub(crate) fn check(ast: &ast::Ast) -> Result<(), Error> {
+    for decl in ast.major_decls() {
+        annotations::check_extra_derives(decl.attributes()).map_err(|(location, reason)| {
+            Error::InvalidAttribute(location.cloned(), decl.name().to_owned(), reason)
+        })?;
+    }
+    Ok(())
+}
+
 impl super::LanguageGenerator for Generator {
     fn generate<W: io::Write>(writer: &mut W, ast: &ast::Ast) -> io::Result<()> {
         writeln!(writer, "// Generated by Molecule {}", VERSION)?;
@@ -44,6 +81,8 @@ impl super::LanguageGenerator for Generator {
             use molecule::prelude::*;
         );
         write!(writer, "{}", code)?;
+        writeln!(writer)?;
+        Self::check_api_version(writer)?;
         let imports = ast.imports();
         if !imports.is_empty() {
             writeln!(writer)?;
@@ -62,9 +101,13 @@ impl super::LanguageGenerator for Generator {
                 ast::TopDecl::FixVec(ref i) => i.generate(writer)?,
                 ast::TopDecl::DynVec(ref i) => i.generate(writer)?,
                 ast::TopDecl::Table(ref i) => i.generate(writer)?,
+                ast::TopDecl::Enum(ref i) => i.generate(writer)?,
                 ast::TopDecl::Primitive(_) => unreachable!(),
             };
         }
+        for constant in ast.major_consts() {
+            constant.generate(writer)?;
+        }
         Ok(())
     }
 }
@@ -1,6 +1,7 @@
 use proc_macro2 as m4;
 use quote::quote;
 
+use super::utilities::enum_repr_type;
 use crate::ast;
 
 pub(super) trait DefProperties {
@@ -89,6 +90,19 @@ impl DefProperties for ast::DynVec {
     }
 }
 
+impl DefProperties for ast::Enum {
+    fn def_properties(&self) -> m4::TokenStream {
+        let repr = enum_repr_type(self);
+        quote!(
+            pub fn raw_value(&self) -> #repr {
+                let mut buf = [0u8; Self::TOTAL_SIZE];
+                buf.copy_from_slice(self.as_slice());
+                #repr::from_le_bytes(buf)
+            }
+        )
+    }
+}
+
 impl DefProperties for ast::Table {
     fn def_properties(&self) -> m4::TokenStream {
         quote!(
@@ -106,10 +120,10 @@ impl DefProperties for ast::Table {
             }
 
             pub fn count_extra_fields(&self) -> usize {
-                self.field_count() - Self::FIELD_COUNT
+                self.field_count().saturating_sub(Self::FIELD_COUNT)
             }
             pub fn has_extra_fields(&self) -> bool {
-                Self::FIELD_COUNT != self.field_count()
+                self.field_count() > Self::FIELD_COUNT
             }
         )
     }
@@ -1,7 +1,7 @@
 use proc_macro2 as m4;
 use quote::quote;
 
-use super::super::utilities::{entity_name, reader_name, usize_lit};
+use super::super::utilities::{entity_name, enum_repr_type, reader_name, usize_lit};
 use crate::ast::{self as ast, HasName};
 
 pub(in super::super) trait ImplReader: HasName {
@@ -33,48 +33,61 @@ pub(in super::super) trait ImplReader: HasName {
 
 impl ImplReader for ast::Option_ {
     fn impl_reader_internal(&self) -> m4::TokenStream {
-        let inner = reader_name(self.item().typ().name());
-        quote!(
-            fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
-                if !slice.is_empty() {
-                    #inner::verify(&slice[..], compatible)?;
-                }
-                Ok(())
+        let is_recursive = self.is_recursive();
+        let verify_inner = verify_inner(is_recursive, &self.item().typ(), quote!(&slice[..]));
+        let body = quote!(
+            if !slice.is_empty() {
+                #verify_inner?;
             }
-        )
+            Ok(())
+        );
+        impl_verify(is_recursive, body)
     }
 }
 
 impl ImplReader for ast::Union {
     fn impl_reader_internal(&self) -> m4::TokenStream {
-        let verify_inners = self.items().iter().enumerate().map(|(index, inner)| {
-            let item_id = usize_lit(index);
-            let inner = reader_name(inner.typ().name());
+        let is_recursive = self.is_recursive();
+        let verify_inners = self.items().iter().map(|inner| {
+            let item_id = usize_lit(inner.id());
+            let verify_inner = verify_inner(is_recursive, &inner.typ(), quote!(inner_slice));
             quote!(
-                #item_id => #inner::verify(inner_slice, compatible),
+                #item_id => #verify_inner,
             )
         });
-        quote!(
-            fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
-                use molecule::verification_error as ve;
-                let slice_len = slice.len();
-                if slice_len < molecule::NUMBER_SIZE {
-                    return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-                }
-                let item_id = molecule::unpack_number(slice);
-                let inner_slice = &slice[molecule::NUMBER_SIZE..];
-                match item_id {
-                    #( #verify_inners )*
-                    _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
-                }?;
-                Ok(())
+        let body = quote!(
+            use molecule::verification_error as ve;
+            let slice_len = slice.len();
+            if slice_len < molecule::NUMBER_SIZE {
+                return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
             }
-        )
+            let item_id = molecule::unpack_number(slice);
+            let inner_slice = &slice[molecule::NUMBER_SIZE..];
+            match item_id {
+                #( #verify_inners )*
+                _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
+            }?;
+            Ok(())
+        );
+        impl_verify(is_recursive, body)
     }
 }
 
 impl ImplReader for ast::Array {
     fn impl_reader_internal(&self) -> m4::TokenStream {
+        let verify_values = if let Some(ast::BuiltIn::Bool) = self.builtin() {
+            quote!(if slice[0] > 1 {
+                return ve!(Self, InvalidBool, slice[0]);
+            })
+        } else if self.item().typ().has_restricted_values() {
+            let inner = reader_name(self.item().typ().name());
+            quote!(for item_slice in slice.chunks(Self::ITEM_SIZE) {
+                #inner::verify(item_slice, _compatible)?;
+            })
+        } else {
+            quote!()
+        };
+        let verify_range = verify_range(self.constraints(), self.total_size());
         quote!(
             fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
                 use molecule::verification_error as ve;
@@ -82,6 +95,8 @@ impl ImplReader for ast::Array {
                 if slice_len != Self::TOTAL_SIZE {
                     return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
                 }
+                #verify_values
+                #verify_range
                 Ok(())
             }
         )
@@ -90,6 +105,23 @@ impl ImplReader for ast::Array {
 
 impl ImplReader for ast::Struct {
     fn impl_reader_internal(&self) -> m4::TokenStream {
+        let mut offset = 0;
+        let verify_fields = self
+            .fields()
+            .iter()
+            .zip(self.field_sizes().iter())
+            .filter_map(|(f, size)| {
+                let start = usize_lit(offset);
+                offset += size;
+                let end = usize_lit(offset);
+                if f.typ().has_restricted_values() {
+                    let inner = reader_name(f.typ().name());
+                    Some(quote!(#inner::verify(&slice[#start..#end], _compatible)?;))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
         quote!(
             fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
                 use molecule::verification_error as ve;
@@ -97,6 +129,7 @@ impl ImplReader for ast::Struct {
                 if slice_len != Self::TOTAL_SIZE {
                     return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
                 }
+                #( #verify_fields )*
                 Ok(())
             }
         )
@@ -105,6 +138,24 @@ impl ImplReader for ast::Struct {
 
 impl ImplReader for ast::FixVec {
     fn impl_reader_internal(&self) -> m4::TokenStream {
+        let verify_items = if let Some(ast::BuiltIn::String) = self.builtin() {
+            quote!(
+                if let Err(err) = ::core::str::from_utf8(&slice[molecule::NUMBER_SIZE..]) {
+                    return ve!(Self, InvalidUtf8, err.valid_up_to());
+                }
+            )
+        } else if self.has_restricted_values() {
+            let inner = reader_name(self.item().typ().name());
+            quote!(for item_slice in slice[molecule::NUMBER_SIZE..].chunks(Self::ITEM_SIZE) {
+                #inner::verify(item_slice, _compatible)?;
+            })
+        } else {
+            quote!()
+        };
+        let verify_keys = verify_map_keys(self.name(), self.is_map());
+        let constraints = self.constraints();
+        let verify_non_empty = verify_non_empty(constraints, quote!(item_count == 0));
+        let verify_max_items = verify_max_items(constraints);
         quote!(
             fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
                 use molecule::verification_error as ve;
@@ -113,6 +164,8 @@ impl ImplReader for ast::FixVec {
                     return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
                 }
                 let item_count = molecule::unpack_number(slice) as usize;
+                #verify_non_empty
+                #verify_max_items
                 if item_count == 0 {
                     if slice_len != molecule::NUMBER_SIZE {
                         return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
@@ -123,6 +176,8 @@ impl ImplReader for ast::FixVec {
                 if slice_len != total_size {
                     return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
                 }
+                #verify_items
+                #verify_keys
                 Ok(())
             }
         )
@@ -131,9 +186,23 @@ impl ImplReader for ast::FixVec {
 
 impl ImplReader for ast::DynVec {
     fn impl_reader_internal(&self) -> m4::TokenStream {
-        let inner = reader_name(self.item().typ().name());
-        quote!(
-            fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        let is_recursive = self.is_recursive();
+        let verify_inner =
+            verify_inner(is_recursive, &self.item().typ(), quote!(&slice[start..end]));
+        let verify_keys = verify_map_keys(self.name(), self.is_map());
+        let constraints = self.constraints();
+        let verify_non_empty =
+            verify_non_empty(constraints, quote!(slice_len == molecule::NUMBER_SIZE));
+        let verify_max_items = if constraints.max_items().is_some() {
+            let verify_max_items = verify_max_items(constraints);
+            quote!(
+                let item_count = offset_first / molecule::NUMBER_SIZE - 1;
+                #verify_max_items
+            )
+        } else {
+            quote!()
+        };
+        let body = quote!(
                 use molecule::verification_error as ve;
                 let slice_len = slice.len();
                 if slice_len < molecule::NUMBER_SIZE {
@@ -143,6 +212,7 @@ impl ImplReader for ast::DynVec {
                 if slice_len != total_size {
                     return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
                 }
+                #verify_non_empty
                 if slice_len == molecule::NUMBER_SIZE {
                     return Ok(());
                 }
@@ -153,6 +223,7 @@ impl ImplReader for ast::DynVec {
                 if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
                     return ve!(Self, OffsetsNotMatch);
                 }
+                #verify_max_items
                 if slice_len < offset_first {
                     return ve!(Self, HeaderIsBroken, offset_first, slice_len);
                 }
@@ -167,9 +238,153 @@ impl ImplReader for ast::DynVec {
                 for pair in offsets.windows(2) {
                     let start = pair[0];
                     let end =  pair[1];
-                    #inner::verify(&slice[start..end], compatible)?;
+                    #verify_inner?;
                 }
+                #verify_keys
                 Ok(())
+        );
+        impl_verify(is_recursive, body)
+    }
+}
+
+// The recursive types are verified with the depth of the nested data, so the data which is nested
+// too deeply is rejected, instead of overflowing the stack.
+fn impl_verify(is_recursive: bool, body: m4::TokenStream) -> m4::TokenStream {
+    if is_recursive {
+        quote!(
+            fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+                Self::verify_nested(slice, compatible, 0)
+            }
+            fn verify_nested(
+                slice: &[u8],
+                compatible: bool,
+                depth: usize,
+            ) -> molecule::error::VerificationResult<()> {
+                if depth >= molecule::MAX_NESTING_DEPTH {
+                    return molecule::verification_error!(
+                        Self,
+                        NestingTooDeep,
+                        molecule::MAX_NESTING_DEPTH
+                    );
+                }
+                #body
+            }
+        )
+    } else {
+        quote!(
+            fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+                #body
+            }
+        )
+    }
+}
+
+fn verify_inner(
+    is_recursive: bool,
+    inner: &ast::TopDecl,
+    slice: m4::TokenStream,
+) -> m4::TokenStream {
+    let reader = reader_name(inner.name());
+    if is_recursive && inner.is_recursive() {
+        quote!(#reader::verify_nested(#slice, compatible, depth + 1))
+    } else {
+        quote!(#reader::verify(#slice, compatible))
+    }
+}
+
+// The keys of a map should be strictly ascending, so a map has only one encoding.
+fn verify_map_keys(name: &str, is_map: bool) -> m4::TokenStream {
+    if !is_map {
+        return quote!();
+    }
+    let reader = reader_name(name);
+    quote!(
+        let reader = #reader::new_unchecked(slice);
+        for idx in 1..reader.len() {
+            let prev = reader.get_unchecked(idx - 1);
+            let curr = reader.get_unchecked(idx);
+            if prev.key().as_slice() >= curr.key().as_slice() {
+                return ve!(Self, UnsortedKeys, idx);
+            }
+        }
+    )
+}
+
+// The violated constraint is reported as it's declared in the schema.
+fn verify_non_empty(constraints: &ast::Constraints, is_empty: m4::TokenStream) -> m4::TokenStream {
+    if !constraints.non_empty() {
+        return quote!();
+    }
+    quote!(if #is_empty {
+        return ve!(Self, ConstraintViolated, "non_empty".to_owned(), 0);
+    })
+}
+
+fn verify_max_items(constraints: &ast::Constraints) -> m4::TokenStream {
+    if let Some(max_items) = constraints.max_items() {
+        let constraint = format!("max_items = {}", max_items);
+        let max_items = usize_lit(max_items);
+        quote!(if item_count > #max_items {
+            let actual = item_count as u64;
+            return ve!(Self, ConstraintViolated, #constraint.to_owned(), actual);
+        })
+    } else {
+        quote!()
+    }
+}
+
+fn verify_range(constraints: &ast::Constraints, size: usize) -> m4::TokenStream {
+    // the checks which always pass are omitted
+    let min_check = constraints.min_value().filter(|min| *min > 0).map(|min| {
+        let constraint = format!("min = {}", min);
+        let min = m4::Literal::u64_unsuffixed(min);
+        quote!(if value < #min {
+            return ve!(Self, ConstraintViolated, #constraint.to_owned(), value);
+        })
+    });
+    let upper_limit = if size < 8 { (1 << (size * 8)) - 1 } else { !0 };
+    let max_check = constraints
+        .max_value()
+        .filter(|max| *max < upper_limit)
+        .map(|max| {
+            let constraint = format!("max = {}", max);
+            let max = m4::Literal::u64_unsuffixed(max);
+            quote!(if value > #max {
+                return ve!(Self, ConstraintViolated, #constraint.to_owned(), value);
+            })
+        });
+    if min_check.is_none() && max_check.is_none() {
+        return quote!();
+    }
+    quote!(
+        let mut buf = [0u8; 8];
+        buf[..Self::TOTAL_SIZE].copy_from_slice(slice);
+        let value = u64::from_le_bytes(buf);
+        #min_check
+        #max_check
+    )
+}
+
+impl ImplReader for ast::Enum {
+    fn impl_reader_internal(&self) -> m4::TokenStream {
+        let repr = enum_repr_type(self);
+        let values = self
+            .items()
+            .iter()
+            .map(|item| m4::Literal::u64_unsuffixed(item.value()));
+        quote!(
+            fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+                use molecule::verification_error as ve;
+                let slice_len = slice.len();
+                if slice_len != Self::TOTAL_SIZE {
+                    return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+                }
+                let mut buf = [0u8; Self::TOTAL_SIZE];
+                buf.copy_from_slice(slice);
+                match #repr::from_le_bytes(buf) {
+                    #( #values )|* => Ok(()),
+                    value => ve!(Self, InvalidEnumValue, u64::from(value)),
+                }
             }
         )
     }
@@ -199,16 +414,44 @@ impl ImplReader for ast::Table {
                 }
             )
         } else {
+            let min_field_count = self.min_field_count();
+            let is_recursive = self.is_recursive();
             let verify_fields = self.fields().iter().enumerate().map(|(i, f)| {
-                let field = reader_name(f.typ().name());
                 let start = usize_lit(i);
                 let end = usize_lit(i + 1);
-                quote!(
-                    #field::verify(&slice[offsets[#start]..offsets[#end]], compatible)?;
-                )
+                let field_slice = quote!(&slice[offsets[#start]..offsets[#end]]);
+                let verify_field = verify_inner(is_recursive, &f.typ(), field_slice);
+                if i < min_field_count {
+                    quote!(
+                        #verify_field?;
+                    )
+                } else {
+                    // the field could be missing in an older encoding
+                    quote!(
+                        if field_count > #start {
+                            #verify_field?;
+                        }
+                    )
+                }
             });
-            quote!(
-                fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+            let (allow_no_fields, expected_field_count) = if min_field_count < self.fields().len() {
+                let allow_no_fields = if min_field_count == 0 {
+                    quote!(if slice_len == molecule::NUMBER_SIZE && compatible {
+                        return Ok(());
+                    })
+                } else {
+                    quote!()
+                };
+                let expected_field_count = quote!(if compatible {
+                    Self::MIN_FIELD_COUNT
+                } else {
+                    Self::FIELD_COUNT
+                });
+                (allow_no_fields, expected_field_count)
+            } else {
+                (quote!(), quote!(Self::FIELD_COUNT))
+            };
+            let body = quote!(
                     use molecule::verification_error as ve;
                     let slice_len = slice.len();
                     if slice_len < molecule::NUMBER_SIZE {
@@ -221,6 +464,7 @@ impl ImplReader for ast::Table {
                     if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
                         return Ok(());
                     }
+                    #allow_no_fields
                     if slice_len < molecule::NUMBER_SIZE * 2 {
                         return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
                     }
@@ -232,7 +476,7 @@ impl ImplReader for ast::Table {
                         return ve!(Self, HeaderIsBroken, offset_first, slice_len);
                     }
                     let field_count = offset_first / molecule::NUMBER_SIZE - 1;
-                    if field_count < Self::FIELD_COUNT {
+                    if field_count < #expected_field_count {
                         return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
                     } else if !compatible && field_count > Self::FIELD_COUNT {
                         return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
@@ -247,8 +491,8 @@ impl ImplReader for ast::Table {
                     }
                     #( #verify_fields )*
                     Ok(())
-                }
-            )
+            );
+            impl_verify(is_recursive, body)
         }
     }
 }
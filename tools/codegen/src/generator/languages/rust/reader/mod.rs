@@ -17,17 +17,22 @@ where
         + super::constants::DefConstants
         + super::properties::DefProperties
         + super::getters::ImplGetters
+        + super::annotations::DefAnnotations
         + implementation::ImplReader,
 {
     fn gen_reader(&self) -> m4::TokenStream {
         let reader = reader_name(self.name());
+        let docs = self.def_docs();
+        let extra_derives = self.def_extra_derives();
+        let allow_deprecated = self.allow_deprecated();
         let display_stmts = self.impl_display();
         let constants = self.def_constants();
         let properties = self.def_properties();
         let getters = self.impl_getters_for_reader();
         let implementation = self.impl_reader();
         quote!(
-            #[derive(Clone, Copy)]
+            #docs
+            #[derive(Clone, Copy #extra_derives)]
             pub struct #reader<'r>(&'r [u8]);
 
             impl<'r> ::core::fmt::LowerHex for #reader<'r> {
@@ -47,6 +52,7 @@ where
             }
 
             impl<'r> ::core::fmt::Display for #reader<'r> {
+                #allow_deprecated
                 fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                     #display_stmts
                 }
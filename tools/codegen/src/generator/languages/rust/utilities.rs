@@ -1,5 +1,73 @@
 use case::CaseExt;
 use proc_macro2 as m4;
+use quote::quote;
+
+use super::super::deprecation_note;
+use crate::ast;
+
+pub(super) fn doc_attrs(docs: &[String]) -> m4::TokenStream {
+    let lines = docs.iter().map(|line| {
+        if line.is_empty() {
+            String::new()
+        } else {
+            format!(" {}", line)
+        }
+    });
+    quote!( #( #[doc = #lines] )* )
+}
+
+/// The doc comments and a note for the deprecation, for the generated code which can't be
+/// marked as `#[deprecated]` since other generated code uses it.
+pub(super) fn doc_attrs_with_deprecation(
+    docs: &[String],
+    attributes: &[ast::Attribute],
+) -> m4::TokenStream {
+    if let Some(note) = deprecation_note(attributes) {
+        let mut docs = docs.to_owned();
+        if !docs.is_empty() {
+            docs.push(String::new());
+        }
+        if note.is_empty() {
+            docs.push("**Deprecated**".to_owned());
+        } else {
+            docs.push(format!("**Deprecated**: {}", note));
+        }
+        doc_attrs(&docs)
+    } else {
+        doc_attrs(docs)
+    }
+}
+
+pub(super) fn deprecated_attr(attributes: &[ast::Attribute]) -> m4::TokenStream {
+    match deprecation_note(attributes) {
+        Some("") => quote!(#[deprecated]),
+        Some(note) => quote!(#[deprecated(note = #note)]),
+        None => quote!(),
+    }
+}
+
+pub(super) fn allow_deprecated_fields(fields: &[ast::FieldDecl]) -> m4::TokenStream {
+    if fields
+        .iter()
+        .any(|f| deprecation_note(f.attributes()).is_some())
+    {
+        quote!(#[allow(deprecated)])
+    } else {
+        quote!()
+    }
+}
+
+/// The native type of a built-in type, for example, `u32` for `uint32`.
+pub(super) fn native_type(builtin: ast::BuiltIn) -> m4::Ident {
+    match builtin {
+        ast::BuiltIn::Integer { signed, size } => {
+            let prefix = if signed { "i" } else { "u" };
+            ident_new(&format!("{}{}", prefix, size * 8))
+        }
+        ast::BuiltIn::Bool => ident_new("bool"),
+        ast::BuiltIn::String => ident_new("str"),
+    }
+}
 
 pub(super) fn usize_lit(num: usize) -> m4::Literal {
     m4::Literal::usize_unsuffixed(num)
@@ -34,6 +102,19 @@ pub(super) fn union_item_name(name: &str) -> m4::Ident {
     ident_name(name, "")
 }
 
+pub(super) fn entity_enum_name(name: &str) -> m4::Ident {
+    ident_name(name, "Enum")
+}
+
+pub(super) fn enum_item_name(name: &str) -> m4::Ident {
+    ident_name(name, "")
+}
+
+/// The native unsigned integer which has the same size as an enum.
+pub(super) fn enum_repr_type(decl: &ast::Enum) -> m4::Ident {
+    ident_new(&format!("u{}", decl.total_size() * 8))
+}
+
 pub(super) fn builder_name(name: &str) -> m4::Ident {
     ident_name(name, "Builder")
 }
@@ -1,6 +1,6 @@
 use std::io;
 
-use crate::ast;
+use crate::{ast, error::Result};
 
 #[cfg(feature = "compiler-plugin")]
 use crate::ir::{self, ToIntermediate as _};
@@ -12,8 +12,9 @@ pub use languages::Language;
 #[derive(Debug, Clone, Copy)]
 pub(crate) enum Target {
     Language(Language),
+    /// The format, and the optional contents, such as the layout of the declarations.
     #[cfg(feature = "compiler-plugin")]
-    Intermediate(ir::Format),
+    Intermediate(ir::Format, ir::Contents),
 }
 
 #[derive(Debug)]
@@ -26,7 +27,7 @@ impl Target {
         match self {
             Self::Language(lang) => lang.extension(),
             #[cfg(feature = "compiler-plugin")]
-            Self::Intermediate(format) => format.extension(),
+            Self::Intermediate(format, _) => format.extension(),
         }
     }
 }
@@ -36,11 +37,27 @@ impl Generator {
         Self { ast }
     }
 
+    pub(crate) fn check(&self, target: Target) -> Result<()> {
+        match target {
+            Target::Language(lang) => lang.check(&self.ast),
+            #[cfg(feature = "compiler-plugin")]
+            Target::Intermediate(_, _) => Ok(()),
+        }
+    }
+
     pub(crate) fn generate<W: io::Write>(&self, target: Target, writer: &mut W) -> io::Result<()> {
         match target {
             Target::Language(lang) => lang.generate(writer, &self.ast),
             #[cfg(feature = "compiler-plugin")]
-            Target::Intermediate(format) => format.generate(writer, &self.ast.to_ir()),
+            Target::Intermediate(format, contents) => {
+                let mut ir = if contents.layout {
+                    self.ast.to_ir_with_layout()
+                } else {
+                    self.ast.to_ir()
+                };
+                ir.retain(contents);
+                format.generate(writer, &ir)
+            }
         }
     }
 }
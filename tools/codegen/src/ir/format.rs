@@ -1,11 +1,13 @@
 use std::{convert::TryFrom, fmt, io, str};
 
-use crate::ast;
+use crate::{ast, error::Error};
 
 #[derive(Debug, Clone, Copy)]
 pub enum Format {
     JSON,
     YAML,
+    /// The intermediate data is encoded by molecule, the schema is `schemas/ir.mol`.
+    Molecule,
 }
 
 impl fmt::Display for Format {
@@ -13,6 +15,7 @@ impl fmt::Display for Format {
         match *self {
             Self::JSON => write!(f, "JSON"),
             Self::YAML => write!(f, "YAML"),
+            Self::Molecule => write!(f, "Molecule"),
         }
     }
 }
@@ -23,6 +26,7 @@ impl TryFrom<&str> for Format {
         match value.to_uppercase().as_str() {
             "JSON" => Ok(Self::JSON),
             "YAML" => Ok(Self::YAML),
+            "MOLECULE" => Ok(Self::Molecule),
             format => Err(format!("unsupport format: [{}]", format)),
         }
     }
@@ -33,10 +37,12 @@ impl Format {
         match self {
             Self::JSON => "json",
             Self::YAML => "yaml",
+            Self::Molecule => "bin",
         }
     }
 
-    pub(crate) fn serialize(self, ir: &super::Ir) -> Result<Vec<u8>, String> {
+    /// Serializes the intermediate data in the format.
+    pub fn serialize(self, ir: &super::Ir) -> Result<Vec<u8>, String> {
         match self {
             Self::JSON => serde_json::to_string_pretty(ir)
                 .map_err(|err| format!("failed to serialize {}: {}", self, err))
@@ -52,10 +58,13 @@ impl Format {
                     s
                 })
                 .map(String::into_bytes),
+            Self::Molecule => super::molecule::encode(ir)
+                .map_err(|err| format!("failed to serialize {}: {}", self, err)),
         }
     }
 
-    pub(crate) fn deserialize(self, bytes: &[u8]) -> Result<super::Ir, String> {
+    /// Deserializes the intermediate data in the format.
+    pub fn deserialize(self, bytes: &[u8]) -> Result<super::Ir, String> {
         match self {
             Self::JSON | Self::YAML => {
                 let s = str::from_utf8(bytes)
@@ -65,17 +74,25 @@ impl Format {
                         .map_err(|err| format!("failed to deserialize {}: {}", self, err)),
                     Self::YAML => serde_yaml::from_str(s)
                         .map_err(|err| format!("failed to deserialize {}: {}", self, err)),
+                    Self::Molecule => unreachable!(),
                 }
             }
+            Self::Molecule => super::molecule::decode(bytes)
+                .map_err(|err| format!("failed to deserialize {}: {}", self, err)),
         }
     }
 
     pub(crate) fn generate<W: io::Write>(self, writer: &mut W, ir: &super::Ir) -> io::Result<()> {
-        let data = self.serialize(ir).unwrap();
+        let data = self
+            .serialize(ir)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
         writer.write_all(&data)
     }
 
-    pub fn recover(self, bytes: &[u8]) -> Result<ast::Ast, String> {
-        self.deserialize(bytes).map(ast::Ast::recover)
+    /// Deserializes the intermediate data and recovers the verified AST from it.
+    pub fn recover(self, bytes: &[u8]) -> Result<ast::Ast, Error> {
+        self.deserialize(bytes)
+            .map_err(Error::Intermediate)
+            .and_then(super::Ir::into_ast)
     }
 }
@@ -1,10 +1,17 @@
-use crate::ast::{self, HasName as _};
+use crate::ast::{self, DefaultContent as _, HasName as _};
 
 pub(crate) trait ToIntermediate {
     type Ir;
     fn to_ir(self: &Self) -> Self::Ir;
 }
 
+// Encodes the bytes as a hex string with the `0x` prefix.
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::from("0x"), |hex, byte| {
+        format!("{}{:02x}", hex, byte)
+    })
+}
+
 impl ToIntermediate for ast::Ast {
     type Ir = super::Ir;
     fn to_ir(&self) -> Self::Ir {
@@ -12,6 +19,52 @@ impl ToIntermediate for ast::Ast {
             namespace: self.namespace().to_owned(),
             imports: self.imports().iter().map(ToIntermediate::to_ir).collect(),
             decls: self.decls().iter().map(|decl| decl.to_ir()).collect(),
+            consts: self.consts().iter().map(ToIntermediate::to_ir).collect(),
+        }
+    }
+}
+
+impl ast::Ast {
+    pub(crate) fn to_ir_with_layout(&self) -> super::Ir {
+        let mut ir = self.to_ir();
+        for (decl_ir, decl) in ir.decls.iter_mut().zip(self.decls()) {
+            decl_ir.set_layout(layout_of(decl));
+        }
+        ir
+    }
+}
+
+pub(crate) fn layout_of(decl: &ast::TopDecl) -> super::Layout {
+    let (item_size, field_offsets) = match decl {
+        ast::TopDecl::Array(inner) => (Some(inner.item_size()), Vec::new()),
+        ast::TopDecl::FixVec(inner) => (Some(inner.item_size()), Vec::new()),
+        ast::TopDecl::Struct(inner) => (None, inner.field_offsets()),
+        _ => (None, Vec::new()),
+    };
+    super::Layout {
+        is_fixed_size: decl.is_fixed_size(),
+        total_size: decl.total_size(),
+        item_size,
+        field_offsets,
+        default: encode_hex(&decl.default_content()),
+    }
+}
+
+impl ToIntermediate for ast::Const {
+    type Ir = super::Const;
+    fn to_ir(&self) -> Self::Ir {
+        let value = encode_hex(self.value());
+        Self::Ir {
+            name: self.name().to_owned(),
+            docs: self.docs().to_owned(),
+            attributes: self
+                .attributes()
+                .iter()
+                .map(ToIntermediate::to_ir)
+                .collect(),
+            typ: self.typ().name().to_owned(),
+            value,
+            imported_depth: self.imported_depth(),
         }
     }
 }
@@ -23,6 +76,7 @@ impl ToIntermediate for ast::ImportStmt {
             name: self.name().to_owned(),
             paths: self.paths().to_owned(),
             path_supers: self.path_supers(),
+            resolved_path: self.resolved_path().cloned(),
         }
     }
 }
@@ -39,6 +93,7 @@ impl ToIntermediate for ast::TopDecl {
             Self::FixVec(inner) => Self::Ir::FixVec(inner.to_ir()),
             Self::DynVec(inner) => Self::Ir::DynVec(inner.to_ir()),
             Self::Table(inner) => Self::Ir::Table(inner.to_ir()),
+            Self::Enum(inner) => Self::Ir::Enum(inner.to_ir()),
         }
     }
 }
@@ -48,8 +103,15 @@ impl ToIntermediate for ast::Option_ {
     fn to_ir(&self) -> Self::Ir {
         Self::Ir {
             name: self.name().to_owned(),
+            docs: self.docs().to_owned(),
+            attributes: self
+                .attributes()
+                .iter()
+                .map(ToIntermediate::to_ir)
+                .collect(),
             item: self.item().to_ir(),
             imported_depth: self.imported_depth(),
+            layout: None,
         }
     }
 }
@@ -59,8 +121,48 @@ impl ToIntermediate for ast::Union {
     fn to_ir(&self) -> Self::Ir {
         Self::Ir {
             name: self.name().to_owned(),
-            items: self.items().iter().map(ToIntermediate::to_ir).collect(),
+            docs: self.docs().to_owned(),
+            attributes: self
+                .attributes()
+                .iter()
+                .map(ToIntermediate::to_ir)
+                .collect(),
+            items: {
+                let mut next_id = 0;
+                self.items()
+                    .iter()
+                    .map(|item| {
+                        let typ = item.typ().name().to_owned();
+                        let is_implicit = item.id() == next_id;
+                        next_id = item.id().saturating_add(1);
+                        if item.name().is_none()
+                            && item.docs().is_empty()
+                            && item.attributes().is_empty()
+                            && is_implicit
+                        {
+                            super::UnionItemDecl::Unnamed(super::ItemDecl { typ })
+                        } else {
+                            let name = item.name().map(ToOwned::to_owned);
+                            let docs = item.docs().to_owned();
+                            let attributes = item
+                                .attributes()
+                                .iter()
+                                .map(ToIntermediate::to_ir)
+                                .collect();
+                            let id = Some(item.id());
+                            super::UnionItemDecl::Detailed(super::DetailedUnionItemDecl {
+                                name,
+                                docs,
+                                attributes,
+                                typ,
+                                id,
+                            })
+                        }
+                    })
+                    .collect()
+            },
             imported_depth: self.imported_depth(),
+            layout: None,
         }
     }
 }
@@ -70,9 +172,18 @@ impl ToIntermediate for ast::Array {
     fn to_ir(&self) -> Self::Ir {
         Self::Ir {
             name: self.name().to_owned(),
+            docs: self.docs().to_owned(),
+            attributes: self
+                .attributes()
+                .iter()
+                .map(ToIntermediate::to_ir)
+                .collect(),
             item: self.item().to_ir(),
             item_count: self.item_count(),
+            builtin: self.builtin().map(ast::BuiltIn::name),
+            constraints: self.constraints().to_ir(),
             imported_depth: self.imported_depth(),
+            layout: None,
         }
     }
 }
@@ -82,8 +193,15 @@ impl ToIntermediate for ast::Struct {
     fn to_ir(&self) -> Self::Ir {
         Self::Ir {
             name: self.name().to_owned(),
+            docs: self.docs().to_owned(),
+            attributes: self
+                .attributes()
+                .iter()
+                .map(ToIntermediate::to_ir)
+                .collect(),
             fields: self.fields().iter().map(ToIntermediate::to_ir).collect(),
             imported_depth: self.imported_depth(),
+            layout: None,
         }
     }
 }
@@ -93,8 +211,18 @@ impl ToIntermediate for ast::FixVec {
     fn to_ir(&self) -> Self::Ir {
         Self::Ir {
             name: self.name().to_owned(),
+            docs: self.docs().to_owned(),
+            attributes: self
+                .attributes()
+                .iter()
+                .map(ToIntermediate::to_ir)
+                .collect(),
             item: self.item().to_ir(),
+            builtin: self.builtin().map(ast::BuiltIn::name),
+            map: self.is_map(),
+            constraints: self.constraints().to_ir(),
             imported_depth: self.imported_depth(),
+            layout: None,
         }
     }
 }
@@ -104,8 +232,29 @@ impl ToIntermediate for ast::DynVec {
     fn to_ir(&self) -> Self::Ir {
         Self::Ir {
             name: self.name().to_owned(),
+            docs: self.docs().to_owned(),
+            attributes: self
+                .attributes()
+                .iter()
+                .map(ToIntermediate::to_ir)
+                .collect(),
             item: self.item().to_ir(),
+            map: self.is_map(),
+            constraints: self.constraints().to_ir(),
             imported_depth: self.imported_depth(),
+            layout: None,
+        }
+    }
+}
+
+impl ToIntermediate for ast::Constraints {
+    type Ir = super::Constraints;
+    fn to_ir(&self) -> Self::Ir {
+        Self::Ir {
+            max_items: self.max_items(),
+            non_empty: self.non_empty(),
+            min_value: self.min_value(),
+            max_value: self.max_value(),
         }
     }
 }
@@ -115,8 +264,50 @@ impl ToIntermediate for ast::Table {
     fn to_ir(&self) -> Self::Ir {
         Self::Ir {
             name: self.name().to_owned(),
+            docs: self.docs().to_owned(),
+            attributes: self
+                .attributes()
+                .iter()
+                .map(ToIntermediate::to_ir)
+                .collect(),
             fields: self.fields().iter().map(ToIntermediate::to_ir).collect(),
             imported_depth: self.imported_depth(),
+            layout: None,
+        }
+    }
+}
+
+impl ToIntermediate for ast::Enum {
+    type Ir = super::Enum;
+    fn to_ir(&self) -> Self::Ir {
+        Self::Ir {
+            name: self.name().to_owned(),
+            docs: self.docs().to_owned(),
+            attributes: self
+                .attributes()
+                .iter()
+                .map(ToIntermediate::to_ir)
+                .collect(),
+            size: self.total_size(),
+            items: self.items().iter().map(ToIntermediate::to_ir).collect(),
+            imported_depth: self.imported_depth(),
+            layout: None,
+        }
+    }
+}
+
+impl ToIntermediate for ast::EnumItemDecl {
+    type Ir = super::EnumItemDecl;
+    fn to_ir(&self) -> Self::Ir {
+        Self::Ir {
+            name: self.name().to_owned(),
+            docs: self.docs().to_owned(),
+            attributes: self
+                .attributes()
+                .iter()
+                .map(ToIntermediate::to_ir)
+                .collect(),
+            value: self.value(),
         }
     }
 }
@@ -135,7 +326,25 @@ impl ToIntermediate for ast::FieldDecl {
     fn to_ir(&self) -> Self::Ir {
         Self::Ir {
             name: self.name().to_owned(),
+            docs: self.docs().to_owned(),
+            attributes: self
+                .attributes()
+                .iter()
+                .map(ToIntermediate::to_ir)
+                .collect(),
             typ: self.typ().name().to_owned(),
+            default: self.default().map(encode_hex),
+        }
+    }
+}
+
+impl ToIntermediate for ast::Attribute {
+    type Ir = super::Attribute;
+    fn to_ir(&self) -> Self::Ir {
+        Self::Ir {
+            name: self.name().to_owned(),
+            value: self.value().map(ToOwned::to_owned),
+            args: self.args().iter().map(ToIntermediate::to_ir).collect(),
         }
     }
 }
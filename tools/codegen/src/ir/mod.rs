@@ -1,34 +1,90 @@
+//! The intermediate data, which is passed from the compiler to the plugins.
+//!
+//! The data model follows [`INTERMEDIATE_VERSION`]: the types in this module only change in
+//! a compatible way, unless the major version is increased.
+//!
+//! A plugin in Rust could read the intermediate data and recover the verified AST with
+//! [`read_ast_from_stdin`]:
+//!
+//! ```no_run
+//! use molecule_codegen::{ast::HasName as _, ir, plugin::Handshake, IntermediateFormat};
+//!
+//! let handshake = Handshake::new(&[IntermediateFormat::JSON], &[]);
+//! if std::env::args().any(|arg| arg == "--handshake") {
+//!     println!("{}", handshake.to_json());
+//!     return;
+//! }
+//! let format = handshake.negotiate().unwrap();
+//! let ast = ir::read_ast_from_stdin(format).unwrap();
+//! for decl in ast.decls() {
+//!     println!("{}", decl.name());
+//! }
+//! ```
+//!
+//! [`INTERMEDIATE_VERSION`]: ../plugin/constant.INTERMEDIATE_VERSION.html
+
 mod format;
 mod from_ast;
+mod molecule;
+
+use std::{io, io::Read as _, path::PathBuf, str};
 
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize};
 
 use property::Property;
 
+use crate::{ast, error::Error};
+
 pub use format::Format;
-pub(crate) use from_ast::ToIntermediate;
+pub(crate) use from_ast::{layout_of, ToIntermediate};
 
 /// Intermediate file.
 #[derive(Debug, Property, Deserialize, Serialize)]
+#[property(get(public))]
 #[serde(deny_unknown_fields)]
-pub(crate) struct Ir {
+pub struct Ir {
     namespace: String,
     imports: Vec<ImportStmt>,
     #[serde(rename = "declarations")]
     decls: Vec<TopDecl>,
+    #[serde(rename = "constants", default, skip_serializing_if = "Vec::is_empty")]
+    consts: Vec<Const>,
 }
 
 #[derive(Debug, Clone, Property, Deserialize, Serialize)]
+#[property(get(public))]
 #[serde(deny_unknown_fields)]
-pub(crate) struct ImportStmt {
+pub struct ImportStmt {
     name: String,
     paths: Vec<String>,
     path_supers: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    resolved_path: Option<PathBuf>,
+}
+
+/// The value of a constant is a hex string with the `0x` prefix.
+#[derive(Debug, Property, Deserialize, Serialize)]
+#[property(get(public))]
+#[serde(deny_unknown_fields)]
+pub struct Const {
+    name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    docs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attributes: Vec<Attribute>,
+    #[serde(rename = "type")]
+    typ: String,
+    #[serde(deserialize_with = "hex_string")]
+    value: String,
+    #[serde(default = "zero", skip_serializing_if = "is_zero")]
+    imported_depth: usize,
 }
 
+/// A declaration, more kinds of declarations could be added in a compatible version.
 #[derive(Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields, tag = "type", rename_all = "lowercase")]
-pub(crate) enum TopDecl {
+#[non_exhaustive]
+pub enum TopDecl {
     #[serde(rename = "option")]
     Option_(Option_),
     Union(Union),
@@ -37,84 +93,284 @@ pub(crate) enum TopDecl {
     FixVec(FixVec),
     DynVec(DynVec),
     Table(Table),
+    Enum(Enum),
 }
 
 #[derive(Debug, Property, Deserialize, Serialize)]
+#[property(get(public))]
 #[serde(deny_unknown_fields)]
-pub(crate) struct Option_ {
+pub struct Option_ {
     name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    docs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attributes: Vec<Attribute>,
     item: ItemDecl,
     #[serde(default = "zero", skip_serializing_if = "is_zero")]
     imported_depth: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    layout: Option<Layout>,
 }
 
 #[derive(Debug, Property, Deserialize, Serialize)]
+#[property(get(public))]
 #[serde(deny_unknown_fields)]
-pub(crate) struct Union {
+pub struct Union {
     name: String,
-    items: Vec<ItemDecl>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    docs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attributes: Vec<Attribute>,
+    items: Vec<UnionItemDecl>,
     #[serde(default = "zero", skip_serializing_if = "is_zero")]
     imported_depth: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    layout: Option<Layout>,
 }
 
 #[derive(Debug, Property, Deserialize, Serialize)]
+#[property(get(public))]
 #[serde(deny_unknown_fields)]
-pub(crate) struct Array {
+pub struct Array {
     name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    docs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attributes: Vec<Attribute>,
     item: ItemDecl,
     item_count: usize,
+    /// The name of the built-in type, if the array is declared by the compiler for it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    builtin: Option<String>,
+    #[serde(default, skip_serializing_if = "Constraints::is_empty")]
+    constraints: Constraints,
     #[serde(default = "zero", skip_serializing_if = "is_zero")]
     imported_depth: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    layout: Option<Layout>,
 }
 
 #[derive(Debug, Property, Deserialize, Serialize)]
+#[property(get(public))]
 #[serde(deny_unknown_fields)]
-pub(crate) struct Struct {
+pub struct Struct {
     name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    docs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attributes: Vec<Attribute>,
     fields: Vec<FieldDecl>,
     #[serde(default = "zero", skip_serializing_if = "is_zero")]
     imported_depth: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    layout: Option<Layout>,
 }
 
 #[derive(Debug, Property, Deserialize, Serialize)]
+#[property(get(public))]
 #[serde(deny_unknown_fields)]
-pub(crate) struct FixVec {
+pub struct FixVec {
     name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    docs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attributes: Vec<Attribute>,
     item: ItemDecl,
+    /// The name of the built-in type, if the vector is declared by the compiler for it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    builtin: Option<String>,
+    /// Whether the vector is declared as a map, its items are the entries, which have two
+    /// fields, `key` and `value`, and they are sorted by the keys.
+    #[serde(default, skip_serializing_if = "is_false")]
+    map: bool,
+    #[serde(default, skip_serializing_if = "Constraints::is_empty")]
+    constraints: Constraints,
     #[serde(default = "zero", skip_serializing_if = "is_zero")]
     imported_depth: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    layout: Option<Layout>,
 }
 
 #[derive(Debug, Property, Deserialize, Serialize)]
+#[property(get(public))]
 #[serde(deny_unknown_fields)]
-pub(crate) struct DynVec {
+pub struct DynVec {
     name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    docs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attributes: Vec<Attribute>,
     item: ItemDecl,
+    /// Whether the vector is declared as a map, same as `FixVec`.
+    #[serde(default, skip_serializing_if = "is_false")]
+    map: bool,
+    #[serde(default, skip_serializing_if = "Constraints::is_empty")]
+    constraints: Constraints,
     #[serde(default = "zero", skip_serializing_if = "is_zero")]
     imported_depth: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    layout: Option<Layout>,
 }
 
 #[derive(Debug, Property, Deserialize, Serialize)]
+#[property(get(public))]
 #[serde(deny_unknown_fields)]
-pub(crate) struct Table {
+pub struct Table {
     name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    docs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attributes: Vec<Attribute>,
     fields: Vec<FieldDecl>,
     #[serde(default = "zero", skip_serializing_if = "is_zero")]
     imported_depth: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    layout: Option<Layout>,
 }
 
 #[derive(Debug, Property, Deserialize, Serialize)]
+#[property(get(public))]
+#[serde(deny_unknown_fields)]
+pub struct Enum {
+    name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    docs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attributes: Vec<Attribute>,
+    /// The size of the unsigned integer in bytes.
+    size: usize,
+    items: Vec<EnumItemDecl>,
+    #[serde(default = "zero", skip_serializing_if = "is_zero")]
+    imported_depth: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    layout: Option<Layout>,
+}
+
+/// The layout of a declaration, which is computed by the compiler.
+///
+/// It's only included if it's required, so the plugins don't have to compute it again.
+#[derive(Debug, PartialEq, Eq, Property, Deserialize, Serialize)]
+#[property(get(public))]
+#[serde(deny_unknown_fields)]
+pub struct Layout {
+    is_fixed_size: bool,
+    /// The size in bytes, if it's fixed size.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    total_size: Option<usize>,
+    /// The size of the items of an array or a fixvec.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    item_size: Option<usize>,
+    /// The offsets of the fields of a struct.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    field_offsets: Vec<usize>,
+    /// The default value, a hex string with the `0x` prefix.
+    #[serde(deserialize_with = "hex_string")]
+    default: String,
+}
+
+/// The optional contents of the intermediate data.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Contents {
+    pub(crate) layout: bool,
+    pub(crate) docs: bool,
+    pub(crate) attributes: bool,
+}
+
+#[derive(Debug, Property, Deserialize, Serialize)]
+#[property(get(public))]
 #[serde(deny_unknown_fields, transparent)]
-pub(crate) struct ItemDecl {
+pub struct ItemDecl {
+    typ: String,
+}
+
+/// Attributes are kept as they are written, the generators decide how to use them.
+#[derive(Debug, Property, Deserialize, Serialize)]
+#[property(get(public))]
+#[serde(deny_unknown_fields)]
+pub struct Attribute {
+    name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    args: Vec<Attribute>,
+}
+
+/// The constraints of the values, they are declared as attributes in the schema, but they are
+/// checked by the compiler and moved out of the attributes.
+#[derive(Debug, Default, Property, Deserialize, Serialize)]
+#[property(get(public))]
+#[serde(deny_unknown_fields)]
+pub struct Constraints {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_items: Option<usize>,
+    #[serde(default, skip_serializing_if = "is_false")]
+    non_empty: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_value: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_value: Option<u64>,
+}
+
+/// Union items which only have types are same as the items in other types.
+///
+/// An item with doc comments or attributes is always detailed.
+///
+/// An item without an explicit id follows the previous item, the first one is 0.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum UnionItemDecl {
+    Unnamed(ItemDecl),
+    Detailed(DetailedUnionItemDecl),
+}
+
+#[derive(Debug, Property, Deserialize, Serialize)]
+#[property(get(public))]
+#[serde(deny_unknown_fields)]
+pub struct DetailedUnionItemDecl {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    docs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attributes: Vec<Attribute>,
+    #[serde(rename = "type")]
     typ: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    id: Option<usize>,
 }
 
+/// The values of enum items are always explicit.
 #[derive(Debug, Property, Deserialize, Serialize)]
+#[property(get(public))]
 #[serde(deny_unknown_fields)]
-pub(crate) struct FieldDecl {
+pub struct EnumItemDecl {
     name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    docs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attributes: Vec<Attribute>,
+    value: u64,
+}
+
+#[derive(Debug, Property, Deserialize, Serialize)]
+#[property(get(public))]
+#[serde(deny_unknown_fields)]
+pub struct FieldDecl {
+    name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    docs: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attributes: Vec<Attribute>,
     #[serde(rename = "type")]
     typ: String,
+    /// The default value of a table field, a hex string with the `0x` prefix.
+    #[serde(
+        default,
+        deserialize_with = "hex_string_opt",
+        skip_serializing_if = "Option::is_none"
+    )]
+    default: Option<String>,
 }
 
 const fn zero() -> usize {
@@ -126,8 +382,111 @@ const fn is_zero(value: &usize) -> bool {
     *value == 0
 }
 
+// Decodes a hex string with the `0x` prefix, the values in the intermediate data are encoded so.
+fn decode_hex(value: &str) -> Result<Vec<u8>, String> {
+    let invalid = |reason: &str| format!("the hex string ({}) {}", value, reason);
+    if !value.starts_with("0x") {
+        return Err(invalid("doesn't start with 0x"));
+    }
+    let digits = &value[2..];
+    if !digits.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return Err(invalid("has invalid digits"));
+    }
+    // All digits are checked, a pair could only be invalid if it's the last one in an odd length.
+    digits
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            str::from_utf8(pair)
+                .ok()
+                .filter(|s| s.len() == 2)
+                .and_then(|s| u8::from_str_radix(s, 16).ok())
+                .ok_or_else(|| invalid("has odd length"))
+        })
+        .collect()
+}
+
+// The hex strings are checked once when they are deserialized, so the encoders could rely on them.
+fn hex_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    decode_hex(&value).map_err(de::Error::custom)?;
+    Ok(value)
+}
+
+fn hex_string_opt<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    let value = Option::<String>::deserialize(deserializer)?;
+    if let Some(ref value) = value {
+        decode_hex(value).map_err(de::Error::custom)?;
+    }
+    Ok(value)
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+const fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+impl Constraints {
+    fn is_empty(&self) -> bool {
+        self.max_items.is_none()
+            && !self.non_empty
+            && self.min_value.is_none()
+            && self.max_value.is_none()
+    }
+}
+
+impl Default for Contents {
+    fn default() -> Self {
+        Self {
+            layout: false,
+            docs: true,
+            attributes: true,
+        }
+    }
+}
+
+impl Ir {
+    /// Reads the intermediate data from stdin.
+    pub fn from_stdin(format: Format) -> Result<Self, Error> {
+        let mut bytes = Vec::new();
+        io::stdin()
+            .read_to_end(&mut bytes)
+            .map_err(|err| Error::Io(None, err))?;
+        format.deserialize(&bytes).map_err(Error::Intermediate)
+    }
+
+    /// Recovers the verified AST from the intermediate data.
+    ///
+    /// The declarations are checked again, an error is returned if they are invalid, for example,
+    /// a type is undeclared, or the layout doesn't match the declaration.
+    pub fn into_ast(self) -> Result<ast::Ast, Error> {
+        ast::Ast::recover(self)
+    }
+
+    /// Removes the doc comments and the attributes, if they aren't included in the contents.
+    pub(crate) fn retain(&mut self, contents: Contents) {
+        let annotations = self
+            .decls
+            .iter_mut()
+            .flat_map(TopDecl::annotations_mut)
+            .chain(
+                self.consts
+                    .iter_mut()
+                    .map(|inner| (&mut inner.docs, &mut inner.attributes)),
+            );
+        for (docs, attributes) in annotations {
+            if !contents.docs {
+                docs.clear();
+            }
+            if !contents.attributes {
+                attributes.clear();
+            }
+        }
+    }
+}
+
 impl TopDecl {
-    pub(crate) fn name(&self) -> &str {
+    pub fn name(&self) -> &str {
         match self {
             Self::Option_(inner) => inner.name(),
             Self::Union(inner) => inner.name(),
@@ -136,6 +495,116 @@ impl TopDecl {
             Self::FixVec(inner) => inner.name(),
             Self::DynVec(inner) => inner.name(),
             Self::Table(inner) => inner.name(),
+            Self::Enum(inner) => inner.name(),
+        }
+    }
+}
+
+impl TopDecl {
+    pub fn layout(&self) -> Option<&Layout> {
+        match self {
+            Self::Option_(inner) => inner.layout.as_ref(),
+            Self::Union(inner) => inner.layout.as_ref(),
+            Self::Array(inner) => inner.layout.as_ref(),
+            Self::Struct(inner) => inner.layout.as_ref(),
+            Self::FixVec(inner) => inner.layout.as_ref(),
+            Self::DynVec(inner) => inner.layout.as_ref(),
+            Self::Table(inner) => inner.layout.as_ref(),
+            Self::Enum(inner) => inner.layout.as_ref(),
+        }
+    }
+
+    fn set_layout(&mut self, layout: Layout) {
+        let field = match self {
+            Self::Option_(inner) => &mut inner.layout,
+            Self::Union(inner) => &mut inner.layout,
+            Self::Array(inner) => &mut inner.layout,
+            Self::Struct(inner) => &mut inner.layout,
+            Self::FixVec(inner) => &mut inner.layout,
+            Self::DynVec(inner) => &mut inner.layout,
+            Self::Table(inner) => &mut inner.layout,
+            Self::Enum(inner) => &mut inner.layout,
+        };
+        field.replace(layout);
+    }
+
+    // The doc comments and the attributes of the declaration, its items and its fields.
+    fn annotations_mut(&mut self) -> Vec<(&mut Vec<String>, &mut Vec<Attribute>)> {
+        let mut annotations = Vec::new();
+        match self {
+            Self::Option_(inner) => annotations.push((&mut inner.docs, &mut inner.attributes)),
+            Self::Union(inner) => {
+                annotations.push((&mut inner.docs, &mut inner.attributes));
+                for item in &mut inner.items {
+                    if let UnionItemDecl::Detailed(item) = item {
+                        annotations.push((&mut item.docs, &mut item.attributes));
+                    }
+                }
+            }
+            Self::Array(inner) => annotations.push((&mut inner.docs, &mut inner.attributes)),
+            Self::Struct(inner) => {
+                annotations.push((&mut inner.docs, &mut inner.attributes));
+                for field in &mut inner.fields {
+                    annotations.push((&mut field.docs, &mut field.attributes));
+                }
+            }
+            Self::FixVec(inner) => annotations.push((&mut inner.docs, &mut inner.attributes)),
+            Self::DynVec(inner) => annotations.push((&mut inner.docs, &mut inner.attributes)),
+            Self::Table(inner) => {
+                annotations.push((&mut inner.docs, &mut inner.attributes));
+                for field in &mut inner.fields {
+                    annotations.push((&mut field.docs, &mut field.attributes));
+                }
+            }
+            Self::Enum(inner) => {
+                annotations.push((&mut inner.docs, &mut inner.attributes));
+                for item in &mut inner.items {
+                    annotations.push((&mut item.docs, &mut item.attributes));
+                }
+            }
+        }
+        annotations
+    }
+}
+
+impl UnionItemDecl {
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Unnamed(_) => None,
+            Self::Detailed(inner) => inner.name.as_ref().map(AsRef::as_ref),
         }
     }
+
+    pub fn typ(&self) -> &str {
+        match self {
+            Self::Unnamed(inner) => inner.typ(),
+            Self::Detailed(inner) => inner.typ(),
+        }
+    }
+
+    pub fn id(&self) -> Option<usize> {
+        match self {
+            Self::Unnamed(_) => None,
+            Self::Detailed(inner) => inner.id,
+        }
+    }
+
+    pub fn docs(&self) -> &[String] {
+        match self {
+            Self::Unnamed(_) => &[],
+            Self::Detailed(inner) => inner.docs(),
+        }
+    }
+
+    pub fn attributes(&self) -> &[Attribute] {
+        match self {
+            Self::Unnamed(_) => &[],
+            Self::Detailed(inner) => inner.attributes(),
+        }
+    }
+}
+
+/// Reads the intermediate data from stdin, and recovers the verified AST from it.
+pub fn read_ast_from_stdin(format: Format) -> Result<ast::Ast, Error> {
+    Ir::from_stdin(format).and_then(Ir::into_ast)
 }
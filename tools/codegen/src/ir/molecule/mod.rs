@@ -0,0 +1,717 @@
+//! The intermediate data encoded by molecule, it's described by the schema `schemas/ir.mol`.
+//!
+//! The code in `generated.rs` is generated from the schema by `make ir-code`, and the CI checks
+//! that it's up to date.
+
+#[allow(dead_code, clippy::all)]
+mod generated;
+
+use std::path::PathBuf;
+
+use molecule::prelude::*;
+
+use generated as mol;
+
+pub(super) fn encode(ir: &super::Ir) -> Result<Vec<u8>, String> {
+    ir.to_molecule().map(|ir| ir.as_slice().to_owned())
+}
+
+pub(super) fn decode(bytes: &[u8]) -> Result<super::Ir, String> {
+    mol::IrReader::from_slice(bytes)
+        .map(super::Ir::from_molecule)
+        .map_err(|err| err.to_string())
+}
+
+trait ToMolecule {
+    type Entity;
+    fn to_molecule(&self) -> Result<Self::Entity, String>;
+}
+
+trait FromMolecule<'r> {
+    type Reader;
+    fn from_molecule(reader: Self::Reader) -> Self;
+}
+
+fn string(value: &str) -> mol::Utf8String {
+    mol::Utf8String::from(value)
+}
+
+fn string_opt(value: Option<&str>) -> mol::Utf8StringOpt {
+    mol::Utf8StringOpt::new_builder()
+        .set(value.map(string))
+        .build()
+}
+
+fn string_vec(values: &[String]) -> mol::Utf8StringVec {
+    mol::Utf8StringVec::new_builder()
+        .extend(values.iter().map(|value| string(value)))
+        .build()
+}
+
+fn uint64(value: u64) -> mol::Uint64 {
+    mol::Uint64::from(value)
+}
+
+fn uint64_opt(value: Option<u64>) -> mol::Uint64Opt {
+    mol::Uint64Opt::new_builder().set(value.map(uint64)).build()
+}
+
+fn usize_opt(value: Option<usize>) -> mol::Uint64Opt {
+    uint64_opt(value.map(|value| value as u64))
+}
+
+fn bytes(hex: &str) -> Result<mol::Bytes, String> {
+    let data = super::decode_hex(hex)?.into_iter().map(Byte::new).collect();
+    Ok(mol::Bytes::new_builder().set(data).build())
+}
+
+fn entities<T: ToMolecule>(values: &[T]) -> Result<Vec<T::Entity>, String> {
+    values.iter().map(ToMolecule::to_molecule).collect()
+}
+
+fn attributes(values: &[super::Attribute]) -> Result<mol::AttributeVec, String> {
+    Ok(mol::AttributeVec::new_builder()
+        .extend(entities(values)?)
+        .build())
+}
+
+fn layout(value: Option<&super::Layout>) -> Result<mol::LayoutOpt, String> {
+    Ok(mol::LayoutOpt::new_builder()
+        .set(value.map(ToMolecule::to_molecule).transpose()?)
+        .build())
+}
+
+fn field_decls(values: &[super::FieldDecl]) -> Result<mol::FieldDeclVec, String> {
+    Ok(mol::FieldDeclVec::new_builder()
+        .extend(entities(values)?)
+        .build())
+}
+
+// Encodes the bytes as a hex string with the `0x` prefix.
+fn hex(reader: mol::BytesReader) -> String {
+    reader
+        .raw_data()
+        .iter()
+        .fold(String::from("0x"), |hex, byte| {
+            format!("{}{:02x}", hex, byte)
+        })
+}
+
+// The strings are verified when the intermediate data is decoded.
+fn owned_string(reader: mol::Utf8StringReader) -> String {
+    reader.as_str().unwrap_or_default().to_owned()
+}
+
+fn owned_string_opt(reader: mol::Utf8StringOptReader) -> Option<String> {
+    reader.to_opt().map(owned_string)
+}
+
+fn owned_string_vec(reader: mol::Utf8StringVecReader) -> Vec<String> {
+    reader.iter().map(owned_string).collect()
+}
+
+fn usize_of(reader: mol::Uint64Reader) -> usize {
+    reader.get_u64() as usize
+}
+
+fn usize_opt_of(reader: mol::Uint64OptReader) -> Option<usize> {
+    reader.to_opt().map(usize_of)
+}
+
+fn attributes_of(reader: mol::AttributeVecReader) -> Vec<super::Attribute> {
+    reader.iter().map(FromMolecule::from_molecule).collect()
+}
+
+fn layout_of(reader: mol::LayoutOptReader) -> Option<super::Layout> {
+    reader.to_opt().map(FromMolecule::from_molecule)
+}
+
+fn field_decls_of(reader: mol::FieldDeclVecReader) -> Vec<super::FieldDecl> {
+    reader.iter().map(FromMolecule::from_molecule).collect()
+}
+
+impl ToMolecule for super::Ir {
+    type Entity = mol::Ir;
+    fn to_molecule(&self) -> Result<Self::Entity, String> {
+        let imports = mol::ImportStmtVec::new_builder()
+            .extend(entities(&self.imports)?)
+            .build();
+        let declarations = mol::TopDeclVec::new_builder()
+            .extend(entities(&self.decls)?)
+            .build();
+        let constants = mol::ConstVec::new_builder()
+            .extend(entities(&self.consts)?)
+            .build();
+        Ok(mol::Ir::new_builder()
+            .namespace(string(&self.namespace))
+            .imports(imports)
+            .declarations(declarations)
+            .constants(constants)
+            .build())
+    }
+}
+
+impl<'r> FromMolecule<'r> for super::Ir {
+    type Reader = mol::IrReader<'r>;
+    fn from_molecule(reader: Self::Reader) -> Self {
+        Self {
+            namespace: owned_string(reader.namespace()),
+            imports: reader
+                .imports()
+                .iter()
+                .map(FromMolecule::from_molecule)
+                .collect(),
+            decls: reader
+                .declarations()
+                .iter()
+                .map(FromMolecule::from_molecule)
+                .collect(),
+            consts: reader
+                .constants()
+                .iter()
+                .map(FromMolecule::from_molecule)
+                .collect(),
+        }
+    }
+}
+
+impl ToMolecule for super::ImportStmt {
+    type Entity = mol::ImportStmt;
+    fn to_molecule(&self) -> Result<Self::Entity, String> {
+        let resolved_path = self
+            .resolved_path
+            .as_ref()
+            .map(|path| path.to_string_lossy());
+        Ok(mol::ImportStmt::new_builder()
+            .name(string(&self.name))
+            .paths(string_vec(&self.paths))
+            .path_supers(uint64(self.path_supers as u64))
+            .resolved_path(string_opt(resolved_path.as_ref().map(AsRef::as_ref)))
+            .build())
+    }
+}
+
+impl<'r> FromMolecule<'r> for super::ImportStmt {
+    type Reader = mol::ImportStmtReader<'r>;
+    fn from_molecule(reader: Self::Reader) -> Self {
+        Self {
+            name: owned_string(reader.name()),
+            paths: owned_string_vec(reader.paths()),
+            path_supers: usize_of(reader.path_supers()),
+            resolved_path: owned_string_opt(reader.resolved_path()).map(PathBuf::from),
+        }
+    }
+}
+
+impl ToMolecule for super::Const {
+    type Entity = mol::Const;
+    fn to_molecule(&self) -> Result<Self::Entity, String> {
+        Ok(mol::Const::new_builder()
+            .name(string(&self.name))
+            .docs(string_vec(&self.docs))
+            .attributes(attributes(&self.attributes)?)
+            .item(string(&self.typ))
+            .value(bytes(&self.value)?)
+            .imported_depth(uint64(self.imported_depth as u64))
+            .build())
+    }
+}
+
+impl<'r> FromMolecule<'r> for super::Const {
+    type Reader = mol::ConstReader<'r>;
+    fn from_molecule(reader: Self::Reader) -> Self {
+        Self {
+            name: owned_string(reader.name()),
+            docs: owned_string_vec(reader.docs()),
+            attributes: attributes_of(reader.attributes()),
+            typ: owned_string(reader.item()),
+            value: hex(reader.value()),
+            imported_depth: usize_of(reader.imported_depth()),
+        }
+    }
+}
+
+impl ToMolecule for super::TopDecl {
+    type Entity = mol::TopDecl;
+    fn to_molecule(&self) -> Result<Self::Entity, String> {
+        let inner = match self {
+            Self::Option_(inner) => mol::TopDeclUnion::from(inner.to_molecule()?),
+            Self::Union(inner) => inner.to_molecule()?.into(),
+            Self::Array(inner) => inner.to_molecule()?.into(),
+            Self::Struct(inner) => inner.to_molecule()?.into(),
+            Self::FixVec(inner) => inner.to_molecule()?.into(),
+            Self::DynVec(inner) => inner.to_molecule()?.into(),
+            Self::Table(inner) => inner.to_molecule()?.into(),
+            Self::Enum(inner) => inner.to_molecule()?.into(),
+        };
+        Ok(mol::TopDecl::new_builder().set(inner).build())
+    }
+}
+
+impl<'r> FromMolecule<'r> for super::TopDecl {
+    type Reader = mol::TopDeclReader<'r>;
+    fn from_molecule(reader: Self::Reader) -> Self {
+        match reader.to_enum() {
+            mol::TopDeclUnionReader::OptionDecl(inner) => {
+                Self::Option_(FromMolecule::from_molecule(inner))
+            }
+            mol::TopDeclUnionReader::UnionDecl(inner) => {
+                Self::Union(FromMolecule::from_molecule(inner))
+            }
+            mol::TopDeclUnionReader::ArrayDecl(inner) => {
+                Self::Array(FromMolecule::from_molecule(inner))
+            }
+            mol::TopDeclUnionReader::StructDecl(inner) => {
+                Self::Struct(FromMolecule::from_molecule(inner))
+            }
+            mol::TopDeclUnionReader::FixVecDecl(inner) => {
+                Self::FixVec(FromMolecule::from_molecule(inner))
+            }
+            mol::TopDeclUnionReader::DynVecDecl(inner) => {
+                Self::DynVec(FromMolecule::from_molecule(inner))
+            }
+            mol::TopDeclUnionReader::TableDecl(inner) => {
+                Self::Table(FromMolecule::from_molecule(inner))
+            }
+            mol::TopDeclUnionReader::EnumDecl(inner) => {
+                Self::Enum(FromMolecule::from_molecule(inner))
+            }
+        }
+    }
+}
+
+impl ToMolecule for super::Option_ {
+    type Entity = mol::OptionDecl;
+    fn to_molecule(&self) -> Result<Self::Entity, String> {
+        Ok(mol::OptionDecl::new_builder()
+            .name(string(&self.name))
+            .docs(string_vec(&self.docs))
+            .attributes(attributes(&self.attributes)?)
+            .item(string(self.item.typ()))
+            .imported_depth(uint64(self.imported_depth as u64))
+            .layout(layout(self.layout.as_ref())?)
+            .build())
+    }
+}
+
+impl<'r> FromMolecule<'r> for super::Option_ {
+    type Reader = mol::OptionDeclReader<'r>;
+    fn from_molecule(reader: Self::Reader) -> Self {
+        Self {
+            name: owned_string(reader.name()),
+            docs: owned_string_vec(reader.docs()),
+            attributes: attributes_of(reader.attributes()),
+            item: super::ItemDecl {
+                typ: owned_string(reader.item()),
+            },
+            imported_depth: usize_of(reader.imported_depth()),
+            layout: layout_of(reader.layout()),
+        }
+    }
+}
+
+impl ToMolecule for super::Union {
+    type Entity = mol::UnionDecl;
+    fn to_molecule(&self) -> Result<Self::Entity, String> {
+        let items = mol::UnionItemDeclVec::new_builder()
+            .extend(entities(&self.items)?)
+            .build();
+        Ok(mol::UnionDecl::new_builder()
+            .name(string(&self.name))
+            .docs(string_vec(&self.docs))
+            .attributes(attributes(&self.attributes)?)
+            .items(items)
+            .imported_depth(uint64(self.imported_depth as u64))
+            .layout(layout(self.layout.as_ref())?)
+            .build())
+    }
+}
+
+impl<'r> FromMolecule<'r> for super::Union {
+    type Reader = mol::UnionDeclReader<'r>;
+    fn from_molecule(reader: Self::Reader) -> Self {
+        Self {
+            name: owned_string(reader.name()),
+            docs: owned_string_vec(reader.docs()),
+            attributes: attributes_of(reader.attributes()),
+            items: reader
+                .items()
+                .iter()
+                .map(FromMolecule::from_molecule)
+                .collect(),
+            imported_depth: usize_of(reader.imported_depth()),
+            layout: layout_of(reader.layout()),
+        }
+    }
+}
+
+impl ToMolecule for super::UnionItemDecl {
+    type Entity = mol::UnionItemDecl;
+    fn to_molecule(&self) -> Result<Self::Entity, String> {
+        Ok(mol::UnionItemDecl::new_builder()
+            .name(string_opt(self.name()))
+            .docs(string_vec(self.docs()))
+            .attributes(attributes(self.attributes())?)
+            .item(string(self.typ()))
+            .id(usize_opt(self.id()))
+            .build())
+    }
+}
+
+impl<'r> FromMolecule<'r> for super::UnionItemDecl {
+    type Reader = mol::UnionItemDeclReader<'r>;
+    fn from_molecule(reader: Self::Reader) -> Self {
+        let name = owned_string_opt(reader.name());
+        let docs = owned_string_vec(reader.docs());
+        let attributes = attributes_of(reader.attributes());
+        let typ = owned_string(reader.item());
+        let id = usize_opt_of(reader.id());
+        if name.is_none() && docs.is_empty() && attributes.is_empty() && id.is_none() {
+            Self::Unnamed(super::ItemDecl { typ })
+        } else {
+            Self::Detailed(super::DetailedUnionItemDecl {
+                name,
+                docs,
+                attributes,
+                typ,
+                id,
+            })
+        }
+    }
+}
+
+impl ToMolecule for super::Array {
+    type Entity = mol::ArrayDecl;
+    fn to_molecule(&self) -> Result<Self::Entity, String> {
+        Ok(mol::ArrayDecl::new_builder()
+            .name(string(&self.name))
+            .docs(string_vec(&self.docs))
+            .attributes(attributes(&self.attributes)?)
+            .item(string(self.item.typ()))
+            .item_count(uint64(self.item_count as u64))
+            .builtin(string_opt(self.builtin.as_ref().map(AsRef::as_ref)))
+            .constraints(self.constraints.to_molecule()?)
+            .imported_depth(uint64(self.imported_depth as u64))
+            .layout(layout(self.layout.as_ref())?)
+            .build())
+    }
+}
+
+impl<'r> FromMolecule<'r> for super::Array {
+    type Reader = mol::ArrayDeclReader<'r>;
+    fn from_molecule(reader: Self::Reader) -> Self {
+        Self {
+            name: owned_string(reader.name()),
+            docs: owned_string_vec(reader.docs()),
+            attributes: attributes_of(reader.attributes()),
+            item: super::ItemDecl {
+                typ: owned_string(reader.item()),
+            },
+            item_count: usize_of(reader.item_count()),
+            builtin: owned_string_opt(reader.builtin()),
+            constraints: FromMolecule::from_molecule(reader.constraints()),
+            imported_depth: usize_of(reader.imported_depth()),
+            layout: layout_of(reader.layout()),
+        }
+    }
+}
+
+impl ToMolecule for super::Struct {
+    type Entity = mol::StructDecl;
+    fn to_molecule(&self) -> Result<Self::Entity, String> {
+        Ok(mol::StructDecl::new_builder()
+            .name(string(&self.name))
+            .docs(string_vec(&self.docs))
+            .attributes(attributes(&self.attributes)?)
+            .fields(field_decls(&self.fields)?)
+            .imported_depth(uint64(self.imported_depth as u64))
+            .layout(layout(self.layout.as_ref())?)
+            .build())
+    }
+}
+
+impl<'r> FromMolecule<'r> for super::Struct {
+    type Reader = mol::StructDeclReader<'r>;
+    fn from_molecule(reader: Self::Reader) -> Self {
+        Self {
+            name: owned_string(reader.name()),
+            docs: owned_string_vec(reader.docs()),
+            attributes: attributes_of(reader.attributes()),
+            fields: field_decls_of(reader.fields()),
+            imported_depth: usize_of(reader.imported_depth()),
+            layout: layout_of(reader.layout()),
+        }
+    }
+}
+
+impl ToMolecule for super::FixVec {
+    type Entity = mol::FixVecDecl;
+    fn to_molecule(&self) -> Result<Self::Entity, String> {
+        Ok(mol::FixVecDecl::new_builder()
+            .name(string(&self.name))
+            .docs(string_vec(&self.docs))
+            .attributes(attributes(&self.attributes)?)
+            .item(string(self.item.typ()))
+            .builtin(string_opt(self.builtin.as_ref().map(AsRef::as_ref)))
+            .map(self.map.into())
+            .constraints(self.constraints.to_molecule()?)
+            .imported_depth(uint64(self.imported_depth as u64))
+            .layout(layout(self.layout.as_ref())?)
+            .build())
+    }
+}
+
+impl<'r> FromMolecule<'r> for super::FixVec {
+    type Reader = mol::FixVecDeclReader<'r>;
+    fn from_molecule(reader: Self::Reader) -> Self {
+        Self {
+            name: owned_string(reader.name()),
+            docs: owned_string_vec(reader.docs()),
+            attributes: attributes_of(reader.attributes()),
+            item: super::ItemDecl {
+                typ: owned_string(reader.item()),
+            },
+            builtin: owned_string_opt(reader.builtin()),
+            map: reader.map().get_bool(),
+            constraints: FromMolecule::from_molecule(reader.constraints()),
+            imported_depth: usize_of(reader.imported_depth()),
+            layout: layout_of(reader.layout()),
+        }
+    }
+}
+
+impl ToMolecule for super::DynVec {
+    type Entity = mol::DynVecDecl;
+    fn to_molecule(&self) -> Result<Self::Entity, String> {
+        Ok(mol::DynVecDecl::new_builder()
+            .name(string(&self.name))
+            .docs(string_vec(&self.docs))
+            .attributes(attributes(&self.attributes)?)
+            .item(string(self.item.typ()))
+            .map(self.map.into())
+            .constraints(self.constraints.to_molecule()?)
+            .imported_depth(uint64(self.imported_depth as u64))
+            .layout(layout(self.layout.as_ref())?)
+            .build())
+    }
+}
+
+impl<'r> FromMolecule<'r> for super::DynVec {
+    type Reader = mol::DynVecDeclReader<'r>;
+    fn from_molecule(reader: Self::Reader) -> Self {
+        Self {
+            name: owned_string(reader.name()),
+            docs: owned_string_vec(reader.docs()),
+            attributes: attributes_of(reader.attributes()),
+            item: super::ItemDecl {
+                typ: owned_string(reader.item()),
+            },
+            map: reader.map().get_bool(),
+            constraints: FromMolecule::from_molecule(reader.constraints()),
+            imported_depth: usize_of(reader.imported_depth()),
+            layout: layout_of(reader.layout()),
+        }
+    }
+}
+
+impl ToMolecule for super::Table {
+    type Entity = mol::TableDecl;
+    fn to_molecule(&self) -> Result<Self::Entity, String> {
+        Ok(mol::TableDecl::new_builder()
+            .name(string(&self.name))
+            .docs(string_vec(&self.docs))
+            .attributes(attributes(&self.attributes)?)
+            .fields(field_decls(&self.fields)?)
+            .imported_depth(uint64(self.imported_depth as u64))
+            .layout(layout(self.layout.as_ref())?)
+            .build())
+    }
+}
+
+impl<'r> FromMolecule<'r> for super::Table {
+    type Reader = mol::TableDeclReader<'r>;
+    fn from_molecule(reader: Self::Reader) -> Self {
+        Self {
+            name: owned_string(reader.name()),
+            docs: owned_string_vec(reader.docs()),
+            attributes: attributes_of(reader.attributes()),
+            fields: field_decls_of(reader.fields()),
+            imported_depth: usize_of(reader.imported_depth()),
+            layout: layout_of(reader.layout()),
+        }
+    }
+}
+
+impl ToMolecule for super::Enum {
+    type Entity = mol::EnumDecl;
+    fn to_molecule(&self) -> Result<Self::Entity, String> {
+        let items = mol::EnumItemDeclVec::new_builder()
+            .extend(entities(&self.items)?)
+            .build();
+        Ok(mol::EnumDecl::new_builder()
+            .name(string(&self.name))
+            .docs(string_vec(&self.docs))
+            .attributes(attributes(&self.attributes)?)
+            .size(uint64(self.size as u64))
+            .items(items)
+            .imported_depth(uint64(self.imported_depth as u64))
+            .layout(layout(self.layout.as_ref())?)
+            .build())
+    }
+}
+
+impl<'r> FromMolecule<'r> for super::Enum {
+    type Reader = mol::EnumDeclReader<'r>;
+    fn from_molecule(reader: Self::Reader) -> Self {
+        Self {
+            name: owned_string(reader.name()),
+            docs: owned_string_vec(reader.docs()),
+            attributes: attributes_of(reader.attributes()),
+            size: usize_of(reader.size()),
+            items: reader
+                .items()
+                .iter()
+                .map(FromMolecule::from_molecule)
+                .collect(),
+            imported_depth: usize_of(reader.imported_depth()),
+            layout: layout_of(reader.layout()),
+        }
+    }
+}
+
+impl ToMolecule for super::EnumItemDecl {
+    type Entity = mol::EnumItemDecl;
+    fn to_molecule(&self) -> Result<Self::Entity, String> {
+        Ok(mol::EnumItemDecl::new_builder()
+            .name(string(&self.name))
+            .docs(string_vec(&self.docs))
+            .attributes(attributes(&self.attributes)?)
+            .value(uint64(self.value))
+            .build())
+    }
+}
+
+impl<'r> FromMolecule<'r> for super::EnumItemDecl {
+    type Reader = mol::EnumItemDeclReader<'r>;
+    fn from_molecule(reader: Self::Reader) -> Self {
+        Self {
+            name: owned_string(reader.name()),
+            docs: owned_string_vec(reader.docs()),
+            attributes: attributes_of(reader.attributes()),
+            value: reader.value().get_u64(),
+        }
+    }
+}
+
+impl ToMolecule for super::FieldDecl {
+    type Entity = mol::FieldDecl;
+    fn to_molecule(&self) -> Result<Self::Entity, String> {
+        let default_value = mol::BytesOpt::new_builder()
+            .set(
+                self.default
+                    .as_ref()
+                    .map(|value| bytes(value))
+                    .transpose()?,
+            )
+            .build();
+        Ok(mol::FieldDecl::new_builder()
+            .name(string(&self.name))
+            .docs(string_vec(&self.docs))
+            .attributes(attributes(&self.attributes)?)
+            .item(string(&self.typ))
+            .default_value(default_value)
+            .build())
+    }
+}
+
+impl<'r> FromMolecule<'r> for super::FieldDecl {
+    type Reader = mol::FieldDeclReader<'r>;
+    fn from_molecule(reader: Self::Reader) -> Self {
+        Self {
+            name: owned_string(reader.name()),
+            docs: owned_string_vec(reader.docs()),
+            attributes: attributes_of(reader.attributes()),
+            typ: owned_string(reader.item()),
+            default: reader.default_value().to_opt().map(hex),
+        }
+    }
+}
+
+impl ToMolecule for super::Attribute {
+    type Entity = mol::Attribute;
+    fn to_molecule(&self) -> Result<Self::Entity, String> {
+        Ok(mol::Attribute::new_builder()
+            .name(string(&self.name))
+            .value(string_opt(self.value.as_ref().map(AsRef::as_ref)))
+            .args(attributes(&self.args)?)
+            .build())
+    }
+}
+
+impl<'r> FromMolecule<'r> for super::Attribute {
+    type Reader = mol::AttributeReader<'r>;
+    fn from_molecule(reader: Self::Reader) -> Self {
+        Self {
+            name: owned_string(reader.name()),
+            value: owned_string_opt(reader.value()),
+            args: attributes_of(reader.args()),
+        }
+    }
+}
+
+impl ToMolecule for super::Constraints {
+    type Entity = mol::Constraints;
+    fn to_molecule(&self) -> Result<Self::Entity, String> {
+        Ok(mol::Constraints::new_builder()
+            .max_items(usize_opt(self.max_items))
+            .non_empty(self.non_empty.into())
+            .min_value(uint64_opt(self.min_value))
+            .max_value(uint64_opt(self.max_value))
+            .build())
+    }
+}
+
+impl<'r> FromMolecule<'r> for super::Constraints {
+    type Reader = mol::ConstraintsReader<'r>;
+    fn from_molecule(reader: Self::Reader) -> Self {
+        Self {
+            max_items: usize_opt_of(reader.max_items()),
+            non_empty: reader.non_empty().get_bool(),
+            min_value: reader.min_value().to_opt().map(|value| value.get_u64()),
+            max_value: reader.max_value().to_opt().map(|value| value.get_u64()),
+        }
+    }
+}
+
+impl ToMolecule for super::Layout {
+    type Entity = mol::Layout;
+    fn to_molecule(&self) -> Result<Self::Entity, String> {
+        let field_offsets = mol::Uint64Vec::new_builder()
+            .extend(
+                self.field_offsets
+                    .iter()
+                    .map(|offset| uint64(*offset as u64)),
+            )
+            .build();
+        Ok(mol::Layout::new_builder()
+            .is_fixed_size(self.is_fixed_size.into())
+            .size(usize_opt(self.total_size))
+            .item_size(usize_opt(self.item_size))
+            .field_offsets(field_offsets)
+            .default_value(bytes(&self.default)?)
+            .build())
+    }
+}
+
+impl<'r> FromMolecule<'r> for super::Layout {
+    type Reader = mol::LayoutReader<'r>;
+    fn from_molecule(reader: Self::Reader) -> Self {
+        Self {
+            is_fixed_size: reader.is_fixed_size().get_bool(),
+            total_size: usize_opt_of(reader.size()),
+            item_size: usize_opt_of(reader.item_size()),
+            field_offsets: reader.field_offsets().iter().map(usize_of).collect(),
+            default: hex(reader.default_value()),
+        }
+    }
+}
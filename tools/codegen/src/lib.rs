@@ -1,20 +1,27 @@
 #![recursion_limit = "256"]
 
 pub mod ast;
+pub(crate) mod compat;
 pub(crate) mod compiler;
+pub(crate) mod error;
 pub(crate) mod generator;
 pub(crate) mod parser;
 pub(crate) mod utils;
 
 #[cfg(feature = "compiler-plugin")]
-mod ir;
+pub mod ir;
+#[cfg(feature = "compiler-plugin")]
+pub mod plugin;
 
+pub use compat::{check_compatibility, Change, Compatibility, CompatibilityReport};
 pub use compiler::Compiler;
+pub use error::{Error, Location};
 pub use generator::Language;
-pub use parser::Parser;
+pub use parser::{EmbeddedResolver, FileResolver, MemoryResolver, Parser, Resolver};
 
 #[cfg(feature = "compiler-plugin")]
 pub use ir::Format as IntermediateFormat;
 
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
-pub const C_API_VERSION_MIN: &str = "0.7.0";
+pub const C_API_VERSION_MIN: &str = "0.8.0";
+pub const RUST_API_VERSION_MIN: &str = "0.8.0";
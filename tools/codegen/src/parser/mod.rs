@@ -1,15 +1,73 @@
 use std::path::Path;
 
-use crate::{ast, utils::ParserUtils as _};
+use crate::{
+    ast,
+    error::{Error, Result},
+    utils::ParserUtils as _,
+};
 
 mod inner;
+mod resolver;
 pub(crate) use inner::{Parser as InnerParser, Rule};
+pub use resolver::{EmbeddedResolver, FileResolver, MemoryResolver, Resolver};
 
-pub struct Parser;
+/// The parser of schemas.
+///
+/// `Parser::parse` and `Parser::parse_sources` use the default options, build a
+/// parser by `Parser::new` to change them.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Parser {
+    allow_identical_redeclarations: bool,
+}
 
 impl Parser {
-    pub fn parse<P: AsRef<Path>>(path: &P) -> ast::Ast {
-        let ast_raw = Self::preprocess(path).unwrap();
-        ast::Ast::complete(ast_raw)
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows a name to be declared more than once (e.g. in different imported
+    /// schemas), as long as all the declarations are identical.
+    pub fn allow_identical_redeclarations(&mut self, allow: bool) -> &mut Self {
+        self.allow_identical_redeclarations = allow;
+        self
+    }
+
+    pub fn parse<P: AsRef<Path>>(path: &P) -> Result<ast::Ast> {
+        Self::default().parse_schema_file(path, &FileResolver::default())
+    }
+
+    /// Parses the root schema from `source`, and loads its imports by `resolver`.
+    ///
+    /// The `path` of the root schema is used as the namespace and as the base of
+    /// relative imports, it doesn't have to exist in the file system.
+    pub fn parse_sources<P: AsRef<Path>, R: Resolver>(
+        path: &P,
+        source: &str,
+        resolver: &R,
+    ) -> Result<ast::Ast> {
+        Self::default().parse_schema_sources(path, source, resolver)
+    }
+
+    /// Loads the root schema and its imports by `resolver`, then parses them.
+    pub fn parse_schema_file<P: AsRef<Path>, R: Resolver>(
+        &self,
+        path: &P,
+        resolver: &R,
+    ) -> Result<ast::Ast> {
+        let source = resolver
+            .load(path.as_ref())
+            .map_err(|err| Error::Io(Some(path.as_ref().to_path_buf()), err))?;
+        self.parse_schema_sources(path, &source, resolver)
+    }
+
+    /// Same as `Parser::parse_sources`, but with the options of this parser.
+    pub fn parse_schema_sources<P: AsRef<Path>, R: Resolver>(
+        &self,
+        path: &P,
+        source: &str,
+        resolver: &R,
+    ) -> Result<ast::Ast> {
+        let ast_raw = Self::preprocess(path, source, resolver)?;
+        ast::Ast::complete(ast_raw, self.allow_identical_redeclarations)
     }
 }
@@ -0,0 +1,184 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Component, Path, PathBuf},
+};
+
+use same_file::is_same_file;
+
+/// Locates and loads the schemas which are imported by other schemas.
+pub trait Resolver {
+    /// Returns the path of the schema which is imported by `importer` as `import`.
+    ///
+    /// The `import` path is relative to the directory of the importer, and it
+    /// already has the `.mol` extension.
+    fn resolve(&self, importer: &Path, import: &Path) -> io::Result<PathBuf>;
+
+    /// Loads the content of a schema.
+    fn load(&self, path: &Path) -> io::Result<String>;
+
+    /// Checks whether two paths refer to the same schema.
+    fn is_same(&self, lhs: &Path, rhs: &Path) -> io::Result<bool> {
+        Ok(normalize(lhs) == normalize(rhs))
+    }
+}
+
+/// Resolves schemas in the file system.
+///
+/// Imports are relative to the importing file at first, then the import paths
+/// are searched in order.
+#[derive(Debug, Default, Clone)]
+pub struct FileResolver {
+    import_paths: Vec<PathBuf>,
+}
+
+/// Resolves schemas in a map from paths to contents.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryResolver {
+    sources: HashMap<PathBuf, String>,
+}
+
+/// Resolves schemas in a static bundle, which is usually built by `include_str!`.
+#[derive(Debug, Clone, Copy)]
+pub struct EmbeddedResolver {
+    sources: &'static [(&'static str, &'static str)],
+}
+
+impl FileResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a directory to search for imported schemas.
+    pub fn import_path<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        self.import_paths.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    pub fn import_paths(&self) -> &[PathBuf] {
+        &self.import_paths
+    }
+}
+
+impl Resolver for FileResolver {
+    fn resolve(&self, importer: &Path, import: &Path) -> io::Result<PathBuf> {
+        let mut path_buf = importer.to_path_buf();
+        path_buf.pop();
+        path_buf.push(import);
+        if self.import_paths.is_empty() || path_buf.is_file() {
+            return Ok(path_buf);
+        }
+        for import_path in &self.import_paths {
+            let path_buf = import_path.join(import);
+            if path_buf.is_file() {
+                return Ok(path_buf);
+            }
+        }
+        let message = format!(
+            "`{}` is neither relative to `{}` nor in the import paths",
+            import.display(),
+            importer.display()
+        );
+        Err(io::Error::new(io::ErrorKind::NotFound, message))
+    }
+
+    fn load(&self, path: &Path) -> io::Result<String> {
+        fs::read_to_string(path)
+    }
+
+    fn is_same(&self, lhs: &Path, rhs: &Path) -> io::Result<bool> {
+        if lhs.exists() && rhs.exists() {
+            is_same_file(lhs, rhs)
+        } else {
+            Ok(normalize(lhs) == normalize(rhs))
+        }
+    }
+}
+
+impl MemoryResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert<P: AsRef<Path>, S: Into<String>>(&mut self, path: P, source: S) -> &mut Self {
+        self.sources.insert(normalize(path.as_ref()), source.into());
+        self
+    }
+}
+
+impl Resolver for MemoryResolver {
+    fn resolve(&self, importer: &Path, import: &Path) -> io::Result<PathBuf> {
+        resolve_in(importer, import, |path| self.sources.contains_key(path))
+    }
+
+    fn load(&self, path: &Path) -> io::Result<String> {
+        self.sources
+            .get(&normalize(path))
+            .cloned()
+            .ok_or_else(|| not_found(path))
+    }
+}
+
+impl EmbeddedResolver {
+    pub const fn new(sources: &'static [(&'static str, &'static str)]) -> Self {
+        Self { sources }
+    }
+
+    fn find(&self, path: &Path) -> Option<&'static str> {
+        self.sources
+            .iter()
+            .find(|(p, _)| normalize(Path::new(p)) == path)
+            .map(|(_, source)| *source)
+    }
+}
+
+impl Resolver for EmbeddedResolver {
+    fn resolve(&self, importer: &Path, import: &Path) -> io::Result<PathBuf> {
+        resolve_in(importer, import, |path| self.find(path).is_some())
+    }
+
+    fn load(&self, path: &Path) -> io::Result<String> {
+        self.find(&normalize(path))
+            .map(ToOwned::to_owned)
+            .ok_or_else(|| not_found(path))
+    }
+}
+
+fn resolve_in<F>(importer: &Path, import: &Path, exists: F) -> io::Result<PathBuf>
+where
+    F: Fn(&Path) -> bool,
+{
+    let mut path_buf = importer.to_path_buf();
+    path_buf.pop();
+    path_buf.push(import);
+    let path_buf = normalize(&path_buf);
+    if exists(&path_buf) {
+        Ok(path_buf)
+    } else {
+        Err(not_found(&path_buf))
+    }
+}
+
+fn not_found(path: &Path) -> io::Error {
+    let message = format!("no source for `{}`", path.display());
+    io::Error::new(io::ErrorKind::NotFound, message)
+}
+
+// Removes `.` and folds `..` without accessing the file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut components: Vec<Component> = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match components.last() {
+                Some(Component::Normal(_)) => {
+                    components.pop();
+                }
+                Some(Component::RootDir) | Some(Component::Prefix(_)) => {}
+                _ => components.push(component),
+            },
+            _ => components.push(component),
+        }
+    }
+    components.iter().collect()
+}
@@ -0,0 +1,248 @@
+//! The protocol between the compiler and the plugins.
+//!
+//! The compiler runs `moleculec-<lang> --handshake` at first, the plugin prints a [`Handshake`]
+//! as JSON. Then the compiler runs `moleculec-<lang> --protocol <version>` with the options
+//! `--option <key=value>`, and writes the intermediate data to its stdin. The plugin writes the
+//! code to stdout, or a [`Manifest`] of several files if the handshake says so, and the
+//! [`Diagnostic`]s to stderr, one JSON object per line.
+//!
+//! If a plugin doesn't print a valid handshake, it's an old plugin: the compiler runs
+//! `moleculec-<lang> --format` to get the format, and only checks the exit code of the plugin.
+
+use std::{convert::TryFrom as _, fmt};
+
+use property::Property;
+use serde::{Deserialize, Serialize};
+
+use crate::ir::Format;
+
+/// The version of the protocol between the compiler and the plugins.
+pub const PLUGIN_PROTOCOL_VERSION: u32 = 1;
+
+/// The version of the intermediate data, the plugins should support the same major version.
+pub const INTERMEDIATE_VERSION: &str = "1.0.0";
+
+/// The optional contents of the intermediate data which a plugin supports.
+///
+/// The doc comments and the attributes are removed if the plugin doesn't support them, and the
+/// layout can't be required.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feature {
+    DocComments,
+    Attributes,
+    Layout,
+}
+
+/// What a plugin supports, it's printed by the plugin when it's run with `--handshake`.
+///
+/// The unknown formats and features are ignored, so the plugins could support newer ones.
+#[derive(Debug, Clone, Property, Deserialize, Serialize)]
+#[property(get(public))]
+pub struct Handshake {
+    protocol: u32,
+    intermediate_version: String,
+    /// The formats of the intermediate data, the preferred one is the first.
+    formats: Vec<String>,
+    #[serde(default)]
+    features: Vec<String>,
+    /// Whether the plugin outputs a manifest of files instead of the code.
+    #[serde(default)]
+    manifest: bool,
+}
+
+/// The files generated by a plugin, the compiler writes them into the output directory.
+#[derive(Debug, Clone, Default, Property, Deserialize, Serialize)]
+#[property(get(public))]
+pub struct Manifest {
+    files: Vec<OutputFile>,
+}
+
+#[derive(Debug, Clone, Property, Deserialize, Serialize)]
+#[property(get(public))]
+pub struct OutputFile {
+    /// The path relative to the output directory, it can't go out of the output directory.
+    path: String,
+    content: String,
+}
+
+/// A diagnostic reported by a plugin.
+#[derive(Debug, Clone, Property, Deserialize, Serialize)]
+#[property(get(public))]
+pub struct Diagnostic {
+    level: Level,
+    message: String,
+    /// The name of the declaration which the diagnostic is about.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    declaration: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    Error,
+    Warning,
+    Note,
+}
+
+impl Feature {
+    pub fn all() -> Vec<Self> {
+        vec![Self::DocComments, Self::Attributes, Self::Layout]
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::DocComments => "doc-comments",
+            Self::Attributes => "attributes",
+            Self::Layout => "layout",
+        }
+    }
+}
+
+impl Handshake {
+    pub fn new(formats: &[Format], features: &[Feature]) -> Self {
+        Self {
+            protocol: PLUGIN_PROTOCOL_VERSION,
+            intermediate_version: INTERMEDIATE_VERSION.to_owned(),
+            formats: formats
+                .iter()
+                .map(|format| format.to_string().to_lowercase())
+                .collect(),
+            features: features
+                .iter()
+                .map(|feature| feature.as_str().to_owned())
+                .collect(),
+            manifest: false,
+        }
+    }
+
+    pub fn with_manifest(mut self, manifest: bool) -> Self {
+        self.manifest = manifest;
+        self
+    }
+
+    pub fn from_json(bytes: &[u8]) -> Result<Self, String> {
+        serde_json::from_slice(bytes).map_err(|err| format!("invalid handshake: {}", err))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    pub fn supports(&self, feature: Feature) -> bool {
+        self.features.iter().any(|name| name == feature.as_str())
+    }
+
+    pub fn supported_features(&self) -> Vec<Feature> {
+        Feature::all()
+            .into_iter()
+            .filter(|feature| self.supports(*feature))
+            .collect()
+    }
+
+    /// Checks whether the plugin works with the compiler, then chooses the first format which
+    /// the compiler supports.
+    pub fn negotiate(&self) -> Result<Format, String> {
+        if self.protocol != PLUGIN_PROTOCOL_VERSION {
+            return Err(format!(
+                "the plugin uses the protocol version {}, but the compiler uses {}",
+                self.protocol, PLUGIN_PROTOCOL_VERSION
+            ));
+        }
+        let version = semver::Version::parse(&self.intermediate_version).map_err(|err| {
+            format!(
+                "the intermediate version `{}` of the plugin is invalid: {}",
+                self.intermediate_version, err
+            )
+        })?;
+        let expected = semver::Version::parse(INTERMEDIATE_VERSION).unwrap();
+        if version.major != expected.major {
+            return Err(format!(
+                "the plugin supports the intermediate data {}, but the compiler generates {}",
+                version, expected
+            ));
+        }
+        self.formats
+            .iter()
+            .find_map(|name| Format::try_from(name.as_str()).ok())
+            .ok_or_else(|| {
+                format!(
+                    "the plugin doesn't support any format of the compiler: [{}]",
+                    self.formats.join(", ")
+                )
+            })
+    }
+}
+
+impl Manifest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_file(&mut self, path: &str, content: String) -> &mut Self {
+        self.files.push(OutputFile {
+            path: path.to_owned(),
+            content,
+        });
+        self
+    }
+
+    pub fn from_json(bytes: &[u8]) -> Result<Self, String> {
+        serde_json::from_slice(bytes).map_err(|err| format!("invalid manifest: {}", err))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+impl Diagnostic {
+    pub fn new(level: Level, message: String) -> Self {
+        Self {
+            level,
+            message,
+            declaration: None,
+        }
+    }
+
+    pub fn error(message: String) -> Self {
+        Self::new(Level::Error, message)
+    }
+
+    pub fn warning(message: String) -> Self {
+        Self::new(Level::Warning, message)
+    }
+
+    pub fn with_declaration(mut self, name: &str) -> Self {
+        self.declaration = Some(name.to_owned());
+        self
+    }
+
+    /// Parses a line which the plugin writes to stderr, returns `None` if it isn't a diagnostic.
+    pub fn from_json(line: &str) -> Option<Self> {
+        serde_json::from_str(line).ok()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warning => write!(f, "warning"),
+            Self::Note => write!(f, "note"),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.level, self.message)?;
+        if let Some(ref name) = self.declaration {
+            write!(f, "\n  = note: in the declaration `{}`", name)?;
+        }
+        Ok(())
+    }
+}
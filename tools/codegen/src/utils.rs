@@ -1,15 +1,32 @@
 use std::path::Path;
 
-use pest::error::Error as PestError;
-
-use crate::{ast::raw as ast, parser};
+use crate::{
+    ast::raw as ast,
+    error::{Location, Result},
+    parser::Resolver,
+};
 
 pub(crate) trait PairsUtils {
+    fn peek_location<P: AsRef<Path>>(&mut self, path: &P) -> Location;
     fn next_string(&mut self) -> String;
-    fn next_usize(&mut self) -> usize;
-    fn next_item(&mut self) -> ast::ItemDecl;
-    fn next_items(&mut self) -> Vec<ast::ItemDecl>;
-    fn next_fields(&mut self) -> Vec<ast::FieldDecl>;
+    fn next_usize<P: AsRef<Path>>(&mut self, path: &P) -> Result<usize>;
+    fn next_annotations<P: AsRef<Path>>(&mut self, path: &P) -> (Vec<String>, Vec<ast::Attribute>);
+    fn next_item<P: AsRef<Path>>(
+        &mut self,
+        path: &P,
+        anonymous: &mut ast::AnonymousDecls,
+    ) -> Result<ast::ItemDecl>;
+    fn next_union_items<P: AsRef<Path>>(
+        &mut self,
+        path: &P,
+        anonymous: &mut ast::AnonymousDecls,
+    ) -> Result<Vec<ast::UnionItemDecl>>;
+    fn next_enum_items<P: AsRef<Path>>(&mut self, path: &P) -> Vec<ast::EnumItemDecl>;
+    fn next_fields<P: AsRef<Path>>(
+        &mut self,
+        path: &P,
+        anonymous: &mut ast::AnonymousDecls,
+    ) -> Result<Vec<ast::FieldDecl>>;
     fn next_import<P: AsRef<Path>>(
         &mut self,
         imported_base: &P,
@@ -19,5 +36,9 @@ pub(crate) trait PairsUtils {
 }
 
 pub(crate) trait ParserUtils {
-    fn preprocess<P: AsRef<Path>>(path: &P) -> Result<ast::Ast, PestError<parser::Rule>>;
+    fn preprocess<P: AsRef<Path>, R: Resolver>(
+        path: &P,
+        source: &str,
+        resolver: &R,
+    ) -> Result<ast::Ast>;
 }
@@ -1,9 +1,13 @@
 #[macro_use]
 extern crate clap;
 
-use std::{convert::TryFrom, process, str};
+use std::{convert::TryFrom, path::Path, process, str};
 
-use molecule_codegen::{Compiler, IntermediateFormat};
+use molecule_codegen::{
+    check_compatibility,
+    plugin::{Feature, Handshake, PLUGIN_PROTOCOL_VERSION},
+    Compiler, FileResolver, IntermediateFormat, Parser,
+};
 
 pub(crate) mod config {
     use std::{convert::TryFrom, path::PathBuf, process};
@@ -17,28 +21,78 @@ pub(crate) mod config {
 
     pub(crate) struct AppConfig {
         pub(crate) schema_file: PathBuf,
+        pub(crate) include_dirs: Vec<PathBuf>,
         pub(crate) output_config: OutputConfig,
+        pub(crate) with_layout: bool,
+        pub(crate) plugin_options: Vec<String>,
+        pub(crate) output_dir: Option<PathBuf>,
     }
 
-    pub(crate) fn build_commandline() -> AppConfig {
+    pub(crate) struct CompatConfig {
+        pub(crate) old_schema_file: PathBuf,
+        pub(crate) new_schema_file: PathBuf,
+        pub(crate) include_dirs: Vec<PathBuf>,
+    }
+
+    pub(crate) enum Command {
+        Compile(AppConfig),
+        Compat(CompatConfig),
+    }
+
+    pub(crate) fn build_commandline() -> Command {
         let yaml = clap::load_yaml!("cli/compiler.yaml");
         let matches = clap::App::from_yaml(yaml)
             .version(clap::crate_version!())
             .get_matches();
-        AppConfig::from(&matches)
+        if let Some(matches) = matches.subcommand_matches("compat") {
+            Command::Compat(CompatConfig::from(matches))
+        } else {
+            Command::Compile(AppConfig::from(&matches))
+        }
     }
 
-    impl<'a> From<&'a clap::ArgMatches<'a>> for AppConfig {
-        fn from(matches: &'a clap::ArgMatches) -> Self {
-            let schema_file = value_t_or_exit!(matches, "schema-file", PathBuf);
-            let language = value_t_or_exit!(matches, "language", String);
-            if !schema_file.as_path().is_file() {
+    fn schema_file(matches: &clap::ArgMatches, name: &str) -> PathBuf {
+        let schema_file = value_t_or_exit!(matches, name, PathBuf);
+        if !schema_file.as_path().is_file() {
+            eprintln!(
+                "Error: {} [{}] should be a file",
+                name,
+                schema_file.to_str().unwrap()
+            );
+            process::exit(1);
+        }
+        schema_file
+    }
+
+    fn include_dirs(matches: &clap::ArgMatches) -> Vec<PathBuf> {
+        let include_dirs = values_t!(matches, "include-dir", PathBuf).unwrap_or_default();
+        for include_dir in &include_dirs {
+            if !include_dir.is_dir() {
                 eprintln!(
-                    "Error: schema-file [{}] should be a file",
-                    schema_file.to_str().unwrap()
+                    "Error: include-dir [{}] should be a directory",
+                    include_dir.display()
                 );
                 process::exit(1);
             }
+        }
+        include_dirs
+    }
+
+    impl<'a> From<&'a clap::ArgMatches<'a>> for CompatConfig {
+        fn from(matches: &'a clap::ArgMatches) -> Self {
+            Self {
+                old_schema_file: schema_file(matches, "old-schema-file"),
+                new_schema_file: schema_file(matches, "new-schema-file"),
+                include_dirs: include_dirs(matches),
+            }
+        }
+    }
+
+    impl<'a> From<&'a clap::ArgMatches<'a>> for AppConfig {
+        fn from(matches: &'a clap::ArgMatches) -> Self {
+            let schema_file = schema_file(matches, "schema-file");
+            let language = value_t_or_exit!(matches, "language", String);
+            let include_dirs = include_dirs(matches);
             let output_config = if language == "-" {
                 let format = value_t!(matches, "format", String).unwrap_or_else(|_| {
                     eprintln!("Error: since language is \"-\", a format is required");
@@ -64,50 +118,172 @@ pub(crate) mod config {
                     process::exit(1);
                 }
             };
+            let plugin_options = values_t!(matches, "plugin-opt", String).unwrap_or_default();
+            if let OutputConfig::Output(_) = output_config {
+                if !plugin_options.is_empty() {
+                    eprintln!("Error: since language is \"-\", don't specify plugin options");
+                    process::exit(1);
+                }
+            }
+            for option in &plugin_options {
+                if !option.contains('=') {
+                    eprintln!("Error: plugin-opt [{}] should be \"<key>=<value>\"", option);
+                    process::exit(1);
+                }
+            }
+            let output_dir = value_t!(matches, "output-dir", PathBuf).ok();
+            if let Some(ref output_dir) = output_dir {
+                if !output_dir.is_dir() {
+                    eprintln!(
+                        "Error: output-dir [{}] should be a directory",
+                        output_dir.display()
+                    );
+                    process::exit(1);
+                }
+            }
             Self {
                 schema_file,
+                include_dirs,
                 output_config,
+                with_layout: matches.is_present("with-layout"),
+                plugin_options,
+                output_dir,
             }
         }
     }
 }
 
 fn main() {
-    let config = config::build_commandline();
+    let config = match config::build_commandline() {
+        config::Command::Compile(config) => config,
+        config::Command::Compat(config) => compat(&config),
+    };
     let default_format = IntermediateFormat::JSON;
     let mut compiler = Compiler::new();
+    compiler.intermediate_layout(config.with_layout);
     match config.output_config {
         config::OutputConfig::Plugin(ref plugin_file) => {
-            let output = process::Command::new(plugin_file.as_path())
-                .arg("--format")
-                .output()
-                .expect("Error: failed to execute plugin process");
-            let format = if output.status.success() {
-                str::from_utf8(&output.stdout[..])
-                    .ok()
-                    .and_then(|s| IntermediateFormat::try_from(s).ok())
-                    .unwrap_or(default_format)
+            if let Some(handshake) = handshake(plugin_file) {
+                let format = handshake.negotiate().unwrap_or_else(|err| {
+                    eprintln!("Error: {}", err);
+                    process::exit(1);
+                });
+                if config.with_layout && !handshake.supports(Feature::Layout) {
+                    eprintln!("Error: the plugin doesn't support the layout");
+                    process::exit(1);
+                }
+                compiler
+                    .generate_intermediate(format)
+                    .intermediate_features(&handshake.supported_features());
+                let mut command = process::Command::new(plugin_file.as_path());
+                command
+                    .arg("--protocol")
+                    .arg(PLUGIN_PROTOCOL_VERSION.to_string())
+                    .stdin(process::Stdio::piped())
+                    .stderr(process::Stdio::piped());
+                for option in &config.plugin_options {
+                    command.arg("--option").arg(option);
+                }
+                match (handshake.manifest(), config.output_dir.as_ref()) {
+                    (true, Some(output_dir)) => {
+                        let child = spawn(command.stdout(process::Stdio::piped()));
+                        compiler.output_plugin_manifest(child, output_dir);
+                    }
+                    (true, None) => {
+                        eprintln!(
+                            "Error: the plugin outputs several files, output-dir is required"
+                        );
+                        process::exit(1);
+                    }
+                    (false, Some(_)) => {
+                        eprintln!("Error: the plugin outputs to stdout, don't specify output-dir");
+                        process::exit(1);
+                    }
+                    (false, None) => {
+                        compiler.output_plugin_process(spawn(&mut command));
+                    }
+                }
             } else {
-                default_format
-            };
-            if let Ok(child) = process::Command::new(plugin_file.as_path())
-                .stdin(process::Stdio::piped())
-                .spawn()
-            {
+                if !config.plugin_options.is_empty() || config.output_dir.is_some() {
+                    eprintln!(
+                        "Error: the plugin doesn't support the handshake, \
+                         so neither plugin-opt nor output-dir is supported"
+                    );
+                    process::exit(1);
+                }
+                let output = process::Command::new(plugin_file.as_path())
+                    .arg("--format")
+                    .output()
+                    .expect("Error: failed to execute plugin process");
+                let format = if output.status.success() {
+                    str::from_utf8(&output.stdout[..])
+                        .ok()
+                        .and_then(|s| IntermediateFormat::try_from(s.trim()).ok())
+                        .unwrap_or(default_format)
+                } else {
+                    default_format
+                };
+                let child = spawn(
+                    process::Command::new(plugin_file.as_path()).stdin(process::Stdio::piped()),
+                );
                 compiler
                     .generate_intermediate(format)
                     .output_plugin_process(child);
-            } else {
-                eprintln!("Error: failed to spawn plugin process");
-                process::exit(1);
             }
         }
         config::OutputConfig::Output(format) => {
             compiler.generate_intermediate(format);
+            if let Some(ref output_dir) = config.output_dir {
+                compiler.output_dir(output_dir);
+            }
         }
     };
-    compiler
+    for include_dir in &config.include_dirs {
+        compiler.import_path(include_dir);
+    }
+    if let Err(err) = compiler
         .input_schema_file(config.schema_file.as_path())
         .run()
-        .unwrap()
+    {
+        eprintln!("{}", err);
+        process::exit(1);
+    }
+}
+
+fn spawn(command: &mut process::Command) -> process::Child {
+    command.spawn().unwrap_or_else(|_| {
+        eprintln!("Error: failed to spawn plugin process");
+        process::exit(1);
+    })
+}
+
+// Asks the plugin what it supports, returns `None` if it's an old plugin without the handshake.
+fn handshake(plugin_file: &Path) -> Option<Handshake> {
+    process::Command::new(plugin_file)
+        .arg("--handshake")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| Handshake::from_json(&output.stdout).ok())
+}
+
+// Compares two versions of a schema, then exits with 2 if any change is breaking.
+fn compat(config: &config::CompatConfig) -> ! {
+    let mut resolver = FileResolver::new();
+    for include_dir in &config.include_dirs {
+        resolver.import_path(include_dir);
+    }
+    let parse = |path| {
+        Parser::new()
+            .parse_schema_file(path, &resolver)
+            .unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                process::exit(1);
+            })
+    };
+    let old = parse(&config.old_schema_file);
+    let new = parse(&config.new_schema_file);
+    let report = check_compatibility(&old, &new);
+    println!("{}", report);
+    process::exit(if report.is_breaking() { 2 } else { 0 });
 }
@@ -3,10 +3,14 @@ use std::{
     process,
 };
 
-use molecule_codegen::{Compiler, IntermediateFormat, Language};
+use molecule_codegen::{
+    plugin::{Diagnostic, Feature, Handshake},
+    Compiler, IntermediateFormat, Language,
+};
 
 pub(crate) enum AppAction {
     DisplayFormat,
+    DisplayHandshake,
     ProcessIntermediate(Vec<u8>),
 }
 
@@ -14,6 +18,10 @@ pub struct AppConfig {
     action: AppAction,
     lang: Language,
     format: IntermediateFormat,
+    /// The version of the protocol, it's `None` if the compiler doesn't support the handshake.
+    protocol: Option<u32>,
+    /// The options for the plugin, as `<key>=<value>`.
+    options: Vec<String>,
 }
 
 type RawAppConfig<'a> = (Language, IntermediateFormat, &'a clap::ArgMatches<'a>);
@@ -33,6 +41,8 @@ impl<'a> From<&'a RawAppConfig<'a>> for AppConfig {
         let (lang, format, matches) = input;
         let action = if matches.is_present("format") {
             AppAction::DisplayFormat
+        } else if matches.is_present("handshake") {
+            AppAction::DisplayHandshake
         } else {
             let mut input = Vec::new();
             if io::stdin().read_to_end(&mut input).is_err() {
@@ -41,10 +51,22 @@ impl<'a> From<&'a RawAppConfig<'a>> for AppConfig {
             };
             AppAction::ProcessIntermediate(input)
         };
+        let protocol = matches.value_of("protocol").map(|value| {
+            value.parse().unwrap_or_else(|_| {
+                eprintln!("Error: protocol [{}] should be a number", value);
+                process::exit(1);
+            })
+        });
+        let options = matches
+            .values_of("option")
+            .map(|values| values.map(ToOwned::to_owned).collect())
+            .unwrap_or_default();
         Self {
             action,
             lang: *lang,
             format: *format,
+            protocol,
+            options,
         }
     }
 }
@@ -55,12 +77,30 @@ impl AppConfig {
             AppAction::DisplayFormat => {
                 println!("{}", self.format);
             }
+            AppAction::DisplayHandshake => {
+                let handshake = Handshake::new(&[self.format], &Feature::all());
+                println!("{}", handshake.to_json());
+            }
             AppAction::ProcessIntermediate(ref input) => {
-                Compiler::new()
+                // The generators don't have any options yet.
+                if let Some(option) = self.options.first() {
+                    let key = option.split('=').next().unwrap();
+                    let message = format!("unknown option `{}`", key);
+                    eprintln!("{}", Diagnostic::error(message).to_json());
+                    process::exit(1);
+                }
+                if let Err(err) = Compiler::new()
                     .generate_code(self.lang)
                     .input_intermediate(self.format, input.to_owned())
                     .run()
-                    .unwrap();
+                {
+                    if self.protocol.is_some() {
+                        eprintln!("{}", Diagnostic::error(err.message()).to_json());
+                    } else {
+                        eprintln!("{}", err);
+                    }
+                    process::exit(1);
+                }
             }
         }
     }